@@ -0,0 +1,87 @@
+//! Benchmarks for (de)serializing types directly and through their raw representation.
+
+use std::fmt::Debug;
+
+use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion};
+use serde::{de::DeserializeOwned, Serialize};
+use type_system::{bench_raw as raw, DataType, EntityType, PropertyType};
+
+#[path = "../tests/data/lib.rs"]
+#[expect(dead_code, reason = "Only some of the test data is benchmarked")]
+mod test_data;
+
+/// Benchmarks (de)serializing `T` directly against (de)serializing its raw representation `R`
+/// and converting it.
+fn bench_type<T, R>(criterion: &mut Criterion, name: &str, inputs: &[(&str, &str)])
+where
+    T: Clone + Serialize + DeserializeOwned + TryFrom<R>,
+    T::Error: Debug,
+    R: Serialize + DeserializeOwned + From<T>,
+{
+    let mut group = criterion.benchmark_group(name);
+    for &(input_name, input) in inputs {
+        group.bench_with_input(
+            BenchmarkId::new("deserialize", input_name),
+            input,
+            |bencher, input| {
+                bencher.iter(|| serde_json::from_str::<T>(input).expect("failed to deserialize"));
+            },
+        );
+        group.bench_with_input(
+            BenchmarkId::new("deserialize_raw", input_name),
+            input,
+            |bencher, input| {
+                bencher.iter(|| {
+                    T::try_from(serde_json::from_str::<R>(input).expect("failed to deserialize"))
+                        .expect("failed to convert")
+                });
+            },
+        );
+
+        let value = serde_json::from_str::<T>(input).expect("failed to deserialize");
+        group.bench_with_input(
+            BenchmarkId::new("serialize", input_name),
+            &value,
+            |bencher, value| {
+                bencher.iter(|| serde_json::to_string(value).expect("failed to serialize"));
+            },
+        );
+        group.bench_with_input(
+            BenchmarkId::new("serialize_raw", input_name),
+            &value,
+            |bencher, value| {
+                bencher.iter(|| {
+                    serde_json::to_string(&R::from(value.clone())).expect("failed to serialize")
+                });
+            },
+        );
+    }
+    group.finish();
+}
+
+fn data_type(criterion: &mut Criterion) {
+    bench_type::<DataType, raw::DataType>(criterion, "data_type", &[
+        ("text", test_data::data_type::TEXT_V1),
+        ("number", test_data::data_type::NUMBER_V1),
+    ]);
+}
+
+fn property_type(criterion: &mut Criterion) {
+    bench_type::<PropertyType, raw::PropertyType>(criterion, "property_type", &[
+        ("age", test_data::property_type::AGE_V1),
+        (
+            "contact_information",
+            test_data::property_type::CONTACT_INFORMATION_V1,
+        ),
+    ]);
+}
+
+fn entity_type(criterion: &mut Criterion) {
+    bench_type::<EntityType, raw::EntityType>(criterion, "entity_type", &[
+        ("book", test_data::entity_type::BOOK_V1),
+        ("person", test_data::entity_type::PERSON_V1),
+    ]);
+}
+
+criterion_group!(benches, data_type, property_type, entity_type);
+criterion_main!(benches);
@@ -0,0 +1,288 @@
+use std::cmp::Ordering;
+
+use crate::{
+    ontology::{
+        data_type::{constraints::compare_numbers, validation::is_multiple_of},
+        shared::compatibility::into_result,
+    },
+    DataType, Incompatibility, IncompatibilityReason, JsonPointer, JsonType,
+};
+
+impl DataType {
+    /// Checks if every value which is valid for this data type is also valid for `other`.
+    ///
+    /// The check is conservative: incompatibilities may be reported for data types whose value
+    /// spaces are related in ways which can't be derived from the constraint keywords alone, e.g.
+    /// two different `pattern`s.
+    ///
+    /// # Errors
+    ///
+    /// - every [`Incompatibility`] found, addressed by its location inside the schema of this data
+    ///   type
+    pub fn is_compatible_with(&self, other: &Self) -> Result<(), Vec<Incompatibility>> {
+        let mut incompatibilities = Vec::new();
+        self.check_compatibility_at(other, &JsonPointer::root(), &mut incompatibilities);
+        into_result(incompatibilities)
+    }
+
+    fn check_compatibility_at(
+        &self,
+        other: &Self,
+        path: &JsonPointer,
+        incompatibilities: &mut Vec<Incompatibility>,
+    ) {
+        if self.id == other.id {
+            return;
+        }
+
+        // A data type with a finite set of values is compatible if each of those is valid
+        if let Some(value) = &self.constraints.const_value {
+            if other.validate_value(value).is_err() {
+                incompatibilities.push(Incompatibility::new(
+                    path.join("const"),
+                    IncompatibilityReason::ValueNotAllowed(value.clone()),
+                ));
+            }
+            return;
+        }
+        if let Some(values) = &self.constraints.enum_values {
+            for (index, value) in values.iter().enumerate() {
+                if other.validate_value(value).is_err() {
+                    incompatibilities.push(Incompatibility::new(
+                        path.join("enum").join(index),
+                        IncompatibilityReason::ValueNotAllowed(value.clone()),
+                    ));
+                }
+            }
+            return;
+        }
+
+        let is_subtype = self.json_type == other.json_type
+            || (self.json_type == JsonType::Integer && other.json_type == JsonType::Number);
+        if !is_subtype {
+            incompatibilities.push(Incompatibility::new(
+                path.join("type"),
+                IncompatibilityReason::JsonTypeMismatch {
+                    json_type: self.json_type,
+                    other_json_type: other.json_type,
+                },
+            ));
+            return;
+        }
+
+        let own = &self.constraints;
+        let other = &other.constraints;
+
+        let is_at_least = |own: Option<&_>, other: Option<&_>, ordering| match (own, other) {
+            (_, None) => true,
+            (None, Some(_)) => false,
+            (Some(own), Some(other)) => {
+                compare_numbers(own, other).is_some_and(|order| order == ordering || order.is_eq())
+            }
+        };
+
+        // In the order of `DataTypeConstraints::keywords`
+        let is_narrowed = [
+            is_at_least(
+                own.minimum.as_ref(),
+                other.minimum.as_ref(),
+                Ordering::Greater,
+            ),
+            is_at_least(own.maximum.as_ref(), other.maximum.as_ref(), Ordering::Less),
+            match (&own.multiple_of, &other.multiple_of) {
+                (_, None) => true,
+                (None, Some(_)) => false,
+                (Some(own), Some(other)) => is_multiple_of(own, other),
+            },
+            other
+                .min_length
+                .is_none_or(|other| own.min_length.is_some_and(|own| own >= other)),
+            other
+                .max_length
+                .is_none_or(|other| own.max_length.is_some_and(|own| own <= other)),
+            other.pattern.is_none() || own.pattern == other.pattern,
+            other.format.is_none() || own.format == other.format,
+            other.enum_values.is_none(),
+            other.const_value.is_none(),
+        ];
+
+        for (((keyword, value), (_, other_value)), is_narrowed) in own
+            .keywords()
+            .into_iter()
+            .zip(other.keywords())
+            .zip(is_narrowed)
+        {
+            if let (false, Some(other_value)) = (is_narrowed, other_value) {
+                incompatibilities.push(Incompatibility::new(
+                    path.join(keyword),
+                    IncompatibilityReason::ConstraintLoosened {
+                        keyword: keyword.to_owned(),
+                        value,
+                        other_value,
+                    },
+                ));
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+    use crate::utils::tests::{data_type, data_type_json};
+
+    #[test]
+    fn json_types() {
+        let integer = data_type(&data_type_json("a/v/1", "integer", &json!({})).to_string());
+        let number = data_type(&data_type_json("b/v/1", "number", &json!({})).to_string());
+        let string = data_type(&data_type_json("c/v/1", "string", &json!({})).to_string());
+
+        integer
+            .is_compatible_with(&number)
+            .expect("integers should be numbers");
+        assert_eq!(
+            number.is_compatible_with(&integer),
+            Err(vec![Incompatibility::new(
+                JsonPointer::root().join("type"),
+                IncompatibilityReason::JsonTypeMismatch {
+                    json_type: JsonType::Number,
+                    other_json_type: JsonType::Integer,
+                },
+            )])
+        );
+        string
+            .is_compatible_with(&number)
+            .expect_err("strings should not be numbers");
+    }
+
+    #[test]
+    fn constraints() {
+        let percentage = data_type(
+            &data_type_json(
+                "percentage/v/1",
+                "number",
+                &json!({ "minimum": 0, "maximum": 100, "multipleOf": 0.5 }),
+            )
+            .to_string(),
+        );
+        let positive = data_type(
+            &data_type_json(
+                "positive/v/1",
+                "number",
+                &json!({ "minimum": 0, "multipleOf": 0.25 }),
+            )
+            .to_string(),
+        );
+
+        percentage
+            .is_compatible_with(&positive)
+            .expect("percentage should be positive");
+        assert_eq!(
+            positive.is_compatible_with(&percentage),
+            Err(vec![
+                Incompatibility::new(
+                    JsonPointer::root().join("maximum"),
+                    IncompatibilityReason::ConstraintLoosened {
+                        keyword: "maximum".to_owned(),
+                        value: None,
+                        other_value: json!(100),
+                    },
+                ),
+                Incompatibility::new(
+                    JsonPointer::root().join("multipleOf"),
+                    IncompatibilityReason::ConstraintLoosened {
+                        keyword: "multipleOf".to_owned(),
+                        value: Some(json!(0.25)),
+                        other_value: json!(0.5),
+                    },
+                ),
+            ])
+        );
+
+        let short = data_type(
+            &data_type_json("short/v/1", "string", &json!({ "maxLength": 10 })).to_string(),
+        );
+        let shorter = data_type(
+            &data_type_json(
+                "shorter/v/1",
+                "string",
+                &json!({ "maxLength": 5, "format": "hostname" }),
+            )
+            .to_string(),
+        );
+
+        shorter
+            .is_compatible_with(&short)
+            .expect("shorter should be short");
+        assert_eq!(
+            short.is_compatible_with(&shorter),
+            Err(vec![
+                Incompatibility::new(
+                    JsonPointer::root().join("maxLength"),
+                    IncompatibilityReason::ConstraintLoosened {
+                        keyword: "maxLength".to_owned(),
+                        value: Some(json!(10)),
+                        other_value: json!(5),
+                    },
+                ),
+                Incompatibility::new(
+                    JsonPointer::root().join("format"),
+                    IncompatibilityReason::ConstraintLoosened {
+                        keyword: "format".to_owned(),
+                        value: None,
+                        other_value: json!("hostname"),
+                    },
+                ),
+            ])
+        );
+    }
+
+    #[test]
+    fn finite_values() {
+        let colors = data_type(
+            &data_type_json(
+                "colors/v/1",
+                "string",
+                &json!({ "enum": ["red", "green", "blue"] }),
+            )
+            .to_string(),
+        );
+        let red =
+            data_type(&data_type_json("red/v/1", "string", &json!({ "const": "red" })).to_string());
+        let short = data_type(
+            &data_type_json("short/v/1", "string", &json!({ "maxLength": 5 })).to_string(),
+        );
+
+        red.is_compatible_with(&colors)
+            .expect("red should be a color");
+        assert_eq!(
+            colors.is_compatible_with(&red),
+            Err(vec![
+                Incompatibility::new(
+                    JsonPointer::root().join("enum").join(1),
+                    IncompatibilityReason::ValueNotAllowed(json!("green")),
+                ),
+                Incompatibility::new(
+                    JsonPointer::root().join("enum").join(2),
+                    IncompatibilityReason::ValueNotAllowed(json!("blue")),
+                ),
+            ])
+        );
+        colors
+            .is_compatible_with(&short)
+            .expect("colors should be short");
+        assert_eq!(
+            short.is_compatible_with(&colors),
+            Err(vec![Incompatibility::new(
+                JsonPointer::root().join("enum"),
+                IncompatibilityReason::ConstraintLoosened {
+                    keyword: "enum".to_owned(),
+                    value: None,
+                    other_value: json!(["red", "green", "blue"]),
+                },
+            )])
+        );
+    }
+}
@@ -0,0 +1,264 @@
+use std::{cmp::Ordering, fmt, str::FromStr};
+
+use regex::Regex;
+use serde_json::Number;
+
+use crate::{DataTypeConstraintError, JsonType};
+
+/// A regular expression used in the `pattern` keyword of a [`DataType`].
+///
+/// [`DataType`]: crate::DataType
+#[derive(Debug, Clone)]
+pub struct Pattern(Regex);
+
+impl Pattern {
+    /// Compiles the given regular expression.
+    ///
+    /// # Errors
+    ///
+    /// - [`InvalidPattern`] if `pattern` is not a valid regular expression
+    ///
+    /// [`InvalidPattern`]: DataTypeConstraintError::InvalidPattern
+    pub fn new(pattern: &str) -> Result<Self, DataTypeConstraintError> {
+        Regex::new(pattern)
+            .map(Self)
+            .map_err(|error| DataTypeConstraintError::InvalidPattern(error.to_string()))
+    }
+
+    #[must_use]
+    pub fn as_str(&self) -> &str {
+        self.0.as_str()
+    }
+
+    #[must_use]
+    pub fn is_match(&self, value: &str) -> bool {
+        self.0.is_match(value)
+    }
+}
+
+impl PartialEq for Pattern {
+    fn eq(&self, other: &Self) -> bool {
+        self.as_str() == other.as_str()
+    }
+}
+
+impl Eq for Pattern {}
+
+impl fmt::Display for Pattern {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(self.as_str(), fmt)
+    }
+}
+
+impl FromStr for Pattern {
+    type Err = DataTypeConstraintError;
+
+    fn from_str(pattern: &str) -> Result<Self, Self::Err> {
+        Self::new(pattern)
+    }
+}
+
+/// The constraint keywords of a [`DataType`] restricting the values it allows.
+///
+/// Which keywords may be used depends on the [`JsonType`] of the data type:
+///
+/// - `minimum`, `maximum` and `multipleOf` require a `number` or `integer`
+/// - `minLength`, `maxLength`, `pattern` and `format` require a `string`
+/// - `enum` and `const` may be used with any type, but their values have to be of that type
+///
+/// [`DataType`]: crate::DataType
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct DataTypeConstraints {
+    pub minimum: Option<Number>,
+    pub maximum: Option<Number>,
+    pub multiple_of: Option<Number>,
+    pub min_length: Option<usize>,
+    pub max_length: Option<usize>,
+    pub pattern: Option<Pattern>,
+    pub format: Option<String>,
+    pub enum_values: Option<Vec<serde_json::Value>>,
+    pub const_value: Option<serde_json::Value>,
+}
+
+impl DataTypeConstraints {
+    /// Returns the value of every constraint keyword, or `None` if the keyword is not specified.
+    pub(super) fn keywords(&self) -> [(&'static str, Option<serde_json::Value>); 9] {
+        [
+            (
+                "minimum",
+                self.minimum.clone().map(serde_json::Value::Number),
+            ),
+            (
+                "maximum",
+                self.maximum.clone().map(serde_json::Value::Number),
+            ),
+            (
+                "multipleOf",
+                self.multiple_of.clone().map(serde_json::Value::Number),
+            ),
+            ("minLength", self.min_length.map(serde_json::Value::from)),
+            ("maxLength", self.max_length.map(serde_json::Value::from)),
+            (
+                "pattern",
+                self.pattern
+                    .as_ref()
+                    .map(|pattern| serde_json::Value::String(pattern.as_str().to_owned())),
+            ),
+            ("format", self.format.clone().map(serde_json::Value::String)),
+            (
+                "enum",
+                self.enum_values.clone().map(serde_json::Value::Array),
+            ),
+            ("const", self.const_value.clone()),
+        ]
+    }
+
+    /// Checks that the constraints can be applied to values of `json_type` and don't contradict
+    /// each other.
+    ///
+    /// # Errors
+    ///
+    /// - [`InapplicableKeyword`] if a keyword is used with a type it doesn't apply to
+    /// - [`MinimumExceedsMaximum`] if `minimum` is greater than `maximum`
+    /// - [`MinLengthExceedsMaxLength`] if `minLength` is greater than `maxLength`
+    /// - [`NonPositiveMultipleOf`] if `multipleOf` is not greater than zero
+    /// - [`EmptyEnum`] if `enum` is empty
+    /// - [`MismatchedValueType`] if a value in `enum` or `const` is not of type `json_type`
+    /// - [`ConstNotInEnum`] if both `const` and `enum` are specified but `enum` doesn't contain the
+    ///   `const` value
+    ///
+    /// [`InapplicableKeyword`]: DataTypeConstraintError::InapplicableKeyword
+    /// [`MinimumExceedsMaximum`]: DataTypeConstraintError::MinimumExceedsMaximum
+    /// [`MinLengthExceedsMaxLength`]: DataTypeConstraintError::MinLengthExceedsMaxLength
+    /// [`NonPositiveMultipleOf`]: DataTypeConstraintError::NonPositiveMultipleOf
+    /// [`EmptyEnum`]: DataTypeConstraintError::EmptyEnum
+    /// [`MismatchedValueType`]: DataTypeConstraintError::MismatchedValueType
+    /// [`ConstNotInEnum`]: DataTypeConstraintError::ConstNotInEnum
+    pub fn validate(&self, json_type: JsonType) -> Result<(), DataTypeConstraintError> {
+        let mut errors = Vec::new();
+        self.validate_at(json_type, &mut errors);
+        errors.into_iter().next().map_or(Ok(()), Err)
+    }
+
+    /// Reports every error [`validate`] would fail with, in the order it checks them.
+    ///
+    /// [`validate`]: Self::validate
+    pub(crate) fn validate_at(
+        &self,
+        json_type: JsonType,
+        errors: &mut Vec<DataTypeConstraintError>,
+    ) {
+        let is_numeric = matches!(json_type, JsonType::Number | JsonType::Integer);
+        let is_string = json_type == JsonType::String;
+
+        for (keyword, is_present, is_applicable) in [
+            ("minimum", self.minimum.is_some(), is_numeric),
+            ("maximum", self.maximum.is_some(), is_numeric),
+            ("multipleOf", self.multiple_of.is_some(), is_numeric),
+            ("minLength", self.min_length.is_some(), is_string),
+            ("maxLength", self.max_length.is_some(), is_string),
+            ("pattern", self.pattern.is_some(), is_string),
+            ("format", self.format.is_some(), is_string),
+        ] {
+            if is_present && !is_applicable {
+                errors.push(DataTypeConstraintError::InapplicableKeyword {
+                    keyword: keyword.to_owned(),
+                    json_type,
+                });
+            }
+        }
+
+        if let (Some(minimum), Some(maximum)) = (&self.minimum, &self.maximum) {
+            if compare_numbers(minimum, maximum) == Some(Ordering::Greater) {
+                errors.push(DataTypeConstraintError::MinimumExceedsMaximum {
+                    minimum: minimum.clone(),
+                    maximum: maximum.clone(),
+                });
+            }
+        }
+
+        if let Some(multiple_of) = &self.multiple_of {
+            if multiple_of.as_f64().is_none_or(|divisor| divisor <= 0.0) {
+                errors.push(DataTypeConstraintError::NonPositiveMultipleOf(
+                    multiple_of.clone(),
+                ));
+            }
+        }
+
+        if let (Some(min_length), Some(max_length)) = (self.min_length, self.max_length) {
+            if min_length > max_length {
+                errors.push(DataTypeConstraintError::MinLengthExceedsMaxLength {
+                    min_length,
+                    max_length,
+                });
+            }
+        }
+
+        if let Some(enum_values) = &self.enum_values {
+            if enum_values.is_empty() {
+                errors.push(DataTypeConstraintError::EmptyEnum);
+            }
+            for value in enum_values {
+                if !json_type.is_type_of(value) {
+                    errors.push(DataTypeConstraintError::MismatchedValueType {
+                        keyword: "enum".to_owned(),
+                        value: value.clone(),
+                        json_type,
+                    });
+                }
+            }
+        }
+
+        if let Some(const_value) = &self.const_value {
+            if !json_type.is_type_of(const_value) {
+                errors.push(DataTypeConstraintError::MismatchedValueType {
+                    keyword: "const".to_owned(),
+                    value: const_value.clone(),
+                    json_type,
+                });
+            } else if let Some(enum_values) = &self.enum_values {
+                if !enum_values
+                    .iter()
+                    .any(|value| values_equal(value, const_value))
+                {
+                    errors.push(DataTypeConstraintError::ConstNotInEnum(const_value.clone()));
+                }
+            }
+        }
+    }
+}
+
+/// Compares two JSON numbers by their numeric value.
+///
+/// Integers are compared exactly, everything else is compared as `f64`.
+pub(crate) fn compare_numbers(lhs: &Number, rhs: &Number) -> Option<Ordering> {
+    if let (Some(lhs), Some(rhs)) = (lhs.as_i64(), rhs.as_i64()) {
+        Some(lhs.cmp(&rhs))
+    } else if let (Some(lhs), Some(rhs)) = (lhs.as_u64(), rhs.as_u64()) {
+        Some(lhs.cmp(&rhs))
+    } else {
+        lhs.as_f64()?.partial_cmp(&rhs.as_f64()?)
+    }
+}
+
+/// Compares two JSON values as JSON Schema does for `const` and `enum`.
+///
+/// Numbers are equal if they have the same numeric value, e.g. `1` and `1.0`, also when they are
+/// nested inside arrays or objects. Everything else is compared structurally.
+pub(crate) fn values_equal(lhs: &serde_json::Value, rhs: &serde_json::Value) -> bool {
+    match (lhs, rhs) {
+        (serde_json::Value::Number(lhs), serde_json::Value::Number(rhs)) => {
+            compare_numbers(lhs, rhs) == Some(Ordering::Equal)
+        }
+        (serde_json::Value::Array(lhs), serde_json::Value::Array(rhs)) => {
+            lhs.len() == rhs.len() && lhs.iter().zip(rhs).all(|(lhs, rhs)| values_equal(lhs, rhs))
+        }
+        (serde_json::Value::Object(lhs), serde_json::Value::Object(rhs)) => {
+            lhs.len() == rhs.len()
+                && lhs
+                    .iter()
+                    .all(|(key, lhs)| rhs.get(key).is_some_and(|rhs| values_equal(lhs, rhs)))
+        }
+        _ => lhs == rhs,
+    }
+}
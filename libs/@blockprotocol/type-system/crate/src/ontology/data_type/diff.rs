@@ -0,0 +1,154 @@
+use std::collections::BTreeSet;
+
+use crate::{ontology::shared::diff::diff_annotations, Change, ChangeKind, DataType, JsonPointer};
+
+impl DataType {
+    /// Returns every change from this data type to `other`, typically a later version of it.
+    ///
+    /// Changes of the `type` and of the constraint keywords are reported as constraint changes.
+    /// Changes of the `title`, the `description` and of any keyword which is not interpreted by
+    /// the type system are reported as semantic-annotation changes.
+    #[must_use]
+    pub fn diff(&self, other: &Self) -> Vec<Change> {
+        let mut changes = Vec::new();
+        let path = JsonPointer::root();
+
+        diff_annotations(
+            (self.title(), self.description()),
+            (other.title(), other.description()),
+            &mut changes,
+        );
+
+        if self.json_type != other.json_type {
+            changes.push(Change::new(
+                path.join("type"),
+                ChangeKind::JsonTypeChanged {
+                    old: self.json_type,
+                    new: other.json_type,
+                },
+            ));
+        }
+
+        for ((keyword, old), (_, new)) in self
+            .constraints
+            .keywords()
+            .into_iter()
+            .zip(other.constraints.keywords())
+        {
+            if old != new {
+                changes.push(Change::new(
+                    path.join(keyword),
+                    ChangeKind::ConstraintChanged {
+                        keyword: keyword.to_owned(),
+                        old,
+                        new,
+                    },
+                ));
+            }
+        }
+
+        let keywords = self
+            .additional_properties
+            .keys()
+            .chain(other.additional_properties.keys())
+            .collect::<BTreeSet<_>>();
+        for keyword in keywords {
+            let old = self.additional_properties.get(keyword);
+            let new = other.additional_properties.get(keyword);
+            if old != new {
+                changes.push(Change::new(
+                    path.join(keyword),
+                    ChangeKind::AnnotationChanged {
+                        keyword: keyword.clone(),
+                        old: old.cloned(),
+                        new: new.cloned(),
+                    },
+                ));
+            }
+        }
+
+        changes
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::{json, Value};
+
+    use super::*;
+    use crate::{raw, utils::tests::check_serialization_from_str, ChangeClassification, JsonType};
+
+    fn data_type(value: &Value) -> DataType {
+        check_serialization_from_str::<DataType, raw::DataType>(&value.to_string(), None)
+    }
+
+    #[test]
+    fn changes() {
+        let old = data_type(&json!({
+            "$schema": "https://blockprotocol.org/types/modules/graph/0.3/schema/data-type",
+            "kind": "dataType",
+            "$id": "https://example.com/data-type/age/v/1",
+            "title": "Age",
+            "type": "number",
+            "minimum": 0,
+            "$comment": "An age"
+        }));
+        let new = data_type(&json!({
+            "$schema": "https://blockprotocol.org/types/modules/graph/0.3/schema/data-type",
+            "kind": "dataType",
+            "$id": "https://example.com/data-type/age/v/2",
+            "title": "Age in years",
+            "type": "integer",
+            "minimum": 0,
+            "maximum": 150
+        }));
+
+        assert!(old.diff(&old).is_empty());
+
+        let changes = old.diff(&new);
+        assert_eq!(changes, [
+            Change::new(
+                JsonPointer::root().join("title"),
+                ChangeKind::TitleChanged {
+                    old: "Age".to_owned(),
+                    new: "Age in years".to_owned(),
+                },
+            ),
+            Change::new(
+                JsonPointer::root().join("type"),
+                ChangeKind::JsonTypeChanged {
+                    old: JsonType::Number,
+                    new: JsonType::Integer,
+                },
+            ),
+            Change::new(
+                JsonPointer::root().join("maximum"),
+                ChangeKind::ConstraintChanged {
+                    keyword: "maximum".to_owned(),
+                    old: None,
+                    new: Some(json!(150)),
+                },
+            ),
+            Change::new(
+                JsonPointer::root().join("$comment"),
+                ChangeKind::AnnotationChanged {
+                    keyword: "$comment".to_owned(),
+                    old: Some(json!("An age")),
+                    new: None,
+                },
+            ),
+        ]);
+        assert_eq!(
+            changes
+                .iter()
+                .map(Change::classification)
+                .collect::<Vec<_>>(),
+            [
+                ChangeClassification::SemanticAnnotation,
+                ChangeClassification::Constraint,
+                ChangeClassification::Constraint,
+                ChangeClassification::SemanticAnnotation,
+            ]
+        );
+    }
+}
@@ -3,16 +3,109 @@ use thiserror::Error;
 #[cfg(target_arch = "wasm32")]
 use tsify::Tsify;
 
-use crate::url::ParseVersionedUrlError;
+use crate::{
+    ontology::shared::json_pointer::ROOT, url::ParseVersionedUrlError, JsonPointer, JsonType,
+};
 
 #[cfg_attr(target_arch = "wasm32", derive(Tsify))]
 #[derive(Debug, Deserialize, Serialize, PartialEq, Eq, Error)]
 #[serde(tag = "reason", content = "inner")]
 pub enum ParseDataTypeError {
-    #[error("invalid `$schema` property, expected `\"https://blockprotocol.org/types/modules/graph/0.3/schema/data-type\"` but received: `{0}`")]
-    InvalidMetaSchema(String),
-    #[error("invalid versioned URL: `{0}`")]
-    InvalidVersionedUrl(ParseVersionedUrlError),
+    #[error("invalid `$schema` property at `{path}`, expected `\"https://blockprotocol.org/types/modules/graph/0.3/schema/data-type\"` but received: `{meta_schema}`")]
+    InvalidMetaSchema {
+        path: JsonPointer,
+        meta_schema: String,
+    },
+    #[error("invalid versioned URL at `{path}`: `{error}`")]
+    InvalidVersionedUrl {
+        path: JsonPointer,
+        error: ParseVersionedUrlError,
+    },
+    #[error("unknown JSON type at `{path}`: `{json_type}`")]
+    UnknownJsonType {
+        path: JsonPointer,
+        json_type: String,
+    },
+    #[error("invalid constraints at `{path}`: `{error}`")]
+    InvalidConstraints {
+        path: JsonPointer,
+        error: DataTypeConstraintError,
+    },
     #[error("error in JSON: `{0}`")]
     InvalidJson(String),
 }
+
+impl ParseDataTypeError {
+    /// Returns the location of the node which failed to parse, relative to the data type.
+    ///
+    /// [`InvalidJson`] errors are located at the root, as the deserializer does not report a
+    /// location.
+    ///
+    /// [`InvalidJson`]: Self::InvalidJson
+    #[must_use]
+    pub fn path(&self) -> &JsonPointer {
+        match self {
+            Self::InvalidMetaSchema { path, .. }
+            | Self::InvalidVersionedUrl { path, .. }
+            | Self::UnknownJsonType { path, .. }
+            | Self::InvalidConstraints { path, .. } => path,
+            Self::InvalidJson(_) => &ROOT,
+        }
+    }
+}
+
+#[cfg_attr(target_arch = "wasm32", derive(Tsify))]
+#[derive(Debug, Clone, Deserialize, Serialize, PartialEq, Eq, Error)]
+#[serde(tag = "reason", content = "inner")]
+pub enum DataTypeConstraintError {
+    #[error("`{keyword}` can not be used with values of type `{json_type}`")]
+    InapplicableKeyword {
+        keyword: String,
+        json_type: JsonType,
+    },
+    #[error("`minimum` ({minimum}) is greater than `maximum` ({maximum})")]
+    MinimumExceedsMaximum {
+        #[cfg_attr(target_arch = "wasm32", tsify(type = "number"))]
+        minimum: serde_json::Number,
+        #[cfg_attr(target_arch = "wasm32", tsify(type = "number"))]
+        maximum: serde_json::Number,
+    },
+    #[error("`minLength` ({min_length}) is greater than `maxLength` ({max_length})")]
+    MinLengthExceedsMaxLength {
+        min_length: usize,
+        max_length: usize,
+    },
+    #[error("`multipleOf` has to be greater than 0, but is {0}")]
+    NonPositiveMultipleOf(
+        #[cfg_attr(target_arch = "wasm32", tsify(type = "number"))] serde_json::Number,
+    ),
+    #[error("invalid `pattern`: {0}")]
+    InvalidPattern(String),
+    #[error("`enum` has to contain at least one value")]
+    EmptyEnum,
+    #[error("the value `{value}` in `{keyword}` is not of type `{json_type}`")]
+    MismatchedValueType {
+        keyword: String,
+        #[cfg_attr(target_arch = "wasm32", tsify(type = "any"))]
+        value: serde_json::Value,
+        json_type: JsonType,
+    },
+    #[error("the `const` value `{0}` is not contained in `enum`")]
+    ConstNotInEnum(#[cfg_attr(target_arch = "wasm32", tsify(type = "any"))] serde_json::Value),
+}
+
+impl DataTypeConstraintError {
+    /// Returns the keyword which caused this error.
+    pub(crate) fn keyword(&self) -> &str {
+        match self {
+            Self::InapplicableKeyword { keyword, .. }
+            | Self::MismatchedValueType { keyword, .. } => keyword,
+            Self::MinimumExceedsMaximum { .. } => "minimum",
+            Self::MinLengthExceedsMaxLength { .. } => "minLength",
+            Self::NonPositiveMultipleOf(_) => "multipleOf",
+            Self::InvalidPattern(_) => "pattern",
+            Self::EmptyEnum => "enum",
+            Self::ConstNotInEnum(_) => "const",
+        }
+    }
+}
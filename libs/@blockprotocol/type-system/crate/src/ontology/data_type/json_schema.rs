@@ -0,0 +1,76 @@
+use serde_json::{Map, Value};
+
+use crate::{ontology::shared::json_schema::JSON_SCHEMA_DIALECT, DataType};
+
+impl DataType {
+    /// Returns this data type as a standalone JSON Schema (draft 2020-12).
+    ///
+    /// Keywords which are not interpreted by the type system are kept as they are.
+    #[must_use]
+    pub fn to_json_schema(&self) -> Value {
+        let mut schema = self.json_schema_object();
+        schema.insert("$schema".to_owned(), JSON_SCHEMA_DIALECT.into());
+        schema.insert("$id".to_owned(), self.id.to_string().into());
+        Value::Object(schema)
+    }
+
+    /// Returns the JSON Schema of this data type without `$schema` and `$id`, so it can be
+    /// inlined into the schema of another type.
+    pub(crate) fn json_schema_object(&self) -> Map<String, Value> {
+        let mut schema = self
+            .additional_properties
+            .iter()
+            .map(|(keyword, value)| (keyword.clone(), value.clone()))
+            .collect::<Map<_, _>>();
+
+        schema.insert("title".to_owned(), self.title.clone().into());
+        if let Some(description) = &self.description {
+            schema.insert("description".to_owned(), description.clone().into());
+        }
+        schema.insert("type".to_owned(), self.json_type.as_str().into());
+        for (keyword, value) in self.constraints.keywords() {
+            if let Some(value) = value {
+                schema.insert(keyword.to_owned(), value);
+            }
+        }
+
+        schema
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+    use crate::{raw, utils::tests::check_serialization_from_str};
+
+    #[test]
+    fn constraints() {
+        let data_type = check_serialization_from_str::<DataType, raw::DataType>(
+            &json!({
+                "$schema": "https://blockprotocol.org/types/modules/graph/0.3/schema/data-type",
+                "kind": "dataType",
+                "$id": "https://example.com/data-type/age/v/1",
+                "title": "Age",
+                "type": "integer",
+                "minimum": 0,
+                "$comment": "An age in years"
+            })
+            .to_string(),
+            None,
+        );
+
+        assert_eq!(
+            data_type.to_json_schema(),
+            json!({
+                "$schema": "https://json-schema.org/draft/2020-12/schema",
+                "$id": "https://example.com/data-type/age/v/1",
+                "title": "Age",
+                "type": "integer",
+                "minimum": 0,
+                "$comment": "An age in years"
+            })
+        );
+    }
+}
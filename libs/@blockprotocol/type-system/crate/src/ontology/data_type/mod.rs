@@ -1,48 +1,80 @@
-use std::collections::HashMap;
-
-pub use error::ParseDataTypeError;
-use serde::{Deserialize, Serialize};
+pub use constraints::{DataTypeConstraints, Pattern};
+pub use error::{DataTypeConstraintError, ParseDataTypeError};
+use indexmap::IndexMap;
 
 use crate::{
+    ontology::shared::parse::parse_all,
     url::{BaseUrl, VersionedUrl},
-    ValidateUrl, ValidationError,
+    JsonType, ValidateUrl, ValidationError,
 };
 
+mod compatibility;
+mod constraints;
+mod diff;
 mod error;
+mod json_schema;
 pub(in crate::ontology) mod raw;
+mod serialization;
+mod validation;
 #[cfg(target_arch = "wasm32")]
 mod wasm;
 
-#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
-#[serde(try_from = "raw::DataType", into = "raw::DataType")]
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub struct DataType {
     id: VersionedUrl,
     title: String,
     description: Option<String>,
-    json_type: String,
+    json_type: JsonType,
+    constraints: DataTypeConstraints,
     /// Properties which are not currently strongly typed.
     ///
     /// The data type meta-schema currently allows arbitrary, untyped properties. This is a
-    /// catch-all field to store all non-typed data.
-    additional_properties: HashMap<String, serde_json::Value>,
+    /// catch-all field to store all keywords which are not covered by [`DataTypeConstraints`].
+    additional_properties: IndexMap<String, serde_json::Value>,
 }
 
 impl DataType {
-    #[must_use]
-    pub const fn new(
+    /// Creates a new `DataType`.
+    ///
+    /// # Errors
+    ///
+    /// - [`DataTypeConstraintError`] if the `constraints` can't be applied to `json_type` or
+    ///   contradict each other, see [`DataTypeConstraints::validate`]
+    pub fn new(
         id: VersionedUrl,
         title: String,
         description: Option<String>,
-        json_type: String,
-        additional_properties: HashMap<String, serde_json::Value>,
-    ) -> Self {
-        Self {
+        json_type: JsonType,
+        constraints: DataTypeConstraints,
+        additional_properties: IndexMap<String, serde_json::Value>,
+    ) -> Result<Self, DataTypeConstraintError> {
+        constraints.validate(json_type)?;
+
+        Ok(Self {
             id,
             title,
             description,
             json_type,
+            constraints,
             additional_properties,
-        }
+        })
+    }
+
+    /// Parses the JSON `schema` of a data type, reporting every error instead of only the first
+    /// one.
+    ///
+    /// Deserializing `schema` fails fast, as it has to have the shape of a data type before its
+    /// values can be checked. The constraints are only checked if the `type` is known.
+    ///
+    /// # Errors
+    ///
+    /// - [`InvalidJson`] if `schema` could not be deserialized, this is the only error reported
+    ///   then
+    /// - every other [`ParseDataTypeError`] found in `schema`, in the order they are encountered
+    ///
+    /// [`InvalidJson`]: ParseDataTypeError::InvalidJson
+    pub fn parse_all(schema: serde_json::Value) -> Result<Self, Vec<ParseDataTypeError>> {
+        parse_all::<raw::DataType, _>(schema, ParseDataTypeError::InvalidJson)
     }
 
     #[must_use]
@@ -61,17 +93,22 @@ impl DataType {
     }
 
     #[must_use]
-    pub fn json_type(&self) -> &str {
-        &self.json_type
+    pub const fn json_type(&self) -> JsonType {
+        self.json_type
+    }
+
+    #[must_use]
+    pub const fn constraints(&self) -> &DataTypeConstraints {
+        &self.constraints
     }
 
     #[must_use]
-    pub const fn additional_properties(&self) -> &HashMap<String, serde_json::Value> {
+    pub const fn additional_properties(&self) -> &IndexMap<String, serde_json::Value> {
         &self.additional_properties
     }
 
     #[must_use]
-    pub fn additional_properties_mut(&mut self) -> &mut HashMap<String, serde_json::Value> {
+    pub fn additional_properties_mut(&mut self) -> &mut IndexMap<String, serde_json::Value> {
         &mut self.additional_properties
     }
 }
@@ -125,7 +162,8 @@ mod tests {
     use crate::{
         test_data,
         url::ParseVersionedUrlError,
-        utils::tests::{check_serialization_from_str, ensure_failed_validation},
+        utils::tests::{check_serialization_from_str, data_type_json, ensure_failed_validation},
+        JsonPointer,
     };
 
     #[test]
@@ -170,10 +208,240 @@ mod tests {
 
     #[test]
     fn empty_list() {
-        check_serialization_from_str::<DataType, raw::DataType>(
+        let data_type = check_serialization_from_str::<DataType, raw::DataType>(
             test_data::data_type::EMPTY_LIST_V1,
             None,
         );
+
+        assert_eq!(data_type.json_type(), JsonType::Array);
+        assert_eq!(data_type.constraints().const_value, Some(json!([])));
+        assert!(data_type.additional_properties().is_empty());
+    }
+
+    #[test]
+    fn constrained_string() {
+        let data_type = check_serialization_from_str::<DataType, raw::DataType>(
+            &data_type_json(
+                "constrained/v/1",
+                "string",
+                &json!({
+                    "minLength": 1,
+                    "maxLength": 10,
+                    "pattern": "^[a-z]+$",
+                    "format": "hostname",
+                    "enum": ["foo", "bar"],
+                    "$comment": "not a constraint"
+                }),
+            )
+            .to_string(),
+            None,
+        );
+
+        let constraints = data_type.constraints();
+        assert_eq!(constraints.min_length, Some(1));
+        assert_eq!(constraints.max_length, Some(10));
+        assert_eq!(
+            constraints.pattern.as_ref().map(Pattern::as_str),
+            Some("^[a-z]+$")
+        );
+        assert_eq!(constraints.format.as_deref(), Some("hostname"));
+        assert_eq!(
+            constraints.enum_values,
+            Some(vec![json!("foo"), json!("bar")])
+        );
+        assert_eq!(
+            data_type.additional_properties(),
+            &IndexMap::from([("$comment".to_owned(), json!("not a constraint"))])
+        );
+    }
+
+    #[test]
+    fn constrained_number() {
+        let data_type = check_serialization_from_str::<DataType, raw::DataType>(
+            &data_type_json(
+                "constrained/v/1",
+                "integer",
+                &json!({ "minimum": -5, "maximum": 10.5, "multipleOf": 5 }),
+            )
+            .to_string(),
+            None,
+        );
+
+        assert_eq!(data_type.json_type(), JsonType::Integer);
+        let constraints = data_type.constraints();
+        assert_eq!(constraints.minimum, Some((-5).into()));
+        assert_eq!(constraints.maximum, serde_json::Number::from_f64(10.5));
+        assert_eq!(constraints.multiple_of, Some(5.into()));
+    }
+
+    #[test]
+    fn const_null() {
+        let data_type = check_serialization_from_str::<DataType, raw::DataType>(
+            &data_type_json("constrained/v/1", "null", &json!({ "const": null })).to_string(),
+            None,
+        );
+
+        assert_eq!(
+            data_type.constraints().const_value,
+            Some(serde_json::Value::Null)
+        );
+    }
+
+    fn invalid_constraints(keyword: &str, error: DataTypeConstraintError) -> ParseDataTypeError {
+        ParseDataTypeError::InvalidConstraints {
+            path: JsonPointer::root().join(keyword),
+            error,
+        }
+    }
+
+    #[test]
+    fn unknown_json_type() {
+        ensure_failed_validation::<raw::DataType, DataType>(
+            &data_type_json("constrained/v/1", "text", &json!({})),
+            ParseDataTypeError::UnknownJsonType {
+                path: JsonPointer::root().join("type"),
+                json_type: "text".to_owned(),
+            },
+        );
+    }
+
+    #[test]
+    fn contradictory_constraints() {
+        ensure_failed_validation::<raw::DataType, DataType>(
+            &data_type_json("constrained/v/1", "string", &json!({ "minimum": 1 })),
+            invalid_constraints("minimum", DataTypeConstraintError::InapplicableKeyword {
+                keyword: "minimum".to_owned(),
+                json_type: JsonType::String,
+            }),
+        );
+        ensure_failed_validation::<raw::DataType, DataType>(
+            &data_type_json("constrained/v/1", "number", &json!({ "pattern": "a" })),
+            invalid_constraints("pattern", DataTypeConstraintError::InapplicableKeyword {
+                keyword: "pattern".to_owned(),
+                json_type: JsonType::Number,
+            }),
+        );
+        ensure_failed_validation::<raw::DataType, DataType>(
+            &data_type_json(
+                "constrained/v/1",
+                "number",
+                &json!({ "minimum": 10, "maximum": 1.5 }),
+            ),
+            invalid_constraints("minimum", DataTypeConstraintError::MinimumExceedsMaximum {
+                minimum: 10.into(),
+                maximum: serde_json::Number::from_f64(1.5).expect("invalid number"),
+            }),
+        );
+        ensure_failed_validation::<raw::DataType, DataType>(
+            &data_type_json(
+                "constrained/v/1",
+                "string",
+                &json!({ "minLength": 10, "maxLength": 1 }),
+            ),
+            invalid_constraints(
+                "minLength",
+                DataTypeConstraintError::MinLengthExceedsMaxLength {
+                    min_length: 10,
+                    max_length: 1,
+                },
+            ),
+        );
+        ensure_failed_validation::<raw::DataType, DataType>(
+            &data_type_json("constrained/v/1", "number", &json!({ "multipleOf": 0 })),
+            invalid_constraints(
+                "multipleOf",
+                DataTypeConstraintError::NonPositiveMultipleOf(0.into()),
+            ),
+        );
+        ensure_failed_validation::<raw::DataType, DataType>(
+            &data_type_json("constrained/v/1", "string", &json!({ "enum": [] })),
+            invalid_constraints("enum", DataTypeConstraintError::EmptyEnum),
+        );
+        ensure_failed_validation::<raw::DataType, DataType>(
+            &data_type_json("constrained/v/1", "string", &json!({ "enum": ["a", 1] })),
+            invalid_constraints("enum", DataTypeConstraintError::MismatchedValueType {
+                keyword: "enum".to_owned(),
+                value: json!(1),
+                json_type: JsonType::String,
+            }),
+        );
+        ensure_failed_validation::<raw::DataType, DataType>(
+            &data_type_json(
+                "constrained/v/1",
+                "string",
+                &json!({ "enum": ["a"], "const": "b" }),
+            ),
+            invalid_constraints("const", DataTypeConstraintError::ConstNotInEnum(json!("b"))),
+        );
+    }
+
+    #[test]
+    fn parse_all() {
+        let mut data_type = data_type_json(
+            "constrained/v/1",
+            "string",
+            &json!({ "minimum": 1, "minLength": 10, "maxLength": 1, "enum": ["a", 1] }),
+        );
+        data_type["$id"] = json!("https://example.com/data-type/constrained/v/");
+
+        assert_eq!(
+            DataType::parse_all(data_type),
+            Err(vec![
+                ParseDataTypeError::InvalidVersionedUrl {
+                    path: JsonPointer::root().join("$id"),
+                    error: ParseVersionedUrlError::MissingVersion,
+                },
+                invalid_constraints("minimum", DataTypeConstraintError::InapplicableKeyword {
+                    keyword: "minimum".to_owned(),
+                    json_type: JsonType::String,
+                }),
+                invalid_constraints(
+                    "minLength",
+                    DataTypeConstraintError::MinLengthExceedsMaxLength {
+                        min_length: 10,
+                        max_length: 1,
+                    },
+                ),
+                invalid_constraints("enum", DataTypeConstraintError::MismatchedValueType {
+                    keyword: "enum".to_owned(),
+                    value: json!(1),
+                    json_type: JsonType::String,
+                }),
+            ])
+        );
+    }
+
+    #[test]
+    fn parse_all_unknown_json_type() {
+        assert_eq!(
+            DataType::parse_all(data_type_json(
+                "constrained/v/1",
+                "text",
+                &json!({ "minimum": 1 })
+            )),
+            Err(vec![ParseDataTypeError::UnknownJsonType {
+                path: JsonPointer::root().join("type"),
+                json_type: "text".to_owned(),
+            }])
+        );
+    }
+
+    #[test]
+    fn invalid_pattern() {
+        let repr: raw::DataType = serde_json::from_value(data_type_json(
+            "constrained/v/1",
+            "string",
+            &json!({ "pattern": "(" }),
+        ))
+        .expect("failed to deserialize");
+
+        assert!(matches!(
+            DataType::try_from(repr),
+            Err(ParseDataTypeError::InvalidConstraints {
+                path,
+                error: DataTypeConstraintError::InvalidPattern(_),
+            }) if path == JsonPointer::root().join("pattern")
+        ));
     }
 
     #[test]
@@ -191,7 +459,10 @@ mod tests {
                   "type": "string"
                 }
             ),
-            ParseDataTypeError::InvalidMetaSchema(invalid_schema_url.to_owned()),
+            ParseDataTypeError::InvalidMetaSchema {
+                path: JsonPointer::root().join("$schema"),
+                meta_schema: invalid_schema_url.to_owned(),
+            },
         );
     }
 
@@ -208,9 +479,10 @@ mod tests {
                   "type": "string"
                 }
             ),
-            ParseDataTypeError::InvalidVersionedUrl(ParseVersionedUrlError::AdditionalEndContent(
-                ".5".to_owned(),
-            )),
+            ParseDataTypeError::InvalidVersionedUrl {
+                path: JsonPointer::root().join("$id"),
+                error: ParseVersionedUrlError::AdditionalEndContent(".5".to_owned()),
+            },
         );
     }
 
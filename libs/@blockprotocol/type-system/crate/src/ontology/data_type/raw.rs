@@ -1,15 +1,18 @@
-use std::{collections::HashMap, str::FromStr};
+use std::str::FromStr;
 
-use serde::{Deserialize, Serialize};
+use indexmap::IndexMap;
+use serde::{Deserialize, Deserializer, Serialize};
 #[cfg(target_arch = "wasm32")]
 use tsify::Tsify;
 
 use crate::{
+    ontology::shared::parse::{first_error, TryFromRaw},
     url::{ParseVersionedUrlError, VersionedUrl},
-    ParseDataTypeError,
+    DataTypeConstraints, JsonPointer, JsonType, ParseDataTypeError, Pattern,
 };
 
-const META_SCHEMA_ID: &str = "https://blockprotocol.org/types/modules/graph/0.3/schema/data-type";
+pub(super) const META_SCHEMA_ID: &str =
+    "https://blockprotocol.org/types/modules/graph/0.3/schema/data-type";
 
 /// Will serialize as a constant value `"dataType"`
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
@@ -39,45 +42,164 @@ pub struct DataType {
     description: Option<String>,
     #[serde(rename = "type")]
     json_type: String,
+    #[cfg_attr(target_arch = "wasm32", tsify(optional, type = "number"))]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    minimum: Option<serde_json::Number>,
+    #[cfg_attr(target_arch = "wasm32", tsify(optional, type = "number"))]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    maximum: Option<serde_json::Number>,
+    #[cfg_attr(target_arch = "wasm32", tsify(optional, type = "number"))]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    multiple_of: Option<serde_json::Number>,
+    #[cfg_attr(target_arch = "wasm32", tsify(optional))]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    min_length: Option<usize>,
+    #[cfg_attr(target_arch = "wasm32", tsify(optional))]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    max_length: Option<usize>,
+    #[cfg_attr(target_arch = "wasm32", tsify(optional))]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pattern: Option<String>,
+    #[cfg_attr(target_arch = "wasm32", tsify(optional))]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    format: Option<String>,
+    #[cfg_attr(target_arch = "wasm32", tsify(optional, type = "any[]"))]
+    #[serde(rename = "enum", default, skip_serializing_if = "Option::is_none")]
+    enum_values: Option<Vec<serde_json::Value>>,
+    // `"const": null` is a valid constraint, so a present `null` must not be deserialized as
+    // `None`
+    #[cfg_attr(target_arch = "wasm32", tsify(optional, type = "any"))]
+    #[serde(
+        rename = "const",
+        default,
+        deserialize_with = "deserialize_present_value",
+        skip_serializing_if = "Option::is_none"
+    )]
+    const_value: Option<serde_json::Value>,
     /// Properties which are not currently strongly typed.
     ///
     /// The data type meta-schema currently allows arbitrary, untyped properties. This is a
-    /// catch-all field to store all non-typed data.
+    /// catch-all field to store all keywords which are not covered by the fields above.
     #[cfg_attr(target_arch = "wasm32", tsify(type = "Record<string, any>"))]
     #[serde(flatten)]
-    additional_properties: HashMap<String, serde_json::Value>,
+    additional_properties: IndexMap<String, serde_json::Value>,
 }
 
-impl TryFrom<DataType> for super::DataType {
+fn deserialize_present_value<'de, D>(deserializer: D) -> Result<Option<serde_json::Value>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    serde_json::Value::deserialize(deserializer).map(Some)
+}
+
+impl TryFromRaw<DataType> for super::DataType {
     type Error = ParseDataTypeError;
 
-    fn try_from(data_type_repr: DataType) -> Result<Self, Self::Error> {
+    fn try_from_raw(data_type_repr: DataType) -> Result<Self, Vec<Self::Error>> {
+        let path = JsonPointer::root();
+        let mut errors = Vec::new();
+
         let id = VersionedUrl::from_str(&data_type_repr.id)
-            .map_err(ParseDataTypeError::InvalidVersionedUrl)?;
+            .map_err(|error| {
+                errors.push(ParseDataTypeError::InvalidVersionedUrl {
+                    path: path.join("$id"),
+                    error,
+                });
+            })
+            .ok();
 
         if data_type_repr.schema != META_SCHEMA_ID {
-            return Err(ParseDataTypeError::InvalidMetaSchema(data_type_repr.schema));
+            errors.push(ParseDataTypeError::InvalidMetaSchema {
+                path: path.join("$schema"),
+                meta_schema: data_type_repr.schema,
+            });
+        }
+
+        let json_type = JsonType::from_str(&data_type_repr.json_type)
+            .map_err(|json_type| {
+                errors.push(ParseDataTypeError::UnknownJsonType {
+                    path: path.join("type"),
+                    json_type,
+                });
+            })
+            .ok();
+
+        let constraints = DataTypeConstraints {
+            minimum: data_type_repr.minimum,
+            maximum: data_type_repr.maximum,
+            multiple_of: data_type_repr.multiple_of,
+            min_length: data_type_repr.min_length,
+            max_length: data_type_repr.max_length,
+            pattern: data_type_repr.pattern.as_deref().and_then(|pattern| {
+                Pattern::new(pattern)
+                    .map_err(|error| {
+                        errors.push(ParseDataTypeError::InvalidConstraints {
+                            path: path.join(error.keyword()),
+                            error,
+                        });
+                    })
+                    .ok()
+            }),
+            format: data_type_repr.format,
+            enum_values: data_type_repr.enum_values,
+            const_value: data_type_repr.const_value,
+        };
+
+        // The constraints can only be checked against a known type
+        if let Some(json_type) = json_type {
+            let mut constraint_errors = Vec::new();
+            constraints.validate_at(json_type, &mut constraint_errors);
+            errors.extend(constraint_errors.into_iter().map(|error| {
+                ParseDataTypeError::InvalidConstraints {
+                    path: path.join(error.keyword()),
+                    error,
+                }
+            }));
+        }
+
+        match (id, json_type) {
+            (Some(id), Some(json_type)) if errors.is_empty() => Ok(Self {
+                id,
+                title: data_type_repr.title,
+                description: data_type_repr.description,
+                json_type,
+                constraints,
+                additional_properties: data_type_repr.additional_properties,
+            }),
+            _ => Err(errors),
         }
+    }
+}
 
-        Ok(Self::new(
-            id,
-            data_type_repr.title,
-            data_type_repr.description,
-            data_type_repr.json_type,
-            data_type_repr.additional_properties,
-        ))
+impl TryFrom<DataType> for super::DataType {
+    type Error = ParseDataTypeError;
+
+    fn try_from(data_type_repr: DataType) -> Result<Self, Self::Error> {
+        first_error(Self::try_from_raw(data_type_repr))
     }
 }
 
 impl From<super::DataType> for DataType {
     fn from(data_type: super::DataType) -> Self {
+        let constraints = data_type.constraints;
         Self {
             schema: META_SCHEMA_ID.to_owned(),
             kind: DataTypeTag::DataType,
             id: data_type.id.to_string(),
             title: data_type.title,
             description: data_type.description,
-            json_type: data_type.json_type,
+            json_type: data_type.json_type.to_string(),
+            minimum: constraints.minimum,
+            maximum: constraints.maximum,
+            multiple_of: constraints.multiple_of,
+            min_length: constraints.min_length,
+            max_length: constraints.max_length,
+            pattern: constraints
+                .pattern
+                .map(|pattern| pattern.as_str().to_owned()),
+            format: constraints.format,
+            enum_values: constraints.enum_values,
+            const_value: constraints.const_value,
             additional_properties: data_type.additional_properties,
         }
     }
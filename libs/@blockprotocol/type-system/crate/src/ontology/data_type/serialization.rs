@@ -0,0 +1,294 @@
+use std::str::FromStr;
+
+use indexmap::IndexMap;
+use serde::{
+    de::{self, MapAccess},
+    ser::SerializeMap,
+    Deserialize, Deserializer, Serialize, Serializer,
+};
+
+use super::raw::META_SCHEMA_ID;
+use crate::{
+    ontology::shared::serialization::{
+        deserialize_map, deserialize_reference, next_id, next_meta_schema, next_tag, next_value,
+        required, required_tag, serialize_optional_entry, serialize_reference, FromMap, Key,
+    },
+    DataType, DataTypeConstraints, DataTypeReference, JsonPointer, JsonType, ParseDataTypeError,
+    Pattern,
+};
+
+impl Serialize for DataType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let constraints = &self.constraints;
+
+        // A map is used as the additional properties are flattened into the data type
+        let mut map = serializer.serialize_map(None)?;
+        map.serialize_entry("$schema", META_SCHEMA_ID)?;
+        map.serialize_entry("kind", "dataType")?;
+        map.serialize_entry("$id", &self.id)?;
+        map.serialize_entry("title", &self.title)?;
+        serialize_optional_entry(&mut map, "description", self.description.as_ref())?;
+        map.serialize_entry("type", self.json_type.as_str())?;
+        serialize_optional_entry(&mut map, "minimum", constraints.minimum.as_ref())?;
+        serialize_optional_entry(&mut map, "maximum", constraints.maximum.as_ref())?;
+        serialize_optional_entry(&mut map, "multipleOf", constraints.multiple_of.as_ref())?;
+        serialize_optional_entry(&mut map, "minLength", constraints.min_length.as_ref())?;
+        serialize_optional_entry(&mut map, "maxLength", constraints.max_length.as_ref())?;
+        serialize_optional_entry(
+            &mut map,
+            "pattern",
+            constraints.pattern.as_ref().map(Pattern::as_str),
+        )?;
+        serialize_optional_entry(&mut map, "format", constraints.format.as_ref())?;
+        serialize_optional_entry(&mut map, "enum", constraints.enum_values.as_ref())?;
+        serialize_optional_entry(&mut map, "const", constraints.const_value.as_ref())?;
+        for (key, value) in &self.additional_properties {
+            map.serialize_entry(key, value)?;
+        }
+        map.end()
+    }
+}
+
+impl<'de> FromMap<'de> for DataType {
+    const EXPECTING: &'static str = "a data type";
+
+    /// Deserializes a data type, keeping every unknown keyword as an additional property.
+    ///
+    /// The constraints are checked once the `type` is known, at the end of the data type.
+    fn from_map<A>(mut map: A) -> Result<Self, A::Error>
+    where
+        A: MapAccess<'de>,
+    {
+        let path = JsonPointer::root();
+
+        let mut schema = false;
+        let mut kind = false;
+        let mut id = None;
+        let mut title = None;
+        let mut description = None;
+        let mut json_type = None;
+        let mut constraints = DataTypeConstraints::default();
+        let mut additional_properties = IndexMap::new();
+
+        // Optional keywords which are explicitly `null` are deserialized as absent
+        let mut minimum = None;
+        let mut maximum = None;
+        let mut multiple_of = None;
+        let mut min_length = None;
+        let mut max_length = None;
+        let mut format = None;
+        let mut enum_values = None;
+
+        while let Some(key) = map.next_key::<Key>()? {
+            match &*key {
+                "$schema" => {
+                    next_meta_schema(&mut map, &mut schema, META_SCHEMA_ID, |meta_schema| {
+                        ParseDataTypeError::InvalidMetaSchema {
+                            path: path.join("$schema"),
+                            meta_schema,
+                        }
+                    })?;
+                }
+                "kind" => next_tag(&mut map, &mut kind, "kind", "dataType")?,
+                "$id" => {
+                    next_id(&mut map, &mut id, |error| {
+                        ParseDataTypeError::InvalidVersionedUrl {
+                            path: path.join("$id"),
+                            error,
+                        }
+                    })?;
+                }
+                "title" => next_value(&mut map, &mut title, "title")?,
+                "description" => next_value(&mut map, &mut description, "description")?,
+                "type" => {
+                    if json_type.is_some() {
+                        return Err(de::Error::duplicate_field("type"));
+                    }
+                    let value = map.next_value::<Key>()?;
+                    json_type = Some(JsonType::from_str(&value).map_err(|json_type| {
+                        de::Error::custom(ParseDataTypeError::UnknownJsonType {
+                            path: path.join("type"),
+                            json_type,
+                        })
+                    })?);
+                }
+                "minimum" => next_value(&mut map, &mut minimum, "minimum")?,
+                "maximum" => next_value(&mut map, &mut maximum, "maximum")?,
+                "multipleOf" => next_value(&mut map, &mut multiple_of, "multipleOf")?,
+                "minLength" => next_value(&mut map, &mut min_length, "minLength")?,
+                "maxLength" => next_value(&mut map, &mut max_length, "maxLength")?,
+                "pattern" => {
+                    if constraints.pattern.is_some() {
+                        return Err(de::Error::duplicate_field("pattern"));
+                    }
+                    if let Some(pattern) = map.next_value::<Option<Key>>()? {
+                        constraints.pattern = Some(Pattern::new(&pattern).map_err(|error| {
+                            de::Error::custom(ParseDataTypeError::InvalidConstraints {
+                                path: path.join(error.keyword()),
+                                error,
+                            })
+                        })?);
+                    }
+                }
+                "format" => next_value(&mut map, &mut format, "format")?,
+                "enum" => next_value(&mut map, &mut enum_values, "enum")?,
+                // `"const": null` is a valid constraint, so it's not deserialized as absent
+                "const" => next_value(&mut map, &mut constraints.const_value, "const")?,
+                _ => {
+                    let value = map.next_value()?;
+                    additional_properties.insert(key.into_owned(), value);
+                }
+            }
+        }
+
+        required_tag(schema, "$schema")?;
+        required_tag(kind, "kind")?;
+        let json_type = required(json_type, "type")?;
+        constraints.minimum = minimum.flatten();
+        constraints.maximum = maximum.flatten();
+        constraints.multiple_of = multiple_of.flatten();
+        constraints.min_length = min_length.flatten();
+        constraints.max_length = max_length.flatten();
+        constraints.format = format.flatten();
+        constraints.enum_values = enum_values.flatten();
+
+        constraints.validate(json_type).map_err(|error| {
+            de::Error::custom(ParseDataTypeError::InvalidConstraints {
+                path: path.join(error.keyword()),
+                error,
+            })
+        })?;
+
+        Ok(Self {
+            id: required(id, "$id")?,
+            title: required(title, "title")?,
+            description: description.flatten(),
+            json_type,
+            constraints,
+            additional_properties,
+        })
+    }
+}
+
+impl<'de> Deserialize<'de> for DataType {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserialize_map(deserializer)
+    }
+}
+
+impl Serialize for DataTypeReference {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serialize_reference("DataTypeReference", self.url(), serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for DataTypeReference {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserialize_reference(deserializer).map(Self::new)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+    use crate::{raw, test_data, utils::tests::data_type_json};
+
+    fn deserialize(data_type: serde_json::Value) -> Result<DataType, String> {
+        serde_json::from_value(data_type).map_err(|error| error.to_string())
+    }
+
+    #[test]
+    fn same_as_raw() {
+        for input in [
+            test_data::data_type::BOOLEAN_V1,
+            test_data::data_type::EMPTY_LIST_V1,
+            test_data::data_type::NULL_V1,
+            test_data::data_type::NUMBER_V1,
+            test_data::data_type::OBJECT_V1,
+            test_data::data_type::TEXT_V1,
+        ] {
+            let data_type: DataType =
+                serde_json::from_str(input).expect("failed to deserialize data type");
+            let data_type_repr: raw::DataType =
+                serde_json::from_str(input).expect("failed to deserialize data type repr");
+
+            assert_eq!(
+                Ok(&data_type),
+                DataType::try_from(data_type_repr.clone()).as_ref()
+            );
+            assert_eq!(
+                serde_json::to_string(&data_type).expect("failed to serialize data type"),
+                serde_json::to_string(&data_type_repr).expect("failed to serialize data type repr")
+            );
+        }
+    }
+
+    #[test]
+    fn constraints() {
+        let input = data_type_json(
+            "example/v/1",
+            "string",
+            &json!({
+                "minLength": 1,
+                "maxLength": null,
+                "pattern": "^[a-z]+$",
+                "additional": { "key": "value" },
+            }),
+        );
+        let data_type = deserialize(input).expect("failed to deserialize data type");
+        assert_eq!(data_type.constraints().min_length, Some(1));
+        assert_eq!(data_type.constraints().max_length, None);
+        assert_eq!(
+            data_type
+                .constraints()
+                .pattern
+                .as_ref()
+                .map(Pattern::as_str),
+            Some("^[a-z]+$")
+        );
+        assert_eq!(
+            data_type.additional_properties().get("additional"),
+            Some(&json!({ "key": "value" }))
+        );
+    }
+
+    #[test]
+    fn validated() {
+        let error = deserialize(data_type_json(
+            "example/v/1",
+            "string",
+            &json!({ "minimum": 1 }),
+        ))
+        .expect_err("deserialized an inapplicable constraint");
+        assert!(
+            error.starts_with("invalid constraints at `/minimum`"),
+            "{error}"
+        );
+
+        let error = deserialize(data_type_json("example/v/1", "text", &json!({})))
+            .expect_err("deserialized an unknown type");
+        assert!(error.starts_with("unknown JSON type at `/type`"), "{error}");
+
+        let mut input = data_type_json("example/v/1", "string", &json!({}));
+        input["$schema"] = json!("https://example.com/schema");
+        let error = deserialize(input).expect_err("deserialized an unknown meta schema");
+        assert!(
+            error.starts_with("invalid `$schema` property at `/$schema`"),
+            "{error}"
+        );
+    }
+}
@@ -0,0 +1,427 @@
+use std::{cmp::Ordering, sync::OnceLock};
+
+use regex::Regex;
+use serde_json::Number;
+
+use crate::{
+    ontology::data_type::constraints::{compare_numbers, values_equal},
+    DataType, InstanceValidationError, InstanceValidationReason, JsonPointer, JsonType,
+};
+
+impl DataType {
+    /// Validates `value` against this data type.
+    ///
+    /// Besides the JSON type of the value, all [`constraints`] of the data type are checked. The
+    /// `format` keyword is only asserted for the formats `date`, `time`, `date-time`, `email`,
+    /// `hostname`, `uri`, `uuid` and `regex`, other formats are treated as annotations.
+    ///
+    /// # Errors
+    ///
+    /// - every [`InstanceValidationError`] found if `value` is not a valid instance of this data
+    ///   type
+    ///
+    /// [`constraints`]: Self::constraints
+    pub fn validate_value(
+        &self,
+        value: &serde_json::Value,
+    ) -> Result<(), Vec<InstanceValidationError>> {
+        let mut errors = Vec::new();
+        self.validate_value_at(value, &JsonPointer::root(), &mut errors);
+
+        if errors.is_empty() {
+            Ok(())
+        } else {
+            Err(errors)
+        }
+    }
+
+    /// Validates `value` against this data type, pushing every problem found to `errors`.
+    pub(crate) fn validate_value_at(
+        &self,
+        value: &serde_json::Value,
+        path: &JsonPointer,
+        errors: &mut Vec<InstanceValidationError>,
+    ) {
+        if !self.json_type.is_type_of(value) {
+            errors.push(InstanceValidationError::new(
+                path.clone(),
+                InstanceValidationReason::InvalidType {
+                    expected: self.json_type,
+                    actual: JsonType::of(value),
+                },
+            ));
+            return;
+        }
+
+        let mut push = |reason| errors.push(InstanceValidationError::new(path.clone(), reason));
+        let constraints = &self.constraints;
+
+        if let Some(const_value) = &constraints.const_value {
+            if !values_equal(value, const_value) {
+                push(InstanceValidationReason::ConstMismatch {
+                    expected: const_value.clone(),
+                });
+            }
+        }
+
+        if let Some(enum_values) = &constraints.enum_values {
+            if !enum_values
+                .iter()
+                .any(|enum_value| values_equal(enum_value, value))
+            {
+                push(InstanceValidationReason::NotInEnum {
+                    expected: enum_values.clone(),
+                });
+            }
+        }
+
+        match value {
+            serde_json::Value::Number(number) => {
+                if let Some(minimum) = &constraints.minimum {
+                    if compare_numbers(number, minimum) == Some(Ordering::Less) {
+                        push(InstanceValidationReason::BelowMinimum {
+                            actual: number.clone(),
+                            minimum: minimum.clone(),
+                        });
+                    }
+                }
+                if let Some(maximum) = &constraints.maximum {
+                    if compare_numbers(number, maximum) == Some(Ordering::Greater) {
+                        push(InstanceValidationReason::AboveMaximum {
+                            actual: number.clone(),
+                            maximum: maximum.clone(),
+                        });
+                    }
+                }
+                if let Some(multiple_of) = &constraints.multiple_of {
+                    if !is_multiple_of(number, multiple_of) {
+                        push(InstanceValidationReason::NotMultipleOf {
+                            actual: number.clone(),
+                            multiple_of: multiple_of.clone(),
+                        });
+                    }
+                }
+            }
+            serde_json::Value::String(string) => {
+                // JSON Schema measures the length of a string in Unicode code points
+                let length = string.chars().count();
+                if let Some(min_length) = constraints.min_length {
+                    if length < min_length {
+                        push(InstanceValidationReason::TooShort {
+                            actual: length,
+                            min_length,
+                        });
+                    }
+                }
+                if let Some(max_length) = constraints.max_length {
+                    if length > max_length {
+                        push(InstanceValidationReason::TooLong {
+                            actual: length,
+                            max_length,
+                        });
+                    }
+                }
+                if let Some(pattern) = &constraints.pattern {
+                    if !pattern.is_match(string) {
+                        push(InstanceValidationReason::PatternMismatch {
+                            pattern: pattern.as_str().to_owned(),
+                        });
+                    }
+                }
+                if let Some(format) = &constraints.format {
+                    if !matches_format(format, string) {
+                        push(InstanceValidationReason::InvalidFormat {
+                            format: format.clone(),
+                        });
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
+/// Returns if `value` is an integer multiple of `divisor`.
+///
+/// Integers are checked exactly, everything else is checked as `f64` allowing for rounding
+/// errors.
+#[expect(
+    clippy::float_arithmetic,
+    reason = "JSON Schema allows arbitrary decimal divisors for `multipleOf`"
+)]
+pub(super) fn is_multiple_of(value: &Number, divisor: &Number) -> bool {
+    if let (Some(value), Some(divisor)) = (value.as_i64(), divisor.as_i64()) {
+        return value.checked_rem(divisor) == Some(0);
+    }
+    if let (Some(value), Some(divisor)) = (value.as_u64(), divisor.as_u64()) {
+        return value.checked_rem(divisor) == Some(0);
+    }
+
+    let (Some(value), Some(divisor)) = (value.as_f64(), divisor.as_f64()) else {
+        return false;
+    };
+    let quotient = value / divisor;
+    quotient.is_finite() && (quotient - quotient.round()).abs() <= f64::EPSILON * quotient.abs()
+}
+
+/// Returns if `value` is valid for the given `format`.
+///
+/// Unknown formats are only annotations and always match.
+fn matches_format(format: &str, value: &str) -> bool {
+    fn regex(cell: &'static OnceLock<Regex>, pattern: &str) -> &'static Regex {
+        cell.get_or_init(|| Regex::new(pattern).expect("format pattern should be valid"))
+    }
+
+    static DATE: OnceLock<Regex> = OnceLock::new();
+    static TIME: OnceLock<Regex> = OnceLock::new();
+    static DATE_TIME: OnceLock<Regex> = OnceLock::new();
+    static EMAIL: OnceLock<Regex> = OnceLock::new();
+    static HOSTNAME: OnceLock<Regex> = OnceLock::new();
+    static UUID: OnceLock<Regex> = OnceLock::new();
+
+    const DATE_PATTERN: &str = r"\d{4}-(0[1-9]|1[0-2])-(0[1-9]|[12]\d|3[01])";
+    const TIME_PATTERN: &str =
+        r"([01]\d|2[0-3]):[0-5]\d:([0-5]\d|60)(\.\d+)?([zZ]|[+-]([01]\d|2[0-3]):[0-5]\d)";
+
+    match format {
+        "date" => regex(&DATE, &format!("^{DATE_PATTERN}$")).is_match(value),
+        "time" => regex(&TIME, &format!("^{TIME_PATTERN}$")).is_match(value),
+        "date-time" => {
+            regex(&DATE_TIME, &format!("^{DATE_PATTERN}[tT ]{TIME_PATTERN}$")).is_match(value)
+        }
+        "email" => regex(&EMAIL, r"^[^@\s]+@[^@\s]+$").is_match(value),
+        "hostname" => regex(
+            &HOSTNAME,
+            r"^(?i)[a-z0-9]([a-z0-9-]{0,61}[a-z0-9])?(\.[a-z0-9]([a-z0-9-]{0,61}[a-z0-9])?)*$",
+        )
+        .is_match(value),
+        "uri" => url::Url::parse(value).is_ok(),
+        "uuid" => regex(
+            &UUID,
+            "^(?i)[0-9a-f]{8}-[0-9a-f]{4}-[0-9a-f]{4}-[0-9a-f]{4}-[0-9a-f]{12}$",
+        )
+        .is_match(value),
+        "regex" => Regex::new(value).is_ok(),
+        _ => true,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+    use crate::utils::tests::{data_type, data_type_json};
+
+    fn reasons(data_type: &DataType, value: &serde_json::Value) -> Vec<InstanceValidationReason> {
+        data_type.validate_value(value).map_or_else(
+            |errors| errors.into_iter().map(|error| error.reason).collect(),
+            |()| Vec::new(),
+        )
+    }
+
+    #[test]
+    fn json_type() {
+        let integer =
+            data_type(&data_type_json("constrained/v/1", "integer", &json!({})).to_string());
+        integer
+            .validate_value(&json!(1))
+            .expect("validation failed");
+        integer
+            .validate_value(&json!(1.0))
+            .expect("validation failed");
+        assert_eq!(reasons(&integer, &json!(1.5)), [
+            InstanceValidationReason::InvalidType {
+                expected: JsonType::Integer,
+                actual: JsonType::Number,
+            }
+        ]);
+        assert_eq!(reasons(&integer, &json!("1")), [
+            InstanceValidationReason::InvalidType {
+                expected: JsonType::Integer,
+                actual: JsonType::String,
+            }
+        ]);
+
+        let null = data_type(&data_type_json("constrained/v/1", "null", &json!({})).to_string());
+        null.validate_value(&json!(null))
+            .expect("validation failed");
+        assert_eq!(reasons(&null, &json!(false)), [
+            InstanceValidationReason::InvalidType {
+                expected: JsonType::Null,
+                actual: JsonType::Boolean,
+            }
+        ]);
+    }
+
+    #[test]
+    fn number_constraints() {
+        let number = data_type(
+            &data_type_json(
+                "constrained/v/1",
+                "number",
+                &json!({ "minimum": 0, "maximum": 10, "multipleOf": 0.5 }),
+            )
+            .to_string(),
+        );
+
+        for valid in [json!(0), json!(2.5), json!(10)] {
+            number.validate_value(&valid).expect("validation failed");
+        }
+        assert_eq!(reasons(&number, &json!(-1)), [
+            InstanceValidationReason::BelowMinimum {
+                actual: (-1).into(),
+                minimum: 0.into(),
+            }
+        ]);
+        assert_eq!(reasons(&number, &json!(10.25)), [
+            InstanceValidationReason::AboveMaximum {
+                actual: Number::from_f64(10.25).expect("invalid number"),
+                maximum: 10.into(),
+            },
+            InstanceValidationReason::NotMultipleOf {
+                actual: Number::from_f64(10.25).expect("invalid number"),
+                multiple_of: Number::from_f64(0.5).expect("invalid number"),
+            },
+        ]);
+
+        let decimal = data_type(
+            &data_type_json("constrained/v/1", "number", &json!({ "multipleOf": 0.01 }))
+                .to_string(),
+        );
+        decimal
+            .validate_value(&json!(19.99))
+            .expect("validation failed");
+    }
+
+    #[test]
+    fn string_constraints() {
+        let string = data_type(
+            &data_type_json(
+                "constrained/v/1",
+                "string",
+                &json!({ "minLength": 2, "maxLength": 3, "pattern": "^[a-z\u{e4}]+$" }),
+            )
+            .to_string(),
+        );
+
+        string
+            .validate_value(&json!("\u{e4}b"))
+            .expect("validation failed");
+        assert_eq!(reasons(&string, &json!("a")), [
+            InstanceValidationReason::TooShort {
+                actual: 1,
+                min_length: 2,
+            }
+        ]);
+        assert_eq!(reasons(&string, &json!("abC1")), [
+            InstanceValidationReason::TooLong {
+                actual: 4,
+                max_length: 3,
+            },
+            InstanceValidationReason::PatternMismatch {
+                pattern: "^[a-z\u{e4}]+$".to_owned(),
+            },
+        ]);
+    }
+
+    #[test]
+    fn format() {
+        for (format, valid, invalid) in [
+            ("date", "2023-10-01", "2023-13-01"),
+            ("time", "12:30:00Z", "12:30"),
+            ("date-time", "2023-10-01T12:30:00.5+02:00", "2023-10-01"),
+            ("email", "alice@example.com", "alice"),
+            ("hostname", "blockprotocol.org", "-example.com"),
+            ("uri", "https://blockprotocol.org", "blockprotocol.org"),
+            ("uuid", "67e55044-10b1-426f-9247-bb680e5fe0c8", "67e55044"),
+            ("regex", "^a+$", "("),
+        ] {
+            let data_type = data_type(
+                &data_type_json("constrained/v/1", "string", &json!({ "format": format }))
+                    .to_string(),
+            );
+            data_type
+                .validate_value(&json!(valid))
+                .unwrap_or_else(|_| panic!("`{valid}` should be a valid `{format}`"));
+            assert_eq!(reasons(&data_type, &json!(invalid)), [
+                InstanceValidationReason::InvalidFormat {
+                    format: format.to_owned(),
+                }
+            ]);
+        }
+
+        data_type(
+            &data_type_json("constrained/v/1", "string", &json!({ "format": "unknown" }))
+                .to_string(),
+        )
+        .validate_value(&json!("anything"))
+        .expect("validation failed");
+    }
+
+    #[test]
+    fn enum_and_const() {
+        let enumeration = data_type(
+            &data_type_json("constrained/v/1", "string", &json!({ "enum": ["a", "b"] }))
+                .to_string(),
+        );
+        enumeration
+            .validate_value(&json!("b"))
+            .expect("validation failed");
+        assert_eq!(reasons(&enumeration, &json!("c")), [
+            InstanceValidationReason::NotInEnum {
+                expected: vec![json!("a"), json!("b")],
+            }
+        ]);
+
+        let empty_list = data_type(crate::test_data::data_type::EMPTY_LIST_V1);
+        empty_list
+            .validate_value(&json!([]))
+            .expect("validation failed");
+        assert_eq!(reasons(&empty_list, &json!([1])), [
+            InstanceValidationReason::ConstMismatch {
+                expected: json!([]),
+            }
+        ]);
+    }
+
+    #[test]
+    fn numeric_equality() {
+        let one = data_type(
+            &data_type_json("constrained/v/1", "number", &json!({ "const": 1 })).to_string(),
+        );
+        one.validate_value(&json!(1.0)).expect("validation failed");
+        assert_eq!(reasons(&one, &json!(1.5)), [
+            InstanceValidationReason::ConstMismatch { expected: json!(1) }
+        ]);
+
+        let enumeration = data_type(
+            &data_type_json("constrained/v/1", "number", &json!({ "enum": [1.0, 2.5] }))
+                .to_string(),
+        );
+        enumeration
+            .validate_value(&json!(1))
+            .expect("validation failed");
+
+        let object = data_type(
+            &data_type_json(
+                "constrained/v/1",
+                "object",
+                &json!({ "const": { "values": [1, { "value": 2 }] } }),
+            )
+            .to_string(),
+        );
+        object
+            .validate_value(&json!({ "values": [1.0, { "value": 2.0 }] }))
+            .expect("validation failed");
+        assert_eq!(
+            reasons(
+                &object,
+                &json!({ "values": [1.0, { "value": 2.0, "other": 3 }] })
+            ),
+            [InstanceValidationReason::ConstMismatch {
+                expected: json!({ "values": [1, { "value": 2 }] }),
+            }]
+        );
+    }
+}
@@ -0,0 +1,329 @@
+use crate::{
+    ontology::shared::compatibility::into_result, EntityType, EntityTypeReference, Incompatibility,
+    IncompatibilityReason, JsonPointer, MaybeOrderedArray, OneOf, OntologyTypeProvider,
+};
+
+impl EntityType {
+    /// Checks if this entity type is compatible with `other` as defined in [RFC 0408].
+    ///
+    /// An entity type is compatible with another one if every entity which is valid for this
+    /// entity type is also valid for `other`:
+    ///
+    /// - the properties have to be compatible, see [`Object::is_compatible_with`]
+    /// - every link of this entity type has to be defined in `other` with at most the same bounds
+    /// - a link which is `ordered` has to be `ordered` in `other` as well
+    /// - every allowed destination of a link has to be allowed by `other`, either directly or
+    ///   through one of its parents, see [`is_subtype_of`]
+    /// - every link required by `other` has to be required by this entity type
+    ///
+    /// The referenced property types and data types are looked up through `provider`. Properties
+    /// and links of the types in [`inherits_from`] are not taken into account, parents have to be
+    /// merged into the entity types beforehand, e.g. by using [`flatten`].
+    ///
+    /// # Errors
+    ///
+    /// - every [`Incompatibility`] found, addressed by its location inside the schema of this
+    ///   entity type
+    ///
+    /// [RFC 0408]: https://github.com/blockprotocol/blockprotocol/blob/main/rfcs/text/0408-versioning-types.md#entity-types
+    /// [`Object::is_compatible_with`]: crate::Object::is_compatible_with
+    /// [`inherits_from`]: Self::inherits_from
+    /// [`flatten`]: Self::flatten
+    /// [`is_subtype_of`]: Self::is_subtype_of
+    pub fn is_compatible_with(
+        &self,
+        other: &Self,
+        provider: &impl OntologyTypeProvider,
+    ) -> Result<(), Vec<Incompatibility>> {
+        let mut incompatibilities = Vec::new();
+        let path = JsonPointer::root();
+
+        self.property_object.check_compatibility_at(
+            &other.property_object,
+            &path,
+            provider,
+            &mut incompatibilities,
+        );
+
+        for (link, array) in self.links() {
+            let link_path = path.join("links").join(link);
+            let Some(other_array) = other.links().get(link) else {
+                incompatibilities.push(Incompatibility::new(
+                    link_path,
+                    IncompatibilityReason::MissingLink(link.clone()),
+                ));
+                continue;
+            };
+
+            array.check_compatibility_at(other_array, &link_path, provider, &mut incompatibilities);
+        }
+
+        for required_link in other.required_links() {
+            if !self.required_links().contains(required_link) {
+                incompatibilities.push(Incompatibility::new(
+                    path.join("requiredLinks"),
+                    IncompatibilityReason::RequiredLink(required_link.clone()),
+                ));
+            }
+        }
+
+        into_result(incompatibilities)
+    }
+}
+
+impl MaybeOrderedArray<Option<OneOf<EntityTypeReference>>> {
+    /// Checks that this link definition is compatible with `other`, i.e. it is not ordered unless
+    /// `other` is, it only allows destinations which are allowed by `other` and it has at most the
+    /// same bounds.
+    pub(crate) fn check_compatibility_at(
+        &self,
+        other: &Self,
+        path: &JsonPointer,
+        provider: &impl OntologyTypeProvider,
+        incompatibilities: &mut Vec<Incompatibility>,
+    ) {
+        if self.ordered() && !other.ordered() {
+            incompatibilities.push(Incompatibility::new(
+                path.join("ordered"),
+                IncompatibilityReason::OrderingRemoved,
+            ));
+        }
+
+        match (self.array().items(), other.array().items()) {
+            (_, None) => {}
+            (None, Some(_)) => incompatibilities.push(Incompatibility::new(
+                path.join("items"),
+                IncompatibilityReason::LinkDestinationsConstrained,
+            )),
+            (Some(destinations), Some(other_destinations)) => {
+                for (index, destination) in destinations.one_of().iter().enumerate() {
+                    let allowed = other_destinations.one_of().contains(destination)
+                        || provider
+                            .entity_type(destination)
+                            .is_some_and(|destination| {
+                                other_destinations
+                                    .one_of()
+                                    .iter()
+                                    .any(|other| destination.is_subtype_of(other, provider))
+                            });
+                    if !allowed {
+                        incompatibilities.push(Incompatibility::new(
+                            path.join("items").join("oneOf").join(index),
+                            IncompatibilityReason::MissingLinkDestination(
+                                destination.url().clone(),
+                            ),
+                        ));
+                    }
+                }
+            }
+        }
+
+        self.array()
+            .check_bounds_compatibility(other.array(), path, incompatibilities);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+    use crate::utils::tests::{base_url, entity_type, entity_type_json, url, TestOntology};
+
+    const NAME: &str = "https://blockprotocol.org/@alice/types/property-type/name/";
+    const WRITTEN_BY: &str = "https://blockprotocol.org/@alice/types/entity-type/written-by/v/1";
+
+    fn book(version: u32, properties: &serde_json::Value, links: &serde_json::Value) -> EntityType {
+        entity_type(&entity_type_json(
+            &format!("book/v/{version}"),
+            &[],
+            properties,
+            &[],
+            links,
+        ))
+    }
+
+    #[test]
+    fn properties() {
+        let ontology = TestOntology::new();
+        let single = book(
+            1,
+            &json!({ NAME: { "$ref": "https://blockprotocol.org/@alice/types/property-type/name/v/1" } }),
+            &json!({}),
+        );
+        let array = book(
+            2,
+            &json!({
+                NAME: {
+                    "type": "array",
+                    "items": { "$ref": "https://blockprotocol.org/@alice/types/property-type/name/v/1" }
+                }
+            }),
+            &json!({}),
+        );
+        let empty = book(3, &json!({}), &json!({}));
+
+        let path = JsonPointer::root().join("properties").join(NAME);
+        assert_eq!(
+            single.is_compatible_with(&array, &ontology),
+            Err(vec![Incompatibility::new(
+                path.clone(),
+                IncompatibilityReason::ExpectedArray
+            )])
+        );
+        assert_eq!(
+            array.is_compatible_with(&single, &ontology),
+            Err(vec![Incompatibility::new(
+                path.clone(),
+                IncompatibilityReason::UnexpectedArray
+            )])
+        );
+        assert_eq!(
+            single.is_compatible_with(&empty, &ontology),
+            Err(vec![Incompatibility::new(
+                path,
+                IncompatibilityReason::MissingProperty(base_url(NAME))
+            )])
+        );
+        empty
+            .is_compatible_with(&single, &ontology)
+            .expect("adding an optional property should be compatible");
+    }
+
+    #[test]
+    fn links() {
+        let ontology = TestOntology::new();
+        let person = "https://blockprotocol.org/@alice/types/entity-type/person/v/1";
+        let organization = "https://blockprotocol.org/@alice/types/entity-type/organization/v/1";
+        let link = |ordered: bool, destinations: &[&str], max_items: Option<usize>| {
+            let mut link = json!({
+                "type": "array",
+                "items": {
+                    "oneOf": destinations
+                        .iter()
+                        .map(|destination| json!({ "$ref": destination }))
+                        .collect::<Vec<_>>()
+                },
+                "ordered": ordered
+            });
+            if destinations.is_empty() {
+                link["items"] = json!({});
+            }
+            if let Some(max_items) = max_items {
+                link["maxItems"] = json!(max_items);
+            }
+            json!({ WRITTEN_BY: link })
+        };
+
+        let strict = book(1, &json!({}), &link(true, &[person], Some(1)));
+        let loose = book(2, &json!({}), &link(true, &[person, organization], None));
+        strict
+            .is_compatible_with(&loose, &ontology)
+            .expect("loosening the link should be compatible");
+
+        let link_path = JsonPointer::root().join("links").join(WRITTEN_BY);
+        let unordered = book(3, &json!({}), &link(false, &[organization], Some(1)));
+        assert_eq!(
+            strict.is_compatible_with(&unordered, &ontology),
+            Err(vec![
+                Incompatibility::new(
+                    link_path.join("ordered"),
+                    IncompatibilityReason::OrderingRemoved
+                ),
+                Incompatibility::new(
+                    link_path.join("items").join("oneOf").join(0),
+                    IncompatibilityReason::MissingLinkDestination(url(person))
+                ),
+            ])
+        );
+
+        let unconstrained = book(4, &json!({}), &link(true, &[], None));
+        assert_eq!(
+            unconstrained.is_compatible_with(&strict, &ontology),
+            Err(vec![
+                Incompatibility::new(
+                    link_path.join("items"),
+                    IncompatibilityReason::LinkDestinationsConstrained
+                ),
+                Incompatibility::new(
+                    link_path.join("maxItems"),
+                    IncompatibilityReason::MaxItemsDecreased {
+                        max_items: None,
+                        other_max_items: 1
+                    }
+                ),
+            ])
+        );
+
+        let without_links = book(5, &json!({}), &json!({}));
+        assert_eq!(
+            strict.is_compatible_with(&without_links, &ontology),
+            Err(vec![Incompatibility::new(
+                link_path,
+                IncompatibilityReason::MissingLink(url(WRITTEN_BY))
+            )])
+        );
+    }
+
+    #[test]
+    fn required_links() {
+        let ontology = TestOntology::new();
+        let links = json!({ WRITTEN_BY: { "type": "array", "items": {}, "ordered": false } });
+        let optional = book(1, &json!({}), &links);
+        let mut required = serde_json::from_str::<serde_json::Value>(&entity_type_json(
+            "book/v/2",
+            &[],
+            &json!({}),
+            &[],
+            &links,
+        ))
+        .expect("invalid JSON");
+        required["requiredLinks"] = json!([WRITTEN_BY]);
+        let required = entity_type(&required.to_string());
+
+        required
+            .is_compatible_with(&optional, &ontology)
+            .expect("a required link should be compatible with an optional one");
+        assert_eq!(
+            optional.is_compatible_with(&required, &ontology),
+            Err(vec![Incompatibility::new(
+                JsonPointer::root().join("requiredLinks"),
+                IncompatibilityReason::RequiredLink(url(WRITTEN_BY))
+            )])
+        );
+    }
+
+    #[test]
+    fn subtype_link_destinations() {
+        let ontology = TestOntology::new();
+        let link = |destination: &str| {
+            json!({
+                WRITTEN_BY: {
+                    "type": "array",
+                    "items": { "oneOf": [{ "$ref": destination }] },
+                    "ordered": false
+                }
+            })
+        };
+        let building = "https://blockprotocol.org/@alice/types/entity-type/building/v/1";
+        let church = "https://blockprotocol.org/@alice/types/entity-type/church/v/1";
+
+        let to_church = book(1, &json!({}), &link(church));
+        let to_building = book(2, &json!({}), &link(building));
+        to_church
+            .is_compatible_with(&to_building, &ontology)
+            .expect("narrowing a destination to a subtype should be compatible");
+        assert_eq!(
+            to_building.is_compatible_with(&to_church, &ontology),
+            Err(vec![Incompatibility::new(
+                JsonPointer::root()
+                    .join("links")
+                    .join(WRITTEN_BY)
+                    .join("items")
+                    .join("oneOf")
+                    .join(0),
+                IncompatibilityReason::MissingLinkDestination(url(building))
+            )])
+        );
+    }
+}
@@ -0,0 +1,253 @@
+use indexmap::IndexSet;
+
+use crate::{
+    ontology::shared::diff::diff_annotations, Change, ChangeKind, EntityType, JsonPointer, OneOf,
+};
+
+impl EntityType {
+    /// Returns every change from this entity type to `other`, typically a later version of it.
+    ///
+    /// Changes of the `title`, the `description` and the `examples` are reported as
+    /// semantic-annotation changes. Changes of the parents in `allOf`, the properties and the
+    /// links are reported as constraint changes.
+    #[must_use]
+    pub fn diff(&self, other: &Self) -> Vec<Change> {
+        let mut changes = Vec::new();
+        let path = JsonPointer::root();
+
+        diff_annotations(
+            (self.title(), self.description()),
+            (other.title(), other.description()),
+            &mut changes,
+        );
+        if self.examples != other.examples {
+            changes.push(Change::new(
+                path.join("examples"),
+                ChangeKind::ExamplesChanged,
+            ));
+        }
+
+        let all_of_path = path.join("allOf");
+        for parent in self.inherits_from.all_of() {
+            if !other.inherits_from.all_of().contains(parent) {
+                changes.push(Change::new(
+                    all_of_path.clone(),
+                    ChangeKind::ParentRemoved(parent.url().clone()),
+                ));
+            }
+        }
+        for parent in other.inherits_from.all_of() {
+            if !self.inherits_from.all_of().contains(parent) {
+                changes.push(Change::new(
+                    all_of_path.clone(),
+                    ChangeKind::ParentAdded(parent.url().clone()),
+                ));
+            }
+        }
+
+        self.property_object
+            .diff_at(&other.property_object, &path, &mut changes);
+        self.diff_links_at(other, &path, &mut changes);
+
+        changes
+    }
+
+    /// Pushes every change of the `links` and `requiredLinks` fields from this entity type to
+    /// `other` to `changes`.
+    fn diff_links_at(&self, other: &Self, path: &JsonPointer, changes: &mut Vec<Change>) {
+        let links = self
+            .links()
+            .keys()
+            .chain(other.links().keys())
+            .collect::<IndexSet<_>>();
+        for link in links {
+            let link_path = path.join("links").join(link);
+            let (array, other_array) = match (self.links().get(link), other.links().get(link)) {
+                (Some(array), Some(other_array)) => (array, other_array),
+                (Some(_), None) => {
+                    changes.push(Change::new(
+                        link_path,
+                        ChangeKind::LinkRemoved(link.clone()),
+                    ));
+                    continue;
+                }
+                (None, Some(_)) => {
+                    changes.push(Change::new(link_path, ChangeKind::LinkAdded(link.clone())));
+                    continue;
+                }
+                (None, None) => continue,
+            };
+
+            if array.ordered() != other_array.ordered() {
+                changes.push(Change::new(
+                    link_path.join("ordered"),
+                    ChangeKind::OrderingChanged {
+                        ordered: other_array.ordered(),
+                    },
+                ));
+            }
+
+            let items_path = link_path.join("items");
+            match (
+                array.array().items().as_ref().map(OneOf::one_of),
+                other_array.array().items().as_ref().map(OneOf::one_of),
+            ) {
+                (None, None) => {}
+                (None, Some(_)) => changes.push(Change::new(
+                    items_path,
+                    ChangeKind::LinkDestinationsConstrained,
+                )),
+                (Some(_), None) => changes.push(Change::new(
+                    items_path,
+                    ChangeKind::LinkDestinationsUnconstrained,
+                )),
+                (Some(destinations), Some(other_destinations)) => {
+                    for destination in destinations {
+                        if !other_destinations.contains(destination) {
+                            changes.push(Change::new(
+                                items_path.clone(),
+                                ChangeKind::LinkDestinationRemoved(destination.url().clone()),
+                            ));
+                        }
+                    }
+                    for destination in other_destinations {
+                        if !destinations.contains(destination) {
+                            changes.push(Change::new(
+                                items_path.clone(),
+                                ChangeKind::LinkDestinationAdded(destination.url().clone()),
+                            ));
+                        }
+                    }
+                }
+            }
+
+            array
+                .array()
+                .diff_bounds_at(other_array.array(), &link_path, changes);
+        }
+
+        let required_links_path = path.join("requiredLinks");
+        for link in self.required_links() {
+            if !other.required_links().contains(link) {
+                changes.push(Change::new(
+                    required_links_path.clone(),
+                    ChangeKind::RequiredLinkRemoved(link.clone()),
+                ));
+            }
+        }
+        for link in other.required_links() {
+            if !self.required_links().contains(link) {
+                changes.push(Change::new(
+                    required_links_path.clone(),
+                    ChangeKind::RequiredLinkAdded(link.clone()),
+                ));
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+    use crate::{
+        utils::tests::{base_url, entity_type, entity_type_json, url},
+        ChangeClassification,
+    };
+
+    const AGE: &str = "https://blockprotocol.org/@alice/types/property-type/age/";
+    const PERSON: &str = "https://blockprotocol.org/@alice/types/entity-type/person/v/1";
+    const FRIEND_OF: &str = "https://blockprotocol.org/@alice/types/entity-type/friend-of/v/1";
+    const OWNS: &str = "https://blockprotocol.org/@alice/types/entity-type/owns/v/1";
+    const BOOK: &str = "https://blockprotocol.org/@alice/types/entity-type/book/v/1";
+
+    #[test]
+    fn changes() {
+        let old = entity_type(crate::test_data::entity_type::PERSON_V1);
+        let mut new =
+            serde_json::from_str::<serde_json::Value>(crate::test_data::entity_type::PERSON_V1)
+                .expect("invalid JSON");
+        new["$id"] = json!("https://blockprotocol.org/@alice/types/entity-type/person/v/2");
+        new["title"] = json!("Human");
+        new["properties"][AGE] = json!({ "$ref": format!("{AGE}v/1") });
+        new["required"] = json!([AGE]);
+        new["links"][FRIEND_OF]["items"]["oneOf"] = json!([{ "$ref": BOOK }]);
+        new["links"][FRIEND_OF]["maxItems"] = json!(10);
+        new["links"][OWNS]["ordered"] = json!(true);
+        new["requiredLinks"] = json!([OWNS]);
+        let new = entity_type(&new.to_string());
+
+        assert!(old.diff(&old).is_empty());
+
+        let changes = old.diff(&new);
+        let friend_of = JsonPointer::root().join("links").join(FRIEND_OF);
+        assert_eq!(changes, [
+            Change::new(
+                JsonPointer::root().join("title"),
+                ChangeKind::TitleChanged {
+                    old: "Person".to_owned(),
+                    new: "Human".to_owned(),
+                },
+            ),
+            Change::new(
+                JsonPointer::root().join("properties").join(AGE),
+                ChangeKind::PropertyAdded(base_url(AGE)),
+            ),
+            Change::new(
+                JsonPointer::root().join("required"),
+                ChangeKind::RequiredAdded(base_url(AGE)),
+            ),
+            Change::new(
+                friend_of.join("items"),
+                ChangeKind::LinkDestinationRemoved(url(PERSON)),
+            ),
+            Change::new(
+                friend_of.join("items"),
+                ChangeKind::LinkDestinationAdded(url(BOOK)),
+            ),
+            Change::new(friend_of.join("maxItems"), ChangeKind::MaxItemsChanged {
+                old: None,
+                new: Some(10),
+            },),
+            Change::new(
+                JsonPointer::root().join("links").join(OWNS).join("ordered"),
+                ChangeKind::OrderingChanged { ordered: true },
+            ),
+            Change::new(
+                JsonPointer::root().join("requiredLinks"),
+                ChangeKind::RequiredLinkAdded(url(OWNS)),
+            ),
+        ]);
+        assert_eq!(
+            changes
+                .iter()
+                .filter(|change| change.classification()
+                    == ChangeClassification::SemanticAnnotation)
+                .count(),
+            1
+        );
+    }
+
+    #[test]
+    fn link_order() {
+        let person = |links: &serde_json::Value| {
+            entity_type(&entity_type_json("person/v/1", &[], &json!({}), &[], links))
+        };
+        let array = json!({ "type": "array", "items": {}, "ordered": false });
+        let old = person(&json!({ OWNS: array, FRIEND_OF: array }));
+        let new = person(&json!({ FRIEND_OF: array, BOOK: array }));
+
+        // Links of the old entity type come first, in declaration order
+        assert_eq!(old.diff(&new), [
+            Change::new(
+                JsonPointer::root().join("links").join(OWNS),
+                ChangeKind::LinkRemoved(url(OWNS)),
+            ),
+            Change::new(
+                JsonPointer::root().join("links").join(BOOK),
+                ChangeKind::LinkAdded(url(BOOK)),
+            ),
+        ]);
+    }
+}
@@ -0,0 +1,179 @@
+use crate::{
+    url::VersionedUrl, DuplicateEntityTypeError, EntityType, EntityTypeReference, Links,
+    MaybeOrderedArray, OneOf,
+};
+
+impl EntityType {
+    /// Duplicates this entity type into a new entity type identified by `new_id`, as described in
+    /// [RFC 0428].
+    ///
+    /// The duplicate is a standalone copy of the properties, links and examples of this entity
+    /// type. It keeps the types in [`inherits_from`], so the relationship to the supertypes of
+    /// the original is preserved, but it is not a subtype of the original itself. Every link
+    /// destination which references this entity type, e.g. a `friend-of` link pointing back at
+    /// `person`, is rewritten to reference the duplicate instead. References to other versions of
+    /// this entity type are left untouched.
+    ///
+    /// # Errors
+    ///
+    /// - [`SameBaseUrl`] if `new_id` shares the base URL of this entity type, in which case it
+    ///   would be a new version rather than a duplicate
+    ///
+    /// [RFC 0428]: https://github.com/blockprotocol/blockprotocol/blob/main/rfcs/text/0428-extending-and-duplicating-types.md#defining-entity-type-duplication
+    /// [`inherits_from`]: Self::inherits_from
+    /// [`SameBaseUrl`]: DuplicateEntityTypeError::SameBaseUrl
+    pub fn duplicate(&self, new_id: VersionedUrl) -> Result<Self, DuplicateEntityTypeError> {
+        if new_id.base_url == self.id.base_url {
+            return Err(DuplicateEntityTypeError::SameBaseUrl {
+                id: self.id.clone(),
+                new_id,
+            });
+        }
+
+        let rewrite = |reference: &EntityTypeReference| {
+            if reference.url == self.id {
+                EntityTypeReference::new(new_id.clone())
+            } else {
+                reference.clone()
+            }
+        };
+
+        let links = self
+            .links()
+            .iter()
+            .map(|(link, array)| {
+                let destinations = array.array().items().as_ref().map(|destinations| {
+                    OneOf::new_unchecked(
+                        destinations
+                            .one_of()
+                            .iter()
+                            .map(rewrite)
+                            .collect::<Vec<_>>(),
+                    )
+                });
+
+                (
+                    link.clone(),
+                    MaybeOrderedArray::new(
+                        array.ordered(),
+                        destinations,
+                        array.array().min_items(),
+                        array.array().max_items(),
+                    ),
+                )
+            })
+            .collect();
+
+        Ok(Self {
+            id: new_id.clone(),
+            title: self.title.clone(),
+            description: self.description.clone(),
+            property_object: self.property_object.clone(),
+            inherits_from: self.inherits_from.clone(),
+            links: Links::new_unchecked(links, self.required_links().to_vec()),
+            examples: self.examples.clone(),
+        })
+    }
+
+    /// Duplicates this entity type like [`duplicate`] and records the original entity type in the
+    /// `description` of the duplicate.
+    ///
+    /// The meta schema has no dedicated field for the provenance of a type, so a sentence naming
+    /// the original `$id` is appended to the description instead.
+    ///
+    /// # Errors
+    ///
+    /// - [`DuplicateEntityTypeError`] if this entity type could not be duplicated, see
+    ///   [`duplicate`]
+    ///
+    /// [`duplicate`]: Self::duplicate
+    pub fn duplicate_with_provenance(
+        &self,
+        new_id: VersionedUrl,
+    ) -> Result<Self, DuplicateEntityTypeError> {
+        let mut duplicate = self.duplicate(new_id)?;
+
+        let provenance = format!("Duplicated from `{}`.", self.id);
+        duplicate.description = Some(match duplicate.description.take() {
+            Some(description) if !description.is_empty() => {
+                format!("{description}\n\n{provenance}")
+            }
+            _ => provenance,
+        });
+
+        Ok(duplicate)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        test_data,
+        utils::tests::{entity_type, url},
+    };
+
+    const FRIEND_OF: &str = "https://blockprotocol.org/@alice/types/entity-type/friend-of/v/1";
+
+    fn person() -> EntityType {
+        entity_type(test_data::entity_type::PERSON_V1)
+    }
+
+    #[test]
+    fn rewrites_self_references() {
+        let person = person();
+        let new_id = url("https://blockprotocol.org/@bob/types/entity-type/person/v/1");
+
+        let duplicate = person
+            .duplicate(new_id.clone())
+            .expect("failed to duplicate");
+
+        assert_eq!(duplicate.id(), &new_id);
+        assert_eq!(duplicate.title(), person.title());
+        assert_eq!(duplicate.description(), person.description());
+        assert_eq!(duplicate.properties(), person.properties());
+        assert_eq!(duplicate.links().len(), person.links().len());
+        assert_eq!(
+            duplicate.links()[&url(FRIEND_OF)]
+                .array()
+                .items()
+                .as_ref()
+                .map(OneOf::one_of),
+            Some([EntityTypeReference::new(new_id)].as_slice())
+        );
+
+        // The duplicate is a valid entity type on its own
+        entity_type(&serde_json::to_string(&duplicate).expect("failed to serialize"));
+    }
+
+    #[test]
+    fn provenance() {
+        let person = person();
+        let duplicate = person
+            .duplicate_with_provenance(url(
+                "https://blockprotocol.org/@bob/types/entity-type/person/v/1",
+            ))
+            .expect("failed to duplicate");
+
+        assert!(duplicate
+            .description()
+            .expect("missing description")
+            .ends_with(
+                "Duplicated from `https://blockprotocol.org/@alice/types/entity-type/person/v/1`."
+            ));
+    }
+
+    #[test]
+    fn same_base_url() {
+        let person = person();
+        let new_id = url("https://blockprotocol.org/@alice/types/entity-type/person/v/2");
+
+        assert_eq!(
+            person.duplicate(new_id.clone()),
+            Err(DuplicateEntityTypeError::SameBaseUrl {
+                id: person.id().clone(),
+                new_id,
+            })
+        );
+    }
+}
@@ -4,32 +4,67 @@ use thiserror::Error;
 use tsify::Tsify;
 
 use crate::{
-    url::{ParseBaseUrlError, ParseVersionedUrlError, VersionedUrl},
-    ParseAllOfError, ParseLinksError, ParsePropertyTypeObjectError,
+    ontology::shared::json_pointer::ROOT,
+    url::{BaseUrl, ParseBaseUrlError, ParseVersionedUrlError, VersionedUrl},
+    Incompatibility, JsonPointer, ParseAllOfError, ParseLinksError, ParsePropertyTypeObjectError,
 };
 
 #[cfg_attr(target_arch = "wasm32", derive(Tsify))]
 #[derive(Debug, Deserialize, Serialize, PartialEq, Eq, Error)]
 #[serde(tag = "reason", content = "inner")]
 pub enum ParseEntityTypeError {
-    #[error("invalid `$schema` property, expected `\"https://blockprotocol.org/types/modules/graph/0.3/schema/entity-type\"` but received: `{0}`")]
-    InvalidMetaSchema(String),
+    #[error("invalid `$schema` property at `{path}`, expected `\"https://blockprotocol.org/types/modules/graph/0.3/schema/entity-type\"` but received: `{meta_schema}`")]
+    InvalidMetaSchema {
+        path: JsonPointer,
+        meta_schema: String,
+    },
     #[error("invalid property type object: `{0}`")]
     InvalidPropertyTypeObject(ParsePropertyTypeObjectError),
     #[error("invalid all of field: `{0}`")]
     InvalidAllOf(ParseAllOfError),
     #[error("invalid links: `{0}`")]
     InvalidLinks(ParseLinksError),
-    #[error("invalid key in default: `{0}`")]
-    InvalidDefaultKey(ParseBaseUrlError),
-    #[error("invalid key in examples list: `{0}`")]
-    InvalidExamplesKey(ParseBaseUrlError),
-    #[error("invalid versioned URL: `{0}`")]
-    InvalidVersionedUrl(ParseVersionedUrlError),
+    #[error("invalid key in default at `{path}`: `{error}`")]
+    InvalidDefaultKey {
+        path: JsonPointer,
+        error: ParseBaseUrlError,
+    },
+    #[error("invalid key in examples list at `{path}`: `{error}`")]
+    InvalidExamplesKey {
+        path: JsonPointer,
+        error: ParseBaseUrlError,
+    },
+    #[error("invalid versioned URL at `{path}`: `{error}`")]
+    InvalidVersionedUrl {
+        path: JsonPointer,
+        error: ParseVersionedUrlError,
+    },
     #[error("error in JSON: `{0}`")]
     InvalidJson(String),
 }
 
+impl ParseEntityTypeError {
+    /// Returns the location of the node which failed to parse, relative to the entity type.
+    ///
+    /// [`InvalidJson`] errors are located at the root, as the deserializer does not report a
+    /// location.
+    ///
+    /// [`InvalidJson`]: Self::InvalidJson
+    #[must_use]
+    pub fn path(&self) -> &JsonPointer {
+        match self {
+            Self::InvalidMetaSchema { path, .. }
+            | Self::InvalidDefaultKey { path, .. }
+            | Self::InvalidExamplesKey { path, .. }
+            | Self::InvalidVersionedUrl { path, .. } => path,
+            Self::InvalidPropertyTypeObject(error) => error.path(),
+            Self::InvalidAllOf(error) => error.path(),
+            Self::InvalidLinks(error) => error.path(),
+            Self::InvalidJson(_) => &ROOT,
+        }
+    }
+}
+
 #[derive(Debug, PartialEq, Eq, Error)]
 pub enum MergeEntityTypeError {
     #[error("`{parent}` is not contained in the `allOf` property of `{child}`")]
@@ -37,4 +72,55 @@ pub enum MergeEntityTypeError {
         child: VersionedUrl,
         parent: VersionedUrl,
     },
+    #[error(
+        "the property `{property}` references `{child}` in the child but `{parent}` in the parent"
+    )]
+    PropertyTypeVersionMismatch {
+        property: BaseUrl,
+        child: VersionedUrl,
+        parent: VersionedUrl,
+    },
+    #[error(
+        "the property `{property}` is defined differently in `{child}` and its parent `{parent}`"
+    )]
+    ConflictingPropertyDefinition {
+        child: VersionedUrl,
+        parent: VersionedUrl,
+        property: BaseUrl,
+    },
+    #[error("the link `{link}` is constrained differently in `{child}` and its parent `{parent}`")]
+    ConflictingLinkConstraints {
+        child: VersionedUrl,
+        parent: VersionedUrl,
+        link: VersionedUrl,
+    },
+    #[error("the parent `{parent}` of `{child}` could not be resolved")]
+    UnresolvedParent {
+        child: VersionedUrl,
+        parent: VersionedUrl,
+    },
+}
+
+#[derive(Debug, PartialEq, Eq, Error)]
+pub enum DuplicateEntityTypeError {
+    #[error(
+        "`{new_id}` shares the base URL of `{id}` and would be a new version, not a duplicate"
+    )]
+    SameBaseUrl {
+        id: VersionedUrl,
+        new_id: VersionedUrl,
+    },
+}
+
+/// The constraints of an entity type which do not narrow the constraints of one of its parents.
+///
+/// The parent may be reached through several steps of inheritance, in which case every ancestor
+/// visited through `parent` has been checked.
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+#[error("`{child}` cannot be used where its parent `{parent}` is expected")]
+pub struct SubstitutionError {
+    pub child: VersionedUrl,
+    pub parent: VersionedUrl,
+    /// Every incompatibility found, addressed by its location inside the schema of `child`.
+    pub incompatibilities: Vec<Incompatibility>,
 }
@@ -0,0 +1,476 @@
+use std::collections::HashSet;
+
+use crate::{EntityType, EntityTypeReference, MergeEntityTypeError, OntologyTypeProvider};
+
+impl EntityType {
+    /// Returns this entity type with all of its ancestors merged into it.
+    ///
+    /// The types in [`inherits_from`] are looked up through `provider`, and merged recursively
+    /// together with their own parents using [`merge_parent`]. The resulting entity type contains
+    /// the properties, `required` list and links of every ancestor and does not inherit from any
+    /// other entity type.
+    ///
+    /// Every base URL is only merged once. As described in [RFC 0428], a parent whose base URL has
+    /// already been seen is skipped instead of being followed, so an ancestor which is reachable
+    /// through multiple parents is merged a single time, and inheritance cycles, e.g. a type
+    /// inheriting from an older version of itself, are tolerated.
+    ///
+    /// # Errors
+    ///
+    /// - [`UnresolvedParent`] if an ancestor could not be found through `provider`
+    /// - [`MergeEntityTypeError`] if an ancestor could not be merged
+    ///
+    /// [`inherits_from`]: Self::inherits_from
+    /// [`merge_parent`]: Self::merge_parent
+    /// [RFC 0428]: https://github.com/blockprotocol/blockprotocol/blob/main/rfcs/text/0428-extending-and-duplicating-types.md#detecting-cycles
+    /// [`UnresolvedParent`]: MergeEntityTypeError::UnresolvedParent
+    pub fn flatten(
+        &self,
+        provider: &impl OntologyTypeProvider,
+    ) -> Result<Self, MergeEntityTypeError> {
+        let mut flattened = self.clone();
+        let mut seen = HashSet::from([self.id.base_url.clone()]);
+        while let Some(parent) = flattened.inherits_from.all_of().first().cloned() {
+            if seen.insert(parent.url.base_url.clone()) {
+                let parent_type = provider.entity_type(&parent).ok_or_else(|| {
+                    MergeEntityTypeError::UnresolvedParent {
+                        child: self.id.clone(),
+                        parent: parent.url.clone(),
+                    }
+                })?;
+                flattened.merge_parent(parent_type.clone(), provider)?;
+            } else {
+                flattened.inherits_from.elements.remove(0);
+            }
+        }
+
+        Ok(flattened)
+    }
+
+    /// Returns `true` if this entity type is `other` or inherits from it, directly or through any
+    /// of its ancestors.
+    ///
+    /// The types in [`inherits_from`] are looked up through `provider`. Ancestors which cannot be
+    /// resolved are skipped, so `false` is returned if the relationship cannot be established
+    /// with the types known to `provider`. Inheritance cycles are tolerated.
+    ///
+    /// [`inherits_from`]: Self::inherits_from
+    #[must_use]
+    pub fn is_subtype_of(
+        &self,
+        other: &EntityTypeReference,
+        provider: &impl OntologyTypeProvider,
+    ) -> bool {
+        let mut visited = HashSet::new();
+        let mut stack = vec![self];
+        while let Some(entity_type) = stack.pop() {
+            if entity_type.id == other.url {
+                return true;
+            }
+            if !visited.insert(&entity_type.id) {
+                continue;
+            }
+
+            for parent in entity_type.inherits_from.all_of() {
+                if parent == other {
+                    return true;
+                }
+                if let Some(parent_type) = provider.entity_type(parent) {
+                    stack.push(parent_type);
+                }
+            }
+        }
+
+        false
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+    use crate::{
+        url::BaseUrl,
+        utils::tests::{base_url, entity_type, entity_type_json, url, TestOntology},
+    };
+
+    const NAME: &str = "https://blockprotocol.org/@alice/types/property-type/name/";
+    const AGE: &str = "https://blockprotocol.org/@alice/types/property-type/age/";
+    const TEXT: &str = "https://blockprotocol.org/@alice/types/property-type/text/";
+
+    /// Creates the entity type `name` with a single optional or required `property` and a single
+    /// link named after it.
+    fn single_property_json(
+        name: &str,
+        parents: &[&str],
+        property: &str,
+        required: bool,
+    ) -> String {
+        let required: &[&str] = if required { &[property] } else { &[] };
+        entity_type_json(
+            name,
+            parents,
+            &json!({ property: { "$ref": format!("{property}v/1") } }),
+            required,
+            &json!({
+                format!(
+                    "https://example.com/entity-type/{}-link/v/1",
+                    name.split('/').next().unwrap_or_default()
+                ): {
+                    "type": "array",
+                    "items": {},
+                    "ordered": false
+                }
+            }),
+        )
+    }
+
+    #[test]
+    fn grandparents() {
+        let mut ontology = TestOntology::new();
+        ontology.add_entity_type(&single_property_json("grandparent/v/1", &[], AGE, true));
+        ontology.add_entity_type(&single_property_json(
+            "parent/v/1",
+            &["grandparent/v/1"],
+            TEXT,
+            false,
+        ));
+        let child = entity_type(&single_property_json(
+            "child/v/1",
+            &["parent/v/1"],
+            NAME,
+            true,
+        ));
+
+        let flattened = child.flatten(&ontology).expect("failed to flatten");
+
+        assert_eq!(flattened.id(), child.id());
+        assert!(flattened.inherits_from().all_of().is_empty());
+        for property in [NAME, AGE, TEXT] {
+            assert!(flattened.properties().contains_key(&base_url(property)));
+        }
+        let required = flattened
+            .required()
+            .iter()
+            .map(BaseUrl::as_str)
+            .collect::<Vec<_>>();
+        assert_eq!(required, [NAME, AGE]);
+        for link in ["child", "parent", "grandparent"] {
+            assert!(flattened.links().contains_key(&url(&format!(
+                "https://example.com/entity-type/{link}-link/v/1"
+            ))));
+        }
+    }
+
+    #[test]
+    fn required_order() {
+        let mut ontology = TestOntology::new();
+        ontology.add_entity_type(&single_property_json("base/v/1", &[], NAME, true));
+        ontology.add_entity_type(&single_property_json(
+            "parent/v/1",
+            &["base/v/1"],
+            TEXT,
+            true,
+        ));
+        let child = entity_type(&single_property_json(
+            "child/v/1",
+            &["parent/v/1"],
+            NAME,
+            true,
+        ));
+
+        let flattened = child.flatten(&ontology).expect("failed to flatten");
+        let required = flattened
+            .required()
+            .iter()
+            .map(BaseUrl::as_str)
+            .collect::<Vec<_>>();
+        assert_eq!(required, [NAME, TEXT]);
+    }
+
+    #[test]
+    fn diamond() {
+        let mut ontology = TestOntology::new();
+        ontology.add_entity_type(&single_property_json("base/v/1", &[], AGE, false));
+        ontology.add_entity_type(&single_property_json(
+            "left/v/1",
+            &["base/v/1"],
+            TEXT,
+            false,
+        ));
+        ontology.add_entity_type(&single_property_json(
+            "right/v/1",
+            &["base/v/1"],
+            TEXT,
+            false,
+        ));
+        let child = entity_type(&single_property_json(
+            "child/v/1",
+            &["left/v/1", "right/v/1"],
+            NAME,
+            false,
+        ));
+
+        let flattened = child.flatten(&ontology).expect("failed to flatten");
+        assert!(flattened.inherits_from().all_of().is_empty());
+        assert_eq!(flattened.properties().len(), 3);
+        assert_eq!(flattened.links().len(), 4);
+    }
+
+    #[test]
+    fn cycle() {
+        let mut ontology = TestOntology::new();
+        ontology.add_entity_type(&single_property_json(
+            "country/v/1",
+            &["region/v/1"],
+            NAME,
+            false,
+        ));
+        ontology.add_entity_type(&single_property_json(
+            "region/v/1",
+            &["country/v/1"],
+            NAME,
+            false,
+        ));
+        let country = entity_type(&single_property_json(
+            "country/v/2",
+            &["region/v/1"],
+            NAME,
+            false,
+        ));
+
+        // `country/v/1` is not merged, as the base URL of `country/v/2` has already been seen
+        let flattened = country.flatten(&ontology).expect("failed to flatten");
+        assert!(flattened.inherits_from().all_of().is_empty());
+        assert_eq!(flattened.properties().len(), 1);
+        let links = flattened
+            .links()
+            .keys()
+            .map(ToString::to_string)
+            .collect::<Vec<_>>();
+        assert_eq!(links, [
+            "https://example.com/entity-type/country-link/v/1",
+            "https://example.com/entity-type/region-link/v/1",
+        ]);
+    }
+
+    #[test]
+    fn stacked_diamonds() {
+        let mut ontology = TestOntology::new();
+        ontology.add_entity_type(&single_property_json("level-0/v/1", &[], AGE, false));
+        for level in 1..=32 {
+            let parent = format!("level-{}/v/1", level - 1);
+            for side in ["left", "right"] {
+                ontology.add_entity_type(&single_property_json(
+                    &format!("{side}-{level}/v/1"),
+                    &[&parent],
+                    TEXT,
+                    false,
+                ));
+            }
+            ontology.add_entity_type(&single_property_json(
+                &format!("level-{level}/v/1"),
+                &[&format!("left-{level}/v/1"), &format!("right-{level}/v/1")],
+                NAME,
+                false,
+            ));
+        }
+        let child = entity_type(&single_property_json(
+            "child/v/1",
+            &["level-32/v/1"],
+            NAME,
+            false,
+        ));
+
+        let flattened = child.flatten(&ontology).expect("failed to flatten");
+        assert!(flattened.inherits_from().all_of().is_empty());
+        assert_eq!(flattened.properties().len(), 3);
+        assert_eq!(flattened.links().len(), 1 + 3 * 32 + 1);
+    }
+
+    #[test]
+    fn unresolved_parent() {
+        let child = entity_type(&single_property_json(
+            "child/v/1",
+            &["parent/v/1"],
+            NAME,
+            false,
+        ));
+
+        assert_eq!(
+            child.flatten(&TestOntology::new()),
+            Err(MergeEntityTypeError::UnresolvedParent {
+                child: url("https://example.com/entity-type/child/v/1"),
+                parent: url("https://example.com/entity-type/parent/v/1"),
+            })
+        );
+    }
+
+    #[test]
+    fn identical_definitions() {
+        let properties = json!({ NAME: { "type": "array", "items": { "$ref": format!("{NAME}v/1") }, "maxItems": 2 } });
+        let links = json!({
+            "https://example.com/entity-type/link/v/1": {
+                "type": "array",
+                "items": { "oneOf": [{ "$ref": "https://example.com/entity-type/parent/v/1" }] },
+                "ordered": true
+            }
+        });
+        let parent = entity_type(&entity_type_json(
+            "parent/v/1",
+            &[],
+            &properties,
+            &[],
+            &links,
+        ));
+        let mut child = entity_type(&entity_type_json(
+            "child/v/1",
+            &["parent/v/1"],
+            &properties,
+            &[],
+            &links,
+        ));
+
+        child
+            .merge_parent(parent, &TestOntology::new())
+            .expect("failed to merge");
+        assert_eq!(child.properties().len(), 1);
+        assert_eq!(child.links().len(), 1);
+    }
+
+    #[test]
+    fn property_type_version_mismatch() {
+        let parent = entity_type(&entity_type_json(
+            "parent/v/1",
+            &[],
+            &json!({ NAME: { "$ref": format!("{NAME}v/1") } }),
+            &[],
+            &json!({}),
+        ));
+        let mut child = entity_type(&entity_type_json(
+            "child/v/1",
+            &["parent/v/1"],
+            &json!({ NAME: { "$ref": format!("{NAME}v/2") } }),
+            &[],
+            &json!({}),
+        ));
+        let unchanged = child.clone();
+
+        assert_eq!(
+            child.merge_parent(parent, &TestOntology::new()),
+            Err(MergeEntityTypeError::PropertyTypeVersionMismatch {
+                property: base_url(NAME),
+                child: url(&format!("{NAME}v/2")),
+                parent: url(&format!("{NAME}v/1")),
+            })
+        );
+        assert_eq!(child, unchanged);
+    }
+
+    #[test]
+    fn conflicting_property_definition() {
+        let parent = entity_type(&entity_type_json(
+            "parent/v/1",
+            &[],
+            &json!({ NAME: { "$ref": format!("{NAME}v/1") } }),
+            &[],
+            &json!({}),
+        ));
+        let mut child = entity_type(&entity_type_json(
+            "child/v/1",
+            &["parent/v/1"],
+            &json!({ NAME: { "type": "array", "items": { "$ref": format!("{NAME}v/1") } } }),
+            &[],
+            &json!({}),
+        ));
+
+        assert_eq!(
+            child.merge_parent(parent, &TestOntology::new()),
+            Err(MergeEntityTypeError::ConflictingPropertyDefinition {
+                child: url("https://example.com/entity-type/child/v/1"),
+                parent: url("https://example.com/entity-type/parent/v/1"),
+                property: base_url(NAME),
+            })
+        );
+    }
+
+    #[test]
+    fn narrowed_link_constraints() {
+        let link = "https://example.com/entity-type/link/v/1";
+        let parent = entity_type(&entity_type_json(
+            "parent/v/1",
+            &[],
+            &json!({}),
+            &[],
+            &json!({ link: { "type": "array", "items": {}, "ordered": false } }),
+        ));
+        let mut child = entity_type(&entity_type_json(
+            "child/v/1",
+            &["parent/v/1"],
+            &json!({}),
+            &[],
+            &json!({ link: { "type": "array", "items": {}, "ordered": false, "maxItems": 1 } }),
+        ));
+
+        child
+            .merge_parent(parent, &TestOntology::new())
+            .expect("narrowing a link should be allowed");
+        assert_eq!(child.links()[&url(link)].array().max_items(), Some(1));
+    }
+
+    #[test]
+    fn conflicting_link_constraints() {
+        let link = "https://example.com/entity-type/link/v/1";
+        let parent = entity_type(&entity_type_json(
+            "parent/v/1",
+            &[],
+            &json!({}),
+            &[],
+            &json!({ link: { "type": "array", "items": {}, "ordered": false, "maxItems": 1 } }),
+        ));
+        let mut child = entity_type(&entity_type_json(
+            "child/v/1",
+            &["parent/v/1"],
+            &json!({}),
+            &[],
+            &json!({ link: { "type": "array", "items": {}, "ordered": false } }),
+        ));
+
+        assert_eq!(
+            child.merge_parent(parent, &TestOntology::new()),
+            Err(MergeEntityTypeError::ConflictingLinkConstraints {
+                child: url("https://example.com/entity-type/child/v/1"),
+                parent: url("https://example.com/entity-type/parent/v/1"),
+                link: url(link),
+            })
+        );
+    }
+
+    #[test]
+    fn subtype() {
+        let mut ontology = TestOntology::new();
+        ontology.add_entity_type(&single_property_json("base/v/1", &[], AGE, false));
+        ontology.add_entity_type(&single_property_json(
+            "middle/v/1",
+            &["base/v/1"],
+            TEXT,
+            false,
+        ));
+        let child = entity_type(&single_property_json(
+            "child/v/1",
+            &["middle/v/1"],
+            NAME,
+            false,
+        ));
+        let reference = |name: &str| {
+            EntityTypeReference::new(url(&format!("https://example.com/entity-type/{name}")))
+        };
+
+        assert!(child.is_subtype_of(&reference("child/v/1"), &ontology));
+        assert!(child.is_subtype_of(&reference("middle/v/1"), &ontology));
+        assert!(child.is_subtype_of(&reference("base/v/1"), &ontology));
+        assert!(!child.is_subtype_of(&reference("base/v/2"), &ontology));
+        assert!(!child.is_subtype_of(&reference("other/v/1"), &ontology));
+    }
+}
@@ -0,0 +1,173 @@
+use serde_json::{Map, Value};
+
+use crate::{
+    ontology::shared::json_schema::JSON_SCHEMA_DIALECT, url::BaseUrl, EntityType, JsonSchemaError,
+    OntologyTypeProvider,
+};
+
+impl EntityType {
+    /// Returns the schema of the properties of this entity type as a self-contained JSON Schema
+    /// (draft 2020-12).
+    ///
+    /// Every parent in [`inherits_from`], property type and data type is looked up through
+    /// `provider` and inlined. As described in [RFC 0428], only the root of the schema is closed
+    /// by `unevaluatedProperties: false`, so properties of the inlined parents are accepted while
+    /// any other property is rejected. Property type objects are closed by
+    /// `additionalProperties: false`.
+    ///
+    /// Links are not part of the properties of an entity and are therefore not expressed in the
+    /// schema.
+    ///
+    /// # Errors
+    ///
+    /// - [`UnresolvedEntityType`] if a parent could not be found through `provider`
+    /// - [`InheritanceCycle`] if the inheritance chain contains a cycle
+    /// - [`JsonSchemaError`] if a property type could not be inlined, see
+    ///   [`PropertyType::to_json_schema`]
+    ///
+    /// [`inherits_from`]: Self::inherits_from
+    /// [RFC 0428]: https://github.com/blockprotocol/blockprotocol/blob/main/rfcs/text/0428-extending-and-duplicating-types.md#defining-an-implicit-unevaluatedproperties
+    /// [`UnresolvedEntityType`]: JsonSchemaError::UnresolvedEntityType
+    /// [`InheritanceCycle`]: JsonSchemaError::InheritanceCycle
+    /// [`PropertyType::to_json_schema`]: crate::PropertyType::to_json_schema
+    pub fn to_json_schema(
+        &self,
+        provider: &impl OntologyTypeProvider,
+    ) -> Result<Value, JsonSchemaError> {
+        let mut schema = self.json_schema_object(provider, &mut Vec::new())?;
+        schema.insert("$schema".to_owned(), JSON_SCHEMA_DIALECT.into());
+        schema.insert("$id".to_owned(), self.id.to_string().into());
+        schema.insert("unevaluatedProperties".to_owned(), false.into());
+        Ok(Value::Object(schema))
+    }
+
+    /// Returns the open JSON Schema of this entity type with its parents inlined into `allOf`.
+    ///
+    /// `chain` contains the base URLs of the entity types which are currently being inlined.
+    fn json_schema_object(
+        &self,
+        provider: &impl OntologyTypeProvider,
+        chain: &mut Vec<BaseUrl>,
+    ) -> Result<Map<String, Value>, JsonSchemaError> {
+        if chain.contains(&self.id.base_url) {
+            return Err(JsonSchemaError::InheritanceCycle(self.id.clone()));
+        }
+        chain.push(self.id.base_url.clone());
+
+        let mut schema = self
+            .property_object
+            .json_schema_object(provider, &mut Vec::new())?;
+        schema.insert("title".to_owned(), self.title.clone().into());
+        if let Some(description) = &self.description {
+            schema.insert("description".to_owned(), description.clone().into());
+        }
+
+        if !self.inherits_from.all_of().is_empty() {
+            let parents = self
+                .inherits_from
+                .all_of()
+                .iter()
+                .map(|parent| {
+                    provider
+                        .entity_type(parent)
+                        .ok_or_else(|| JsonSchemaError::UnresolvedEntityType(parent.url().clone()))
+                        .and_then(|parent| parent.json_schema_object(provider, chain))
+                        .map(Value::Object)
+                })
+                .collect::<Result<_, _>>()?;
+            schema.insert("allOf".to_owned(), parents);
+        }
+
+        chain.pop();
+        Ok(schema)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use crate::utils::tests::{entity_type, TestOntology};
+
+    const BUILT_AT: &str = "https://blockprotocol.org/@alice/types/property-type/built-at/";
+    const NUMBER_BELLS: &str = "https://blockprotocol.org/@alice/types/property-type/number-bells/";
+
+    fn ontology() -> TestOntology {
+        let mut ontology = TestOntology::new();
+        for (id, title, data_type) in [
+            (
+                BUILT_AT,
+                "Built At",
+                "https://blockprotocol.org/@blockprotocol/types/data-type/text/v/1",
+            ),
+            (
+                NUMBER_BELLS,
+                "Number of Bells",
+                "https://blockprotocol.org/@blockprotocol/types/data-type/number/v/1",
+            ),
+        ] {
+            ontology.add_property_type(
+                &json!({
+                    "$schema": "https://blockprotocol.org/types/modules/graph/0.3/schema/property-type",
+                    "kind": "propertyType",
+                    "$id": format!("{id}v/1"),
+                    "title": title,
+                    "oneOf": [{ "$ref": data_type }]
+                })
+                .to_string(),
+            );
+        }
+        ontology
+    }
+
+    #[test]
+    fn inlined_parents() {
+        let church = entity_type(crate::test_data::entity_type::CHURCH_V1);
+        let schema = church
+            .to_json_schema(&ontology())
+            .expect("failed to generate JSON Schema");
+
+        assert_eq!(schema["unevaluatedProperties"], json!(false));
+        assert_eq!(schema["allOf"][0]["title"], json!("Building"));
+        assert!(schema["allOf"][0].get("unevaluatedProperties").is_none());
+        assert_eq!(
+            schema["allOf"][0]["properties"][BUILT_AT]["anyOf"][0]["type"],
+            json!("string")
+        );
+
+        let validator = jsonschema::validator_for(&schema).expect("invalid JSON Schema");
+        assert!(validator.is_valid(&json!({ NUMBER_BELLS: 3, BUILT_AT: "1850" })));
+        assert!(validator.is_valid(&json!({ NUMBER_BELLS: 3 })));
+        assert!(!validator.is_valid(&json!({ BUILT_AT: "1850" })));
+        assert!(!validator.is_valid(&json!({ NUMBER_BELLS: "three" })));
+        assert!(!validator.is_valid(&json!({ NUMBER_BELLS: 3, "https://example.com/unknown/": 1 })));
+    }
+
+    #[test]
+    fn declaration_order() {
+        let entity_type = entity_type(
+            &json!({
+                "$schema": "https://blockprotocol.org/types/modules/graph/0.3/schema/entity-type",
+                "kind": "entityType",
+                "$id": "https://example.com/entity-type/tower/v/1",
+                "type": "object",
+                "title": "Tower",
+                "properties": {
+                    NUMBER_BELLS: { "$ref": format!("{NUMBER_BELLS}v/1") },
+                    BUILT_AT: { "$ref": format!("{BUILT_AT}v/1") },
+                },
+            })
+            .to_string(),
+        );
+        let schema = entity_type
+            .to_json_schema(&ontology())
+            .expect("failed to generate JSON Schema");
+
+        let properties = schema["properties"]
+            .as_object()
+            .expect("properties should be an object")
+            .keys()
+            .collect::<Vec<_>>();
+        assert_eq!(properties, [NUMBER_BELLS, BUILT_AT]);
+    }
+}
@@ -0,0 +1,354 @@
+use std::{str::FromStr, sync::OnceLock};
+
+use serde_json::Value;
+
+use crate::{
+    url::VersionedUrl, EntityType, EntityTypeReference, InstanceValidationError,
+    InstanceValidationReason, JsonPointer, JsonType, LinkValidationError, LinkValidationReason,
+    OntologyTypeProvider,
+};
+
+const LEFT_ENTITY_ID: &str = "leftEntityId";
+const RIGHT_ENTITY_ID: &str = "rightEntityId";
+const LEFT_TO_RIGHT_ORDER: &str = "leftToRightOrder";
+const RIGHT_TO_LEFT_ORDER: &str = "rightToLeftOrder";
+
+impl EntityType {
+    /// The `$id` of the entity type every link entity type inherits from, as defined in
+    /// [RFC 1020].
+    ///
+    /// [RFC 1020]: https://github.com/blockprotocol/blockprotocol/blob/main/rfcs/text/1020-link-entities.md#the-marker-annotation
+    pub const LINK_ENTITY_TYPE_ID: &'static str =
+        "https://blockprotocol.org/@blockprotocol/types/entity-type/link/v/1";
+
+    /// Returns a reference to the entity type identified by [`LINK_ENTITY_TYPE_ID`], which every
+    /// link entity type inherits from.
+    ///
+    /// [`LINK_ENTITY_TYPE_ID`]: Self::LINK_ENTITY_TYPE_ID
+    #[must_use]
+    #[expect(
+        clippy::missing_panics_doc,
+        reason = "the link entity type ID is a valid versioned URL"
+    )]
+    pub fn link_entity_type() -> &'static EntityTypeReference {
+        static LINK: OnceLock<EntityTypeReference> = OnceLock::new();
+        LINK.get_or_init(|| {
+            EntityTypeReference::new(
+                VersionedUrl::from_str(Self::LINK_ENTITY_TYPE_ID)
+                    .expect("link entity type ID should be a valid versioned URL"),
+            )
+        })
+    }
+
+    /// Returns `true` if this is a link entity type as defined in [RFC 1020].
+    ///
+    /// An entity type is a link entity type if it inherits from the [`link_entity_type`],
+    /// either directly or through one of its ancestors which are looked up through `provider`,
+    /// see [`is_subtype_of`].
+    ///
+    /// [RFC 1020]: https://github.com/blockprotocol/blockprotocol/blob/main/rfcs/text/1020-link-entities.md#link-entity-types
+    /// [`link_entity_type`]: Self::link_entity_type
+    /// [`is_subtype_of`]: Self::is_subtype_of
+    #[must_use]
+    pub fn is_link(&self, provider: &impl OntologyTypeProvider) -> bool {
+        self.is_subtype_of(Self::link_entity_type(), provider)
+    }
+
+    /// Checks that every key in [`links`] refers to a link entity type.
+    ///
+    /// The link entity types are looked up through `provider`.
+    ///
+    /// # Errors
+    ///
+    /// - [`UnresolvedLinkType`] if a link entity type could not be found through `provider`
+    /// - [`NotALinkEntityType`] if a key refers to an entity type which is not a link entity type
+    ///
+    /// [`links`]: Self::links
+    /// [`UnresolvedLinkType`]: LinkValidationReason::UnresolvedLinkType
+    /// [`NotALinkEntityType`]: LinkValidationReason::NotALinkEntityType
+    pub fn validate_link_types(
+        &self,
+        provider: &impl OntologyTypeProvider,
+    ) -> Result<(), Vec<LinkValidationError>> {
+        let errors = self
+            .links()
+            .keys()
+            .filter_map(|link| {
+                match provider.entity_type(<&EntityTypeReference>::from(link)) {
+                    None => Some(LinkValidationReason::UnresolvedLinkType),
+                    Some(link_type) if !link_type.is_link(provider) => {
+                        Some(LinkValidationReason::NotALinkEntityType)
+                    }
+                    Some(_) => None,
+                }
+                .map(|reason| LinkValidationError::new(link.clone(), reason))
+            })
+            .collect::<Vec<_>>();
+
+        if errors.is_empty() {
+            Ok(())
+        } else {
+            Err(errors)
+        }
+    }
+
+    /// Validates the `linkData` of an entity of this entity type as defined in [RFC 1020].
+    ///
+    /// Entities of a link entity type, see [`is_link`], have to provide `linkData` containing the
+    /// `leftEntityId` and `rightEntityId` of the linked entities as strings, and optionally the
+    /// non-negative integers `leftToRightOrder` and `rightToLeftOrder`. Entities of any other
+    /// entity type must not provide `linkData`.
+    ///
+    /// Each returned error carries the [`JsonPointer`] of the offending value inside `linkData`.
+    ///
+    /// # Errors
+    ///
+    /// - every [`InstanceValidationError`] found in `link_data`
+    ///
+    /// [RFC 1020]: https://github.com/blockprotocol/blockprotocol/blob/main/rfcs/text/1020-link-entities.md#linkdata
+    /// [`is_link`]: Self::is_link
+    pub fn validate_link_data(
+        &self,
+        link_data: Option<&Value>,
+        provider: &impl OntologyTypeProvider,
+    ) -> Result<(), Vec<InstanceValidationError>> {
+        let path = JsonPointer::root();
+        let mut errors = Vec::new();
+
+        match (self.is_link(provider), link_data) {
+            (false, None) => {}
+            (false, Some(_)) => errors.push(InstanceValidationError::new(
+                path,
+                InstanceValidationReason::UnexpectedLinkData,
+            )),
+            (true, None) => errors.push(InstanceValidationError::new(
+                path,
+                InstanceValidationReason::MissingLinkData,
+            )),
+            (true, Some(Value::Object(link_data))) => {
+                for field in [LEFT_ENTITY_ID, RIGHT_ENTITY_ID] {
+                    match link_data.get(field) {
+                        None => errors.push(InstanceValidationError::new(
+                            path.clone(),
+                            InstanceValidationReason::MissingLinkDataField(field.to_owned()),
+                        )),
+                        Some(Value::String(_)) => {}
+                        Some(value) => errors.push(InstanceValidationError::new(
+                            path.join(field),
+                            InstanceValidationReason::InvalidType {
+                                expected: JsonType::String,
+                                actual: JsonType::of(value),
+                            },
+                        )),
+                    }
+                }
+
+                for field in [LEFT_TO_RIGHT_ORDER, RIGHT_TO_LEFT_ORDER] {
+                    let Some(order) = link_data.get(field) else {
+                        continue;
+                    };
+
+                    if !JsonType::Integer.is_type_of(order) {
+                        errors.push(InstanceValidationError::new(
+                            path.join(field),
+                            InstanceValidationReason::InvalidType {
+                                expected: JsonType::Integer,
+                                actual: JsonType::of(order),
+                            },
+                        ));
+                    } else if let Value::Number(order) = order {
+                        if order.as_f64().is_some_and(f64::is_sign_negative) {
+                            errors.push(InstanceValidationError::new(
+                                path.join(field),
+                                InstanceValidationReason::BelowMinimum {
+                                    actual: order.clone(),
+                                    minimum: 0.into(),
+                                },
+                            ));
+                        }
+                    }
+                }
+
+                let mut unexpected = link_data
+                    .keys()
+                    .filter(|key| {
+                        ![
+                            LEFT_ENTITY_ID,
+                            RIGHT_ENTITY_ID,
+                            LEFT_TO_RIGHT_ORDER,
+                            RIGHT_TO_LEFT_ORDER,
+                        ]
+                        .contains(&key.as_str())
+                    })
+                    .collect::<Vec<_>>();
+                unexpected.sort_unstable();
+                errors.extend(unexpected.into_iter().map(|key| {
+                    InstanceValidationError::new(
+                        path.join(key),
+                        InstanceValidationReason::UnexpectedLinkDataField(key.clone()),
+                    )
+                }));
+            }
+            (true, Some(value)) => errors.push(InstanceValidationError::new(
+                path,
+                InstanceValidationReason::InvalidType {
+                    expected: JsonType::Object,
+                    actual: JsonType::of(value),
+                },
+            )),
+        }
+
+        if errors.is_empty() {
+            Ok(())
+        } else {
+            Err(errors)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+    use crate::utils::tests::{entity_type, url, TestOntology};
+
+    const FRIEND_OF: &str = "https://blockprotocol.org/@alice/types/entity-type/friend-of/v/1";
+    const OWNS: &str = "https://blockprotocol.org/@alice/types/entity-type/owns/v/1";
+
+    fn link_entity_type(id: &str, parent: &str) -> String {
+        json!({
+            "$schema": "https://blockprotocol.org/types/modules/graph/0.3/schema/entity-type",
+            "kind": "entityType",
+            "$id": id,
+            "type": "object",
+            "title": "Link",
+            "allOf": [{ "$ref": parent }],
+            "properties": {}
+        })
+        .to_string()
+    }
+
+    #[test]
+    fn link_types() {
+        let mut ontology = TestOntology::new();
+        let person = entity_type(crate::test_data::entity_type::PERSON_V1);
+
+        assert!(!person.is_link(&ontology));
+        assert_eq!(
+            person.validate_link_types(&ontology),
+            Err(vec![
+                LinkValidationError::new(url(FRIEND_OF), LinkValidationReason::UnresolvedLinkType),
+                LinkValidationError::new(url(OWNS), LinkValidationReason::UnresolvedLinkType),
+            ])
+        );
+
+        // `owns` inherits from `friend-of` which in turn is a link entity type
+        ontology.add_entity_type(&link_entity_type(
+            FRIEND_OF,
+            EntityType::LINK_ENTITY_TYPE_ID,
+        ));
+        ontology.add_entity_type(&link_entity_type(OWNS, FRIEND_OF));
+        person
+            .validate_link_types(&ontology)
+            .expect("validation failed");
+
+        let mut ontology = TestOntology::new();
+        ontology.add_entity_type(&link_entity_type(
+            FRIEND_OF,
+            EntityType::LINK_ENTITY_TYPE_ID,
+        ));
+        ontology.add_entity_type(&link_entity_type(
+            OWNS,
+            "https://blockprotocol.org/@alice/types/entity-type/building/v/1",
+        ));
+        assert_eq!(
+            person.validate_link_types(&ontology),
+            Err(vec![LinkValidationError::new(
+                url(OWNS),
+                LinkValidationReason::NotALinkEntityType
+            )])
+        );
+    }
+
+    #[test]
+    fn link_data() {
+        let ontology = TestOntology::new();
+        let friend_of = entity_type(&link_entity_type(
+            FRIEND_OF,
+            EntityType::LINK_ENTITY_TYPE_ID,
+        ));
+        let person = entity_type(crate::test_data::entity_type::PERSON_V1);
+        let link_data = json!({
+            "leftEntityId": "a28041f9-f8c5-4275-a601-ac5cb0d57a0d",
+            "rightEntityId": "6d7fdb6c-f519-4554-a6d7-485c818c7a63",
+            "leftToRightOrder": 2,
+            "rightToLeftOrder": 10
+        });
+
+        assert!(friend_of.is_link(&ontology));
+        friend_of
+            .validate_link_data(Some(&link_data), &ontology)
+            .expect("validation failed");
+        person
+            .validate_link_data(None, &ontology)
+            .expect("validation failed");
+
+        assert_eq!(
+            person.validate_link_data(Some(&link_data), &ontology),
+            Err(vec![InstanceValidationError::new(
+                JsonPointer::root(),
+                InstanceValidationReason::UnexpectedLinkData
+            )])
+        );
+        assert_eq!(
+            friend_of.validate_link_data(None, &ontology),
+            Err(vec![InstanceValidationError::new(
+                JsonPointer::root(),
+                InstanceValidationReason::MissingLinkData
+            )])
+        );
+        assert_eq!(
+            friend_of.validate_link_data(
+                Some(&json!({
+                    "leftEntityId": 1,
+                    "leftToRightOrder": -1,
+                    "rightToLeftOrder": 0.5,
+                    "sourceEntityId": "a28041f9-f8c5-4275-a601-ac5cb0d57a0d"
+                })),
+                &ontology
+            ),
+            Err(vec![
+                InstanceValidationError::new(
+                    JsonPointer::root().join("leftEntityId"),
+                    InstanceValidationReason::InvalidType {
+                        expected: JsonType::String,
+                        actual: JsonType::Number,
+                    }
+                ),
+                InstanceValidationError::new(
+                    JsonPointer::root(),
+                    InstanceValidationReason::MissingLinkDataField("rightEntityId".to_owned())
+                ),
+                InstanceValidationError::new(
+                    JsonPointer::root().join("leftToRightOrder"),
+                    InstanceValidationReason::BelowMinimum {
+                        actual: (-1).into(),
+                        minimum: 0.into(),
+                    }
+                ),
+                InstanceValidationError::new(
+                    JsonPointer::root().join("rightToLeftOrder"),
+                    InstanceValidationReason::InvalidType {
+                        expected: JsonType::Integer,
+                        actual: JsonType::Number,
+                    }
+                ),
+                InstanceValidationError::new(
+                    JsonPointer::root().join("sourceEntityId"),
+                    InstanceValidationReason::UnexpectedLinkDataField("sourceEntityId".to_owned())
+                ),
+            ])
+        );
+    }
+}
@@ -3,16 +3,87 @@ use thiserror::Error;
 #[cfg(target_arch = "wasm32")]
 use tsify::Tsify;
 
-use crate::{url::ParseVersionedUrlError, ParseEntityTypeReferenceArrayError};
+use crate::{
+    ontology::shared::json_pointer::ROOT,
+    url::{ParseVersionedUrlError, VersionedUrl},
+    JsonPointer, ParseEntityTypeReferenceArrayError, ValidationError,
+};
 
 #[cfg_attr(target_arch = "wasm32", derive(Tsify))]
 #[derive(Debug, Deserialize, Serialize, PartialEq, Eq, Error)]
 #[serde(tag = "reason", content = "inner")]
 pub enum ParseLinksError {
-    #[error("invalid link key: `{0}`")]
-    InvalidLinkKey(ParseVersionedUrlError),
+    #[error("invalid link key at `{path}`: `{error}`")]
+    InvalidLinkKey {
+        path: JsonPointer,
+        error: ParseVersionedUrlError,
+    },
     #[error("invalid array definition: `{0}`")]
     InvalidArray(ParseEntityTypeReferenceArrayError),
+    #[error("invalid key inside required links at `{path}`: `{error}`")]
+    InvalidRequiredLinkKey {
+        path: JsonPointer,
+        error: ParseVersionedUrlError,
+    },
+    #[error("failed validation at `{path}`: `{error}`")]
+    ValidationError {
+        path: JsonPointer,
+        error: ValidationError,
+    },
     #[error("error in JSON: `{0}`")]
     InvalidJson(String),
 }
+
+impl ParseLinksError {
+    /// Returns the location of the node which failed to parse, relative to the schema containing
+    /// `links`.
+    #[must_use]
+    pub fn path(&self) -> &JsonPointer {
+        match self {
+            Self::InvalidLinkKey { path, .. }
+            | Self::InvalidRequiredLinkKey { path, .. }
+            | Self::ValidationError { path, .. } => path,
+            Self::InvalidArray(error) => error.path(),
+            Self::InvalidJson(_) => &ROOT,
+        }
+    }
+}
+
+/// An error found while validating the outgoing links of an entity, addressed by the link entity
+/// type which is violated.
+#[cfg_attr(target_arch = "wasm32", derive(Tsify))]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, Error)]
+#[error("invalid links of type `{link}`: {reason}")]
+pub struct LinkValidationError {
+    pub link: VersionedUrl,
+    pub reason: LinkValidationReason,
+}
+
+impl LinkValidationError {
+    #[must_use]
+    pub const fn new(link: VersionedUrl, reason: LinkValidationReason) -> Self {
+        Self { link, reason }
+    }
+}
+
+#[cfg_attr(target_arch = "wasm32", derive(Tsify))]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, Error)]
+#[serde(tag = "reason", content = "inner")]
+pub enum LinkValidationReason {
+    #[error("the link is not defined in the schema")]
+    UnexpectedLink,
+    #[error("the link is required but missing")]
+    MissingRequiredLink,
+    #[error("expected at least {min_items} links but received {actual}")]
+    TooFewLinks { actual: usize, min_items: usize },
+    #[error("expected at most {max_items} links but received {actual}")]
+    TooManyLinks { actual: usize, max_items: usize },
+    #[error("the destination `{0}` is not allowed for the link")]
+    InvalidDestination(VersionedUrl),
+    #[error("the destination `{0}` could not be resolved")]
+    UnresolvedDestination(VersionedUrl),
+    #[error("the link entity type could not be resolved")]
+    UnresolvedLinkType,
+    #[error("the entity type is not a link entity type")]
+    NotALinkEntityType,
+}
@@ -1,36 +1,100 @@
 mod error;
 pub(in crate::ontology) mod raw;
+pub(super) mod serialization;
 #[cfg(target_arch = "wasm32")]
 mod wasm;
 
-use std::collections::HashMap;
-
-pub use error::ParseLinksError;
+pub use error::{LinkValidationError, LinkValidationReason, ParseLinksError};
+use indexmap::{IndexMap, IndexSet};
 
 use crate::{
     url::{BaseUrl, VersionedUrl},
-    Array, EntityTypeReference, OneOf, ValidateUrl, ValidationError,
+    Array, EntityTypeReference, OneOf, Unsatisfiable, ValidateUrl, ValidationError,
 };
 
 #[derive(Debug, Clone, PartialEq, Eq)]
-pub struct Links(
-    pub(crate) HashMap<VersionedUrl, MaybeOrderedArray<Option<OneOf<EntityTypeReference>>>>,
-);
+pub struct Links {
+    links: IndexMap<VersionedUrl, MaybeOrderedArray<Option<OneOf<EntityTypeReference>>>>,
+    required_links: Vec<VersionedUrl>,
+}
 
 impl Links {
-    /// Creates a new `Links` object.
+    /// Creates a new `Links` object with the given links and required links.
+    ///
+    /// # Errors
+    ///
+    /// - [`ValidationError::MissingRequiredLink`] if a required link is not a key in `links`.
+    pub fn new(
+        links: IndexMap<VersionedUrl, MaybeOrderedArray<Option<OneOf<EntityTypeReference>>>>,
+        required_links: Vec<VersionedUrl>,
+    ) -> Result<Self, ValidationError> {
+        let links = Self::new_unchecked(links, required_links);
+        links.validate()?;
+        Ok(links)
+    }
+
+    /// Creates a new `Links` object without validating.
     #[must_use]
-    pub const fn new(
-        links: HashMap<VersionedUrl, MaybeOrderedArray<Option<OneOf<EntityTypeReference>>>>,
+    pub const fn new_unchecked(
+        links: IndexMap<VersionedUrl, MaybeOrderedArray<Option<OneOf<EntityTypeReference>>>>,
+        required_links: Vec<VersionedUrl>,
     ) -> Self {
-        Self(links)
+        Self {
+            links,
+            required_links,
+        }
+    }
+
+    fn validate(&self) -> Result<(), ValidationError> {
+        let mut errors = Vec::new();
+        Self::validate_at(self.links(), self.required_links(), &mut errors);
+        errors.into_iter().next().map_or(Ok(()), Err)
+    }
+
+    /// Reports every [`ValidationError`] of links with the keys of `links` and `required_links`.
+    ///
+    /// Links which failed to parse still count as defined.
+    pub(crate) fn validate_at<V>(
+        links: &IndexMap<VersionedUrl, V>,
+        required_links: &[VersionedUrl],
+        errors: &mut Vec<ValidationError>,
+    ) {
+        for link in required_links {
+            if !links.contains_key(link) {
+                errors.push(ValidationError::MissingRequiredLink(link.clone()));
+            }
+        }
     }
 
     #[must_use]
     pub const fn links(
         &self,
-    ) -> &HashMap<VersionedUrl, MaybeOrderedArray<Option<OneOf<EntityTypeReference>>>> {
-        &self.0
+    ) -> &IndexMap<VersionedUrl, MaybeOrderedArray<Option<OneOf<EntityTypeReference>>>> {
+        &self.links
+    }
+
+    #[must_use]
+    pub fn required_links(&self) -> &[VersionedUrl] {
+        &self.required_links
+    }
+
+    /// Adds the links of `other` which are not defined yet and requires the links required by
+    /// `other`.
+    ///
+    /// Already defined links are kept as they are, checking them against `other` is up to the
+    /// caller. Required links keep their order and are not duplicated.
+    pub(crate) fn merge(&mut self, other: Self) {
+        for (link, array) in other.links {
+            self.links.entry(link).or_insert(array);
+        }
+
+        self.required_links = self
+            .required_links
+            .drain(..)
+            .chain(other.required_links)
+            .collect::<IndexSet<_>>()
+            .into_iter()
+            .collect();
     }
 }
 
@@ -63,6 +127,18 @@ impl<T> MaybeOrderedArray<T> {
     pub const fn ordered(&self) -> bool {
         self.ordered
     }
+
+    /// Checks that the bounds of this array allow at least one number of links, see
+    /// [`Array::check_satisfiable`].
+    ///
+    /// # Errors
+    ///
+    /// - [`MinItemsExceedsMaxItems`] if `minItems` is greater than `maxItems`
+    ///
+    /// [`MinItemsExceedsMaxItems`]: crate::UnsatisfiableReason::MinItemsExceedsMaxItems
+    pub fn check_satisfiable(&self) -> Result<(), Vec<Unsatisfiable>> {
+        self.array.check_satisfiable()
+    }
 }
 
 impl<T: ValidateUrl> ValidateUrl for MaybeOrderedArray<T> {
@@ -1,11 +1,18 @@
-use std::{collections::HashMap, str::FromStr};
+use std::str::FromStr;
 
+use indexmap::IndexMap;
 use serde::{Deserialize, Serialize};
 #[cfg(target_arch = "wasm32")]
 use {tsify::Tsify, wasm_bindgen::prelude::*};
 
 use crate::{
-    raw, url::VersionedUrl, EntityTypeReference, OneOf, ParseEntityTypeReferenceArrayError,
+    ontology::shared::{
+        json_pointer::Located,
+        parse::{collect, first_error, TryFromRaw},
+    },
+    raw,
+    url::VersionedUrl,
+    Array, EntityTypeReference, JsonPointer, OneOf, ParseEntityTypeReferenceArrayError,
     ParseLinksError, ParseOneOfError,
 };
 
@@ -20,38 +27,101 @@ pub struct Links {
             type = "Record<VersionedUrl, MaybeOrderedArray<MaybeOneOfEntityTypeReference>>"
         )
     )]
-    #[serde(default, skip_serializing_if = "HashMap::is_empty")]
-    pub links: HashMap<String, MaybeOrderedArray<MaybeOneOfEntityTypeReference>>,
+    #[serde(default, skip_serializing_if = "IndexMap::is_empty")]
+    pub links: IndexMap<String, MaybeOrderedArray<MaybeOneOfEntityTypeReference>>,
+    #[cfg_attr(target_arch = "wasm32", tsify(optional, type = "VersionedUrl[]"))]
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub required_links: Vec<String>,
 }
 
-impl TryFrom<Links> for super::Links {
+impl TryFromRaw<Links> for super::Links {
     type Error = ParseLinksError;
 
-    fn try_from(links_repr: Links) -> Result<Self, Self::Error> {
-        let links = links_repr
-            .links
-            .into_iter()
-            .map(|(url, val)| {
-                Ok((
-                    VersionedUrl::from_str(&url).map_err(ParseLinksError::InvalidLinkKey)?,
-                    val.try_into().map_err(ParseLinksError::InvalidArray)?,
-                ))
+    fn try_from_raw(links_repr: Links) -> Result<Self, Vec<Self::Error>> {
+        let path = JsonPointer::root();
+        let mut errors = Vec::new();
+
+        // Links whose array failed to parse are kept as `None`, they are still defined
+        let mut links = IndexMap::with_capacity(links_repr.links.len());
+        for (url, val) in links_repr.links {
+            let link_path = path.join("links").join(&url);
+            let url = VersionedUrl::from_str(&url).map_err(|error| {
+                errors.push(ParseLinksError::InvalidLinkKey {
+                    path: link_path.clone(),
+                    error,
+                });
+            });
+            let val = collect(
+                super::MaybeOrderedArray::try_from_raw(val),
+                &mut errors,
+                |error| ParseLinksError::InvalidArray(error.prefixed(&link_path)),
+            );
+            if let Ok(url) = url {
+                links.insert(url, val);
+            }
+        }
+
+        let required_links = links_repr
+            .required_links
+            .iter()
+            .enumerate()
+            .filter_map(|(index, url)| {
+                VersionedUrl::from_str(url)
+                    .map_err(|error| {
+                        errors.push(ParseLinksError::InvalidRequiredLinkKey {
+                            path: path.join("requiredLinks").join(index),
+                            error,
+                        });
+                    })
+                    .ok()
             })
-            .collect::<Result<HashMap<_, _>, Self::Error>>()?;
+            .collect::<Vec<_>>();
+
+        let mut validation_errors = Vec::new();
+        Self::validate_at(&links, &required_links, &mut validation_errors);
+        errors.extend(validation_errors.into_iter().map(|error| {
+            ParseLinksError::ValidationError {
+                path: error.path(),
+                error,
+            }
+        }));
+
+        if !errors.is_empty() {
+            return Err(errors);
+        }
+        let links = links
+            .into_iter()
+            .filter_map(|(url, val)| Some((url, val?)))
+            .collect();
+        Ok(Self::new_unchecked(links, required_links))
+    }
+}
+
+impl TryFrom<Links> for super::Links {
+    type Error = ParseLinksError;
 
-        Ok(Self::new(links))
+    fn try_from(links_repr: Links) -> Result<Self, Self::Error> {
+        first_error(Self::try_from_raw(links_repr))
     }
 }
 
 impl From<super::Links> for Links {
     fn from(object: super::Links) -> Self {
         let links = object
-            .0
+            .links
             .into_iter()
             .map(|(url, val)| (url.to_string(), val.into()))
             .collect();
+        let required_links = object
+            .required_links
+            .into_iter()
+            .map(|url| url.to_string())
+            .collect();
 
-        Self { links }
+        Self {
+            links,
+            required_links,
+        }
     }
 }
 
@@ -64,21 +134,33 @@ pub struct MaybeOrderedArray<T> {
     ordered: bool,
 }
 
-impl TryFrom<MaybeOrderedArray<MaybeOneOfEntityTypeReference>>
+impl TryFromRaw<MaybeOrderedArray<MaybeOneOfEntityTypeReference>>
     for super::MaybeOrderedArray<Option<OneOf<EntityTypeReference>>>
 {
     type Error = ParseEntityTypeReferenceArrayError;
 
-    fn try_from(
+    fn try_from_raw(
         maybe_ordered_array_repr: MaybeOrderedArray<MaybeOneOfEntityTypeReference>,
-    ) -> Result<Self, Self::Error> {
+    ) -> Result<Self, Vec<Self::Error>> {
         Ok(Self {
-            array: maybe_ordered_array_repr.array.try_into()?,
+            array: Array::try_from_raw(maybe_ordered_array_repr.array)?,
             ordered: maybe_ordered_array_repr.ordered,
         })
     }
 }
 
+impl TryFrom<MaybeOrderedArray<MaybeOneOfEntityTypeReference>>
+    for super::MaybeOrderedArray<Option<OneOf<EntityTypeReference>>>
+{
+    type Error = ParseEntityTypeReferenceArrayError;
+
+    fn try_from(
+        maybe_ordered_array_repr: MaybeOrderedArray<MaybeOneOfEntityTypeReference>,
+    ) -> Result<Self, Self::Error> {
+        first_error(Self::try_from_raw(maybe_ordered_array_repr))
+    }
+}
+
 impl From<super::MaybeOrderedArray<Option<OneOf<EntityTypeReference>>>>
     for MaybeOrderedArray<MaybeOneOfEntityTypeReference>
 {
@@ -259,4 +341,93 @@ mod tests {
             ensure_repr_failed_deserialization::<MaybeOrderedArray<StringTypeStruct>>(as_json);
         }
     }
+
+    mod links {
+        use super::*;
+        use crate::{
+            url::VersionedUrl,
+            utils::tests::{check_serialization_from_str, ensure_failed_validation},
+            ValidationError,
+        };
+
+        const LINK: &str = "https://example.com/entity-type/written-by/v/1";
+
+        #[test]
+        fn required_links() {
+            let links = check_serialization_from_str::<crate::Links, Links>(
+                &json!({
+                    "links": {
+                        LINK: { "type": "array", "items": {}, "ordered": false }
+                    },
+                    "requiredLinks": [LINK]
+                })
+                .to_string(),
+                None,
+            );
+
+            assert_eq!(links.required_links(), [
+                VersionedUrl::from_str(LINK).expect("invalid versioned URL")
+            ]);
+        }
+
+        #[test]
+        fn missing_required_link() {
+            ensure_failed_validation::<Links, crate::Links>(
+                &json!({
+                    "links": {},
+                    "requiredLinks": [LINK]
+                }),
+                ParseLinksError::ValidationError {
+                    path: JsonPointer::root().join("requiredLinks"),
+                    error: ValidationError::MissingRequiredLink(
+                        VersionedUrl::from_str(LINK).expect("invalid versioned URL"),
+                    ),
+                },
+            );
+        }
+
+        #[test]
+        fn invalid_required_link_key() {
+            let result = crate::Links::try_from(Links {
+                links: IndexMap::new(),
+                required_links: vec!["https://example.com/entity-type/written-by/".to_owned()],
+            });
+
+            assert!(matches!(
+                result,
+                Err(ParseLinksError::InvalidRequiredLinkKey { ref path, .. })
+                    if *path == JsonPointer::root().join("requiredLinks").join(0)
+            ));
+        }
+
+        #[test]
+        fn declaration_order() {
+            let links = [
+                "https://example.com/entity-type/written-by/v/1",
+                "https://example.com/entity-type/contains/v/1",
+                "https://example.com/entity-type/authored/v/1",
+            ];
+            let input = json!({
+                "links": {
+                    links[0]: { "type": "array", "items": {}, "ordered": false },
+                    links[1]: { "type": "array", "items": {}, "ordered": true },
+                    links[2]: { "type": "array", "items": {}, "ordered": false },
+                }
+            })
+            .to_string();
+
+            let parsed = check_serialization_from_str::<crate::Links, Links>(&input, None);
+
+            let keys = parsed
+                .links()
+                .keys()
+                .map(ToString::to_string)
+                .collect::<Vec<_>>();
+            assert_eq!(keys, links);
+            assert_eq!(
+                serde_json::to_string(&Links::from(parsed)).expect("failed to serialize"),
+                input
+            );
+        }
+    }
 }
@@ -0,0 +1,225 @@
+use indexmap::IndexMap;
+use serde::{
+    de::{self, MapAccess},
+    ser::SerializeStruct,
+    Deserialize, Deserializer, Serialize, Serializer,
+};
+
+use crate::{
+    ontology::shared::{
+        array::serialization::ArrayFields,
+        serialization::{
+            deserialize_map, next_tag, next_value, required, required_tag,
+            serialize_optional_field, FromMap, Key,
+        },
+    },
+    url::VersionedUrl,
+    EntityTypeReference, Links, MaybeOrderedArray, OneOf, ParseLinksError,
+};
+
+const FIELDS: &[&str] = &["links", "requiredLinks"];
+const MAYBE_ORDERED_ARRAY_FIELDS: &[&str] = &["type", "items", "minItems", "maxItems", "ordered"];
+
+/// The keywords of [`Links`], see [`serialization`].
+///
+/// [`serialization`]: crate::ontology::shared::serialization
+#[derive(Default)]
+pub(crate) struct LinksFields {
+    links: Option<IndexMap<VersionedUrl, MaybeOrderedArray<Option<OneOf<EntityTypeReference>>>>>,
+    required_links: Option<Vec<VersionedUrl>>,
+}
+
+impl LinksFields {
+    /// Deserializes the next value of `map` if `key` is a keyword of links.
+    ///
+    /// Returns `false` without consuming the value otherwise.
+    pub(crate) fn next_value<'de, A>(&mut self, key: &str, map: &mut A) -> Result<bool, A::Error>
+    where
+        A: MapAccess<'de>,
+    {
+        match key {
+            "links" => next_value(map, &mut self.links, "links")?,
+            "requiredLinks" => next_value(map, &mut self.required_links, "requiredLinks")?,
+            _ => return Ok(false),
+        }
+        Ok(true)
+    }
+
+    /// Creates the links from the deserialized keywords, see [`Links::new`].
+    pub(crate) fn finish<E>(self) -> Result<Links, E>
+    where
+        E: de::Error,
+    {
+        Links::new(
+            self.links.unwrap_or_default(),
+            self.required_links.unwrap_or_default(),
+        )
+        .map_err(|error| {
+            E::custom(ParseLinksError::ValidationError {
+                path: error.path(),
+                error,
+            })
+        })
+    }
+}
+
+impl Links {
+    /// Serializes the keywords of these links into `state`, e.g. to flatten them into an
+    /// [`EntityType`].
+    ///
+    /// [`EntityType`]: crate::EntityType
+    pub(crate) fn serialize_fields<S>(&self, state: &mut S) -> Result<(), S::Error>
+    where
+        S: SerializeStruct,
+    {
+        serialize_optional_field(
+            state,
+            "links",
+            (!self.links().is_empty()).then(|| self.links()),
+        )?;
+        serialize_optional_field(
+            state,
+            "requiredLinks",
+            (!self.required_links().is_empty()).then(|| self.required_links()),
+        )
+    }
+}
+
+impl Serialize for Links {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("Links", 2)?;
+        self.serialize_fields(&mut state)?;
+        state.end()
+    }
+}
+
+impl<'de> FromMap<'de> for Links {
+    const EXPECTING: &'static str = "links";
+
+    fn from_map<A>(mut map: A) -> Result<Self, A::Error>
+    where
+        A: MapAccess<'de>,
+    {
+        let mut fields = LinksFields::default();
+        while let Some(key) = map.next_key::<Key>()? {
+            if !fields.next_value(&key, &mut map)? {
+                return Err(de::Error::unknown_field(&key, FIELDS));
+            }
+        }
+
+        fields.finish()
+    }
+}
+
+impl<'de> Deserialize<'de> for Links {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserialize_map(deserializer)
+    }
+}
+
+/// The items of a link, which are either unconstrained (`{}`) or a `oneOf`.
+struct MaybeOneOf<T>(T);
+
+impl<T: Serialize> Serialize for MaybeOneOf<&Option<OneOf<T>>> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        match self.0 {
+            Some(one_of) => one_of.serialize(serializer),
+            None => serializer.serialize_struct("OneOf", 0)?.end(),
+        }
+    }
+}
+
+impl<'de, T: Deserialize<'de>> FromMap<'de> for MaybeOneOf<Option<OneOf<T>>> {
+    const EXPECTING: &'static str = "an optional `oneOf` schema";
+
+    fn from_map<A>(mut map: A) -> Result<Self, A::Error>
+    where
+        A: MapAccess<'de>,
+    {
+        let mut possibilities = None;
+        while let Some(key) = map.next_key::<Key>()? {
+            match &*key {
+                "oneOf" => next_value(&mut map, &mut possibilities, "oneOf")?,
+                key => return Err(de::Error::unknown_field(key, &["oneOf"])),
+            }
+        }
+
+        possibilities
+            .map(OneOf::from_deserialized)
+            .transpose()
+            .map(Self)
+    }
+}
+
+impl<'de, T: Deserialize<'de>> Deserialize<'de> for MaybeOneOf<Option<OneOf<T>>> {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserialize_map(deserializer)
+    }
+}
+
+impl<T: Serialize> Serialize for MaybeOrderedArray<Option<OneOf<T>>> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let array = self.array();
+        let mut state = serializer.serialize_struct("MaybeOrderedArray", 5)?;
+        state.serialize_field("type", "array")?;
+        state.serialize_field("items", &MaybeOneOf(array.items()))?;
+        serialize_optional_field(&mut state, "minItems", array.min_items().as_ref())?;
+        serialize_optional_field(&mut state, "maxItems", array.max_items().as_ref())?;
+        state.serialize_field("ordered", &self.ordered())?;
+        state.end()
+    }
+}
+
+impl<'de, T: Deserialize<'de>> FromMap<'de> for MaybeOrderedArray<Option<OneOf<T>>> {
+    const EXPECTING: &'static str = "an array of links";
+
+    fn from_map<A>(mut map: A) -> Result<Self, A::Error>
+    where
+        A: MapAccess<'de>,
+    {
+        let mut r#type = false;
+        let mut ordered = None;
+        let mut array = ArrayFields::<MaybeOneOf<Option<OneOf<T>>>>::default();
+        while let Some(key) = map.next_key::<Key>()? {
+            match &*key {
+                "type" => next_tag(&mut map, &mut r#type, "type", "array")?,
+                "ordered" => next_value(&mut map, &mut ordered, "ordered")?,
+                key => {
+                    if !array.next_value(key, &mut map)? {
+                        return Err(de::Error::unknown_field(key, MAYBE_ORDERED_ARRAY_FIELDS));
+                    }
+                }
+            }
+        }
+
+        required_tag(r#type, "type")?;
+        Ok(Self {
+            array: array.finish()?.map_items(|MaybeOneOf(items)| items),
+            ordered: required(ordered, "ordered")?,
+        })
+    }
+}
+
+impl<'de, T: Deserialize<'de>> Deserialize<'de> for MaybeOrderedArray<Option<OneOf<T>>> {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserialize_map(deserializer)
+    }
+}
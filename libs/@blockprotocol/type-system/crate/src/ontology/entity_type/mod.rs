@@ -1,23 +1,34 @@
+mod compatibility;
+mod diff;
+mod duplicate;
 mod error;
+mod inheritance;
+mod json_schema;
+mod link;
 pub(in crate::ontology) mod links;
 pub(in crate::ontology) mod raw;
+mod satisfiability;
+mod serialization;
+mod substitution;
+mod validation;
 #[cfg(target_arch = "wasm32")]
 mod wasm;
 
 use std::collections::{HashMap, HashSet};
 
-pub use error::ParseEntityTypeError;
-use serde::{Deserialize, Serialize};
+pub use error::{
+    DuplicateEntityTypeError, MergeEntityTypeError, ParseEntityTypeError, SubstitutionError,
+};
+use indexmap::{IndexMap, IndexSet};
 
 use crate::{
-    ontology::entity_type::error::MergeEntityTypeError,
+    ontology::shared::parse::parse_all,
     url::{BaseUrl, VersionedUrl},
-    AllOf, Links, MaybeOrderedArray, Object, OneOf, PropertyTypeReference, ValidateUrl,
-    ValidationError, ValueOrArray,
+    AllOf, JsonPointer, Links, MaybeOrderedArray, Object, OneOf, OntologyTypeProvider,
+    PropertyTypeReference, ValidateUrl, ValidationError, ValueOrArray,
 };
 
-#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
-#[serde(try_from = "raw::EntityType", into = "raw::EntityType")]
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub struct EntityType {
     id: VersionedUrl,
     title: String,
@@ -25,7 +36,7 @@ pub struct EntityType {
     property_object: Object<ValueOrArray<PropertyTypeReference>>,
     pub inherits_from: AllOf<EntityTypeReference>,
     links: Links,
-    examples: Vec<HashMap<BaseUrl, serde_json::Value>>,
+    examples: Vec<IndexMap<BaseUrl, serde_json::Value>>,
 }
 
 impl EntityType {
@@ -38,7 +49,7 @@ impl EntityType {
         property_object: Object<ValueOrArray<PropertyTypeReference>>,
         inherits_from: AllOf<EntityTypeReference>,
         links: Links,
-        examples: Vec<HashMap<BaseUrl, serde_json::Value>>,
+        examples: Vec<IndexMap<BaseUrl, serde_json::Value>>,
     ) -> Self {
         Self {
             id,
@@ -51,6 +62,23 @@ impl EntityType {
         }
     }
 
+    /// Parses the JSON `schema` of an entity type, reporting every error instead of only the first
+    /// one.
+    ///
+    /// Deserializing `schema` fails fast, as it has to have the shape of an entity type before its
+    /// values can be checked.
+    ///
+    /// # Errors
+    ///
+    /// - [`InvalidJson`] if `schema` could not be deserialized, this is the only error reported
+    ///   then
+    /// - every other [`ParseEntityTypeError`] found in `schema`, in the order they are encountered
+    ///
+    /// [`InvalidJson`]: ParseEntityTypeError::InvalidJson
+    pub fn parse_all(schema: serde_json::Value) -> Result<Self, Vec<ParseEntityTypeError>> {
+        parse_all::<raw::EntityType, _>(schema, ParseEntityTypeError::InvalidJson)
+    }
+
     #[must_use]
     pub const fn id(&self) -> &VersionedUrl {
         &self.id
@@ -72,7 +100,7 @@ impl EntityType {
     }
 
     #[must_use]
-    pub const fn properties(&self) -> &HashMap<BaseUrl, ValueOrArray<PropertyTypeReference>> {
+    pub const fn properties(&self) -> &IndexMap<BaseUrl, ValueOrArray<PropertyTypeReference>> {
         self.property_object.properties()
     }
 
@@ -84,12 +112,17 @@ impl EntityType {
     #[must_use]
     pub const fn links(
         &self,
-    ) -> &HashMap<VersionedUrl, MaybeOrderedArray<Option<OneOf<EntityTypeReference>>>> {
+    ) -> &IndexMap<VersionedUrl, MaybeOrderedArray<Option<OneOf<EntityTypeReference>>>> {
         self.links.links()
     }
 
     #[must_use]
-    pub const fn examples(&self) -> &Vec<HashMap<BaseUrl, serde_json::Value>> {
+    pub fn required_links(&self) -> &[VersionedUrl] {
+        self.links.required_links()
+    }
+
+    #[must_use]
+    pub const fn examples(&self) -> &Vec<IndexMap<BaseUrl, serde_json::Value>> {
         &self.examples
     }
 
@@ -98,48 +131,143 @@ impl EntityType {
     /// This will:
     ///   - remove the other entity type from the `allOf`
     ///   - merge the `properties` and `required` fields
-    ///   - merge the `links` field
+    ///   - merge the `links` and `requiredLinks` fields
+    ///
+    /// A property or link defined by both entity types has to be defined by this entity type in a
+    /// way which narrows the definition of `other`, i.e. it has to be compatible with it as checked
+    /// by [`is_compatible_with`]. The narrower definition of this entity type is kept. Otherwise
+    /// the entity types are left untouched and an error is returned. The types referenced by
+    /// differing definitions are looked up through `provider`.
     ///
     /// # Notes
     ///
     /// - This does not validate the resulting entity type.
-    /// - The `required` field may have a different order after merging.
     ///
     /// # Errors
     ///
     /// - [`DoesNotInheritFrom`] if the other entity type is not in the `allOf` field
+    /// - [`PropertyTypeVersionMismatch`] if this entity type references a version of a property
+    ///   type which is not compatible with the version referenced by `other`
+    /// - [`ConflictingPropertyDefinition`] if this entity type defines a property in a way which
+    ///   does not narrow the definition of `other`, e.g. as an array with a higher `maxItems`
+    /// - [`ConflictingLinkConstraints`] if this entity type defines a link in a way which does not
+    ///   narrow the definition of `other`
     ///
+    /// [`is_compatible_with`]: Self::is_compatible_with
     /// [`DoesNotInheritFrom`]: MergeEntityTypeError::DoesNotInheritFrom
-    pub fn merge_parent(&mut self, other: Self) -> Result<(), MergeEntityTypeError> {
-        self.inherits_from.elements.remove(
-            self.inherits_from
-                .all_of()
-                .iter()
-                .position(|x| x.url == other.id)
-                .ok_or_else(|| MergeEntityTypeError::DoesNotInheritFrom {
-                    child: self.id.clone(),
-                    parent: other.id.clone(),
-                })?,
-        );
+    /// [`PropertyTypeVersionMismatch`]: MergeEntityTypeError::PropertyTypeVersionMismatch
+    /// [`ConflictingPropertyDefinition`]: MergeEntityTypeError::ConflictingPropertyDefinition
+    /// [`ConflictingLinkConstraints`]: MergeEntityTypeError::ConflictingLinkConstraints
+    pub fn merge_parent(
+        &mut self,
+        other: Self,
+        provider: &impl OntologyTypeProvider,
+    ) -> Result<(), MergeEntityTypeError> {
+        let position = self
+            .inherits_from
+            .all_of()
+            .iter()
+            .position(|x| x.url == other.id)
+            .ok_or_else(|| MergeEntityTypeError::DoesNotInheritFrom {
+                child: self.id.clone(),
+                parent: other.id.clone(),
+            })?;
+
+        self.check_merge_conflicts(&other, provider)?;
+
+        self.inherits_from.elements.remove(position);
 
         self.inherits_from
             .elements
             .extend(other.inherits_from.elements);
 
-        self.property_object
-            .properties
-            .extend(other.property_object.properties);
+        let mut properties = other.property_object.properties;
+        properties.retain(|base_url, _| !self.property_object.properties.contains_key(base_url));
+        self.property_object.properties.extend(properties);
 
         self.property_object.required = self
             .property_object
             .required
             .drain(..)
             .chain(other.property_object.required)
-            .collect::<HashSet<_>>()
+            .collect::<IndexSet<_>>()
             .into_iter()
             .collect();
 
-        self.links.0.extend(other.links.0);
+        self.links.merge(other.links);
+
+        Ok(())
+    }
+
+    /// Ensures that every property and link defined by both `self` and `other` is defined by
+    /// `self` in a way which narrows the definition of `other`.
+    fn check_merge_conflicts(
+        &self,
+        other: &Self,
+        provider: &impl OntologyTypeProvider,
+    ) -> Result<(), MergeEntityTypeError> {
+        let path = JsonPointer::root();
+
+        for (base_url, parent_property) in other.properties() {
+            let Some(child_property) = self.properties().get(base_url) else {
+                continue;
+            };
+
+            let mut incompatibilities = Vec::new();
+            child_property.check_compatibility_at(
+                parent_property,
+                &path,
+                provider,
+                &mut incompatibilities,
+            );
+            if incompatibilities.is_empty() {
+                continue;
+            }
+
+            // The property types are only to blame if the bounds of an array are narrowed
+            let references = match (child_property, parent_property) {
+                (ValueOrArray::Value(child), ValueOrArray::Value(parent)) => Some((child, parent)),
+                (ValueOrArray::Array(child), ValueOrArray::Array(parent)) => {
+                    let mut bounds_incompatibilities = Vec::new();
+                    child.check_bounds_compatibility(parent, &path, &mut bounds_incompatibilities);
+                    bounds_incompatibilities
+                        .is_empty()
+                        .then(|| (child.items(), parent.items()))
+                }
+                _ => None,
+            };
+
+            return Err(match references {
+                Some((child, parent)) if child != parent => {
+                    MergeEntityTypeError::PropertyTypeVersionMismatch {
+                        property: base_url.clone(),
+                        child: child.url().clone(),
+                        parent: parent.url().clone(),
+                    }
+                }
+                _ => MergeEntityTypeError::ConflictingPropertyDefinition {
+                    child: self.id.clone(),
+                    parent: other.id.clone(),
+                    property: base_url.clone(),
+                },
+            });
+        }
+
+        for (link, parent_link) in other.links() {
+            let Some(child_link) = self.links().get(link) else {
+                continue;
+            };
+
+            let mut incompatibilities = Vec::new();
+            child_link.check_compatibility_at(parent_link, &path, provider, &mut incompatibilities);
+            if !incompatibilities.is_empty() {
+                return Err(MergeEntityTypeError::ConflictingLinkConstraints {
+                    child: self.id.clone(),
+                    parent: other.id.clone(),
+                    link: link.clone(),
+                });
+            }
+        }
 
         Ok(())
     }
@@ -216,8 +344,16 @@ impl ValidateUrl for EntityTypeReference {
 mod tests {
     use std::str::FromStr;
 
+    use serde_json::json;
+
     use super::*;
-    use crate::{test_data, utils::tests::check_serialization_from_str};
+    use crate::{
+        test_data,
+        url::ParseVersionedUrlError,
+        utils::tests::{check_serialization_from_str, ensure_failed_validation},
+        JsonPointer, ParseLinksError, ParsePropertyTypeObjectError,
+        ParsePropertyTypeReferenceArrayError,
+    };
 
     fn test_property_type_references(
         entity_type: &EntityType,
@@ -427,4 +563,123 @@ mod tests {
             ),
         ]);
     }
+
+    #[test]
+    fn nested_error_path() {
+        let input = json!({
+                "$schema": "https://blockprotocol.org/types/modules/graph/0.3/schema/entity-type",
+                "kind": "entityType",
+                "$id": "https://example.com/entity-type/person/v/1",
+                "type": "object",
+                "title": "Person",
+                "properties": {
+                    "https://example.com/property-type/name/": {
+                        "type": "array",
+                        "items": { "$ref": "https://example.com/property-type/name/" }
+                    }
+            }
+        });
+        let path = JsonPointer::root()
+            .join("properties")
+            .join("https://example.com/property-type/name/")
+            .join("items")
+            .join("$ref");
+        assert_eq!(
+            path.to_string(),
+            "/properties/https:~1~1example.com~1property-type~1name~1/items/$ref"
+        );
+
+        ensure_failed_validation::<raw::EntityType, EntityType>(
+            &input,
+            ParseEntityTypeError::InvalidPropertyTypeObject(
+                ParsePropertyTypeObjectError::InvalidArray(
+                    ParsePropertyTypeReferenceArrayError::InvalidReference {
+                        path,
+                        error: ParseVersionedUrlError::IncorrectFormatting,
+                    },
+                ),
+            ),
+        );
+    }
+
+    #[test]
+    fn parse_all() {
+        let name = "https://example.com/property-type/name/";
+        let age = "https://example.com/property-type/age/";
+        let email = "https://example.com/property-type/email/";
+        let friend_of = "https://example.com/entity-type/friend-of/v/1";
+        let errors = EntityType::parse_all(json!({
+            "$schema": "https://blockprotocol.org/types/modules/graph/0.3/schema/entity-type",
+            "kind": "entityType",
+            "$id": "https://example.com/entity-type/person/v/1",
+            "type": "object",
+            "title": "Person",
+            "properties": {
+                name: { "$ref": name },
+                age: {
+                    "type": "array",
+                    "items": { "$ref": age }
+                },
+                email: { "$ref": "https://example.com/property-type/mail/v/1" }
+            },
+            "required": ["https://example.com/property-type/phone/"],
+            "links": {},
+            "requiredLinks": [friend_of]
+        }))
+        .expect_err("entity type should be invalid");
+
+        let property = JsonPointer::root().join("properties");
+        assert_eq!(errors, [
+            ParseEntityTypeError::InvalidPropertyTypeObject(
+                ParsePropertyTypeObjectError::InvalidPropertyTypeReference {
+                    path: property.join(name).join("$ref"),
+                    error: ParseVersionedUrlError::IncorrectFormatting,
+                }
+            ),
+            ParseEntityTypeError::InvalidPropertyTypeObject(
+                ParsePropertyTypeObjectError::InvalidArray(
+                    ParsePropertyTypeReferenceArrayError::InvalidReference {
+                        path: property.join(age).join("items").join("$ref"),
+                        error: ParseVersionedUrlError::IncorrectFormatting,
+                    }
+                )
+            ),
+            ParseEntityTypeError::InvalidPropertyTypeObject(
+                ParsePropertyTypeObjectError::ValidationError {
+                    path: JsonPointer::root().join("required"),
+                    error: ValidationError::MissingRequiredProperty(
+                        BaseUrl::new("https://example.com/property-type/phone/".to_owned())
+                            .expect("invalid base URL")
+                    ),
+                }
+            ),
+            ParseEntityTypeError::InvalidPropertyTypeObject(
+                ParsePropertyTypeObjectError::ValidationError {
+                    path: property.join(email),
+                    error: ValidationError::BaseUrlMismatch {
+                        base_url: BaseUrl::new(email.to_owned()).expect("invalid base URL"),
+                        versioned_url: VersionedUrl::from_str(
+                            "https://example.com/property-type/mail/v/1"
+                        )
+                        .expect("invalid versioned URL"),
+                    },
+                }
+            ),
+            ParseEntityTypeError::InvalidLinks(ParseLinksError::ValidationError {
+                path: JsonPointer::root().join("requiredLinks"),
+                error: ValidationError::MissingRequiredLink(
+                    VersionedUrl::from_str(friend_of).expect("invalid versioned URL")
+                ),
+            }),
+        ]);
+    }
+
+    #[test]
+    fn parse_all_invalid_json() {
+        let errors = EntityType::parse_all(json!({ "title": "Person" }))
+            .expect_err("entity type should be invalid");
+        assert!(matches!(errors.as_slice(), [
+            ParseEntityTypeError::InvalidJson(_)
+        ]));
+    }
 }
@@ -1,16 +1,19 @@
-use std::{collections::HashMap, str::FromStr};
+use std::str::FromStr;
 
+use indexmap::IndexMap;
 use serde::{Deserialize, Serialize};
 #[cfg(target_arch = "wasm32")]
 use tsify::Tsify;
 
 use crate::{
+    ontology::shared::parse::{collect, first_error, TryFromRaw},
     raw,
     url::{BaseUrl, ParseVersionedUrlError, VersionedUrl},
-    ParseEntityTypeError,
+    AllOf, JsonPointer, Links, Object, ParseEntityTypeError,
 };
 
-const META_SCHEMA_ID: &str = "https://blockprotocol.org/types/modules/graph/0.3/schema/entity-type";
+pub(super) const META_SCHEMA_ID: &str =
+    "https://blockprotocol.org/types/modules/graph/0.3/schema/entity-type";
 
 /// Will serialize as a constant value `"entityType"`
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
@@ -45,67 +48,98 @@ pub struct EntityType {
         tsify(optional, type = "Record<BaseUrl, any>[]")
     )]
     #[serde(default, skip_serializing_if = "Vec::is_empty")]
-    pub examples: Vec<HashMap<String, serde_json::Value>>,
+    pub examples: Vec<IndexMap<String, serde_json::Value>>,
     #[serde(flatten)]
     pub property_object: raw::Object<raw::ValueOrArray<raw::PropertyTypeReference>>,
     #[serde(flatten)]
     pub links: raw::Links,
 }
 
-impl TryFrom<EntityType> for super::EntityType {
+impl TryFromRaw<EntityType> for super::EntityType {
     type Error = ParseEntityTypeError;
 
-    fn try_from(entity_type_repr: EntityType) -> Result<Self, Self::Error> {
+    fn try_from_raw(entity_type_repr: EntityType) -> Result<Self, Vec<Self::Error>> {
+        let path = JsonPointer::root();
+        let mut errors = Vec::new();
+
         let id = VersionedUrl::from_str(&entity_type_repr.id)
-            .map_err(ParseEntityTypeError::InvalidVersionedUrl)?;
+            .map_err(|error| {
+                errors.push(ParseEntityTypeError::InvalidVersionedUrl {
+                    path: path.join("$id"),
+                    error,
+                });
+            })
+            .ok();
 
         if entity_type_repr.schema != META_SCHEMA_ID {
-            return Err(ParseEntityTypeError::InvalidMetaSchema(
-                entity_type_repr.schema,
-            ));
+            errors.push(ParseEntityTypeError::InvalidMetaSchema {
+                path: path.join("$schema"),
+                meta_schema: entity_type_repr.schema,
+            });
         }
 
-        // TODO - validate examples against the entity type
-        let examples = entity_type_repr
-            .examples
-            .into_iter()
-            .map(|example_hash_map| {
-                example_hash_map
-                    .into_iter()
-                    .map(|(url, val)| {
-                        Ok((
-                            BaseUrl::new(url).map_err(ParseEntityTypeError::InvalidExamplesKey)?,
-                            val,
-                        ))
-                    })
-                    .collect()
-            })
-            .collect::<Result<_, _>>()?;
-
-        let property_object = entity_type_repr
-            .property_object
-            .try_into()
-            .map_err(ParseEntityTypeError::InvalidPropertyTypeObject)?;
-
-        let inherits_from = entity_type_repr
-            .all_of
-            .try_into()
-            .map_err(ParseEntityTypeError::InvalidAllOf)?;
-
-        let links = entity_type_repr
-            .links
-            .try_into()
-            .map_err(ParseEntityTypeError::InvalidLinks)?;
-
-        Ok(Self::new(
-            id,
-            entity_type_repr.title,
-            entity_type_repr.description,
-            property_object,
-            inherits_from,
-            links,
-            examples,
-        ))
+        // Examples are checked by `EntityType::validate_examples` instead, as they may use
+        // properties inherited from parents which have to be merged in first
+        let mut examples = Vec::with_capacity(entity_type_repr.examples.len());
+        for (index, example_hash_map) in entity_type_repr.examples.into_iter().enumerate() {
+            let mut example = IndexMap::with_capacity(example_hash_map.len());
+            for (url, val) in example_hash_map {
+                let example_path = path.join("examples").join(index).join(&url);
+                match BaseUrl::new(url) {
+                    Ok(base_url) => {
+                        example.insert(base_url, val);
+                    }
+                    Err(error) => errors.push(ParseEntityTypeError::InvalidExamplesKey {
+                        path: example_path,
+                        error,
+                    }),
+                }
+            }
+            examples.push(example);
+        }
+
+        let property_object = collect(
+            Object::try_from_raw(entity_type_repr.property_object),
+            &mut errors,
+            ParseEntityTypeError::InvalidPropertyTypeObject,
+        );
+
+        let inherits_from = collect(
+            AllOf::try_from_raw(entity_type_repr.all_of),
+            &mut errors,
+            ParseEntityTypeError::InvalidAllOf,
+        );
+
+        let links = collect(
+            Links::try_from_raw(entity_type_repr.links),
+            &mut errors,
+            ParseEntityTypeError::InvalidLinks,
+        );
+
+        match (id, property_object, inherits_from, links) {
+            (Some(id), Some(property_object), Some(inherits_from), Some(links))
+                if errors.is_empty() =>
+            {
+                Ok(Self::new(
+                    id,
+                    entity_type_repr.title,
+                    entity_type_repr.description,
+                    property_object,
+                    inherits_from,
+                    links,
+                    examples,
+                ))
+            }
+            _ => Err(errors),
+        }
+    }
+}
+
+impl TryFrom<EntityType> for super::EntityType {
+    type Error = ParseEntityTypeError;
+
+    fn try_from(entity_type_repr: EntityType) -> Result<Self, Self::Error> {
+        first_error(Self::try_from_raw(entity_type_repr))
     }
 }
 
@@ -164,7 +198,12 @@ impl From<super::EntityTypeReference> for EntityTypeReference {
 
 #[cfg(test)]
 mod tests {
-    use crate::{raw, url::BaseUrl, utils::tests::check_serialization_from_str, EntityType};
+    use crate::{
+        raw,
+        url::BaseUrl,
+        utils::tests::{check_serialization_from_str, TestOntology},
+        EntityType,
+    };
 
     #[test]
     fn merge_entity_type() {
@@ -178,25 +217,21 @@ mod tests {
         );
 
         church
-            .merge_parent(building)
+            .merge_parent(building, &TestOntology::new())
             .expect("merging entity types failed");
 
-        assert!(
-            church.properties().contains_key(
-                &BaseUrl::new(
-                    "https://blockprotocol.org/@alice/types/property-type/built-at/".to_owned()
-                )
-                .expect("invalid url")
+        assert!(church.properties().contains_key(
+            &BaseUrl::new(
+                "https://blockprotocol.org/@alice/types/property-type/built-at/".to_owned()
             )
-        );
-        assert!(
-            church.properties().contains_key(
-                &BaseUrl::new(
-                    "https://blockprotocol.org/@alice/types/property-type/number-bells/".to_owned()
-                )
-                .expect("invalid url")
+            .expect("invalid url")
+        ));
+        assert!(church.properties().contains_key(
+            &BaseUrl::new(
+                "https://blockprotocol.org/@alice/types/property-type/number-bells/".to_owned()
             )
-        );
+            .expect("invalid url")
+        ));
         assert!(church.inherits_from().all_of().is_empty());
     }
 }
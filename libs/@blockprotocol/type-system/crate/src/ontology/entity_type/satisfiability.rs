@@ -0,0 +1,630 @@
+use std::collections::HashSet;
+
+use indexmap::IndexMap;
+
+use crate::{
+    ontology::shared::satisfiability::{check_bounds_at, into_result},
+    url::BaseUrl,
+    Array, DataType, EntityType, JsonPointer, JsonType, OntologyTypeProvider,
+    PropertyTypeReference, PropertyValues, Unsatisfiable, UnsatisfiableReason, ValueOrArray,
+};
+
+/// The bounds of all arrays defined for the same property or link across an inheritance
+/// hierarchy.
+#[derive(Default)]
+struct Bounds {
+    min_items: Option<usize>,
+    max_items: Option<usize>,
+}
+
+impl Bounds {
+    /// Narrows these bounds to the ones allowed by `array` as well.
+    fn intersect<T>(&mut self, array: &Array<T>) {
+        self.min_items = self.min_items.max(array.min_items());
+        self.max_items = match (self.max_items, array.max_items()) {
+            (Some(max_items), Some(other_max_items)) => Some(max_items.min(other_max_items)),
+            (max_items, other_max_items) => max_items.or(other_max_items),
+        };
+    }
+}
+
+/// All definitions of the same property across an inheritance hierarchy.
+struct PropertyDefinitions<'t> {
+    /// Whether the first definition is an array, every other definition has to agree.
+    is_array: bool,
+    /// Every property type referenced by the definitions, each one only once.
+    property_types: Vec<&'t PropertyTypeReference>,
+    bounds: Bounds,
+    /// The first contradiction between the definitions, if any.
+    conflict: Option<UnsatisfiableReason>,
+}
+
+impl<'t> PropertyDefinitions<'t> {
+    fn new(property: &'t ValueOrArray<PropertyTypeReference>) -> Self {
+        let (is_array, property_type) = match property {
+            ValueOrArray::Value(reference) => (false, reference),
+            ValueOrArray::Array(array) => (true, array.items()),
+        };
+        Self {
+            is_array,
+            property_types: vec![property_type],
+            bounds: Bounds::default(),
+            conflict: None,
+        }
+    }
+
+    /// Narrows these definitions to the values allowed by `property` as well.
+    ///
+    /// Referencing a different version of the property type is only possible if a value can be
+    /// valid for both versions, see [`may_overlap`]. The referenced property types are looked up
+    /// through `provider`.
+    fn intersect(
+        &mut self,
+        base_url: &BaseUrl,
+        property: &'t ValueOrArray<PropertyTypeReference>,
+        provider: &impl OntologyTypeProvider,
+    ) {
+        if self.conflict.is_some() {
+            return;
+        }
+
+        let property_type = match property {
+            ValueOrArray::Value(reference) if !self.is_array => reference,
+            ValueOrArray::Array(array) if self.is_array => {
+                self.bounds.intersect(array);
+                array.items()
+            }
+            ValueOrArray::Value(_) | ValueOrArray::Array(_) => {
+                self.conflict = Some(UnsatisfiableReason::PropertyValueAndArray(base_url.clone()));
+                return;
+            }
+        };
+
+        if self.property_types.contains(&property_type) {
+            return;
+        }
+        if let Some(other_property_type) = self
+            .property_types
+            .iter()
+            .find(|other| !may_overlap(other, property_type, provider))
+        {
+            self.conflict = Some(UnsatisfiableReason::ConflictingPropertyTypes {
+                property: base_url.clone(),
+                property_type: other_property_type.url().clone(),
+                other_property_type: property_type.url().clone(),
+            });
+            return;
+        }
+        self.property_types.push(property_type);
+    }
+}
+
+/// Checks if a value may be valid for both referenced property types.
+///
+/// Only the JSON types of the possibilities are compared, so this may return `true` for property
+/// types which don't share a value, but never `false` for property types which do. If a property
+/// type or one of its data types could not be resolved through `provider`, a shared value is
+/// assumed to exist.
+fn may_overlap(
+    reference: &PropertyTypeReference,
+    other: &PropertyTypeReference,
+    provider: &impl OntologyTypeProvider,
+) -> bool {
+    let (Some(json_types), Some(other_json_types)) =
+        (json_types(reference, provider), json_types(other, provider))
+    else {
+        return true;
+    };
+
+    json_types.iter().any(|json_type| {
+        other_json_types.iter().any(|other_json_type| {
+            // Every integer is a number as well
+            json_type == other_json_type
+                || matches!(
+                    (json_type, other_json_type),
+                    (JsonType::Number, JsonType::Integer) | (JsonType::Integer, JsonType::Number)
+                )
+        })
+    })
+}
+
+/// Returns the JSON type of every possibility of the referenced property type, or `None` if the
+/// property type or one of its data types could not be resolved through `provider`.
+fn json_types(
+    reference: &PropertyTypeReference,
+    provider: &impl OntologyTypeProvider,
+) -> Option<Vec<JsonType>> {
+    provider
+        .property_type(reference)?
+        .one_of()
+        .iter()
+        .map(|values| match values {
+            PropertyValues::DataTypeReference(reference) => {
+                provider.data_type(reference).map(DataType::json_type)
+            }
+            PropertyValues::PropertyTypeObject(_) => Some(JsonType::Object),
+            PropertyValues::ArrayOfPropertyValues(_) => Some(JsonType::Array),
+        })
+        .collect()
+}
+
+impl EntityType {
+    /// Checks that at least one entity can satisfy this entity type together with all of its
+    /// ancestors, as described in [RFC 0428].
+    ///
+    /// The types in [`inherits_from`] are looked up through `provider`. A property or link which
+    /// is defined by several types in the hierarchy has to satisfy all of their definitions, so
+    /// the definitions are intersected:
+    ///
+    /// - a property has to be either a single value or an array in every definition
+    /// - the property types referenced for a property have to allow a common value, which is
+    ///   assumed as long as every pair of them shares a JSON type
+    /// - the intersected bounds have to allow at least one number of items
+    /// - a property or link which is required anywhere in the hierarchy has to allow at least one
+    ///   item
+    ///
+    /// Only required properties and links are reported. An optional property or link with
+    /// contradicting definitions can't be present, but it can be left out, so the entity type is
+    /// still satisfiable.
+    ///
+    /// The referenced property types and data types are looked up through `provider` if they
+    /// differ, link destinations are not checked. Inheritance cycles are tolerated.
+    ///
+    /// # Errors
+    ///
+    /// - every [`Unsatisfiable`] set of constraints found, addressed by its location inside the
+    ///   schema of this entity type
+    /// - [`UnresolvedEntityType`] for every ancestor which could not be found through `provider`,
+    ///   addressed by the parent it was reached through
+    ///
+    /// [RFC 0428]: https://github.com/blockprotocol/blockprotocol/blob/main/rfcs/text/0428-extending-and-duplicating-types.md#disallowing-unsatisfiable-schemas
+    /// [`inherits_from`]: Self::inherits_from
+    /// [`UnresolvedEntityType`]: UnsatisfiableReason::UnresolvedEntityType
+    pub fn check_satisfiable(
+        &self,
+        provider: &impl OntologyTypeProvider,
+    ) -> Result<(), Vec<Unsatisfiable>> {
+        let mut unsatisfiable = Vec::new();
+        let path = JsonPointer::root();
+
+        let mut properties = IndexMap::<_, PropertyDefinitions>::new();
+        let mut required = HashSet::new();
+        let mut links = IndexMap::<_, Bounds>::new();
+        let mut required_links = HashSet::new();
+        for entity_type in self.hierarchy(provider, &mut unsatisfiable) {
+            for (base_url, property) in entity_type.properties() {
+                properties
+                    .entry(base_url)
+                    .or_insert_with(|| PropertyDefinitions::new(property))
+                    .intersect(base_url, property, provider);
+            }
+            required.extend(entity_type.required());
+            for (link, array) in entity_type.links() {
+                links.entry(link).or_default().intersect(array.array());
+            }
+            required_links.extend(entity_type.required_links());
+        }
+
+        for (base_url, definitions) in properties {
+            if !required.contains(base_url) {
+                continue;
+            }
+
+            let property_path = path.join("properties").join(base_url);
+            if let Some(conflict) = definitions.conflict {
+                unsatisfiable.push(Unsatisfiable::new(property_path, conflict));
+                continue;
+            }
+
+            check_bounds_at(
+                definitions.bounds.min_items,
+                definitions.bounds.max_items,
+                Some(UnsatisfiableReason::RequiredPropertyWithoutItems(
+                    base_url.clone(),
+                )),
+                &property_path,
+                &mut unsatisfiable,
+            );
+        }
+        for (link, bounds) in links {
+            if !required_links.contains(link) {
+                continue;
+            }
+
+            check_bounds_at(
+                bounds.min_items,
+                bounds.max_items,
+                Some(UnsatisfiableReason::RequiredLinkWithoutItems(link.clone())),
+                &path.join("links").join(link),
+                &mut unsatisfiable,
+            );
+        }
+
+        into_result(unsatisfiable)
+    }
+
+    /// Returns this entity type followed by every ancestor which could be resolved through
+    /// `provider`, each one only once.
+    fn hierarchy<'t>(
+        &'t self,
+        provider: &'t impl OntologyTypeProvider,
+        unsatisfiable: &mut Vec<Unsatisfiable>,
+    ) -> Vec<&'t Self> {
+        let mut hierarchy = vec![self];
+        let mut visited = HashSet::from([&self.id]);
+        let mut stack = self
+            .inherits_from
+            .all_of()
+            .iter()
+            .enumerate()
+            .rev()
+            .collect::<Vec<_>>();
+        while let Some((index, ancestor)) = stack.pop() {
+            if !visited.insert(&ancestor.url) {
+                continue;
+            }
+
+            let Some(ancestor_type) = provider.entity_type(ancestor) else {
+                unsatisfiable.push(Unsatisfiable::new(
+                    JsonPointer::root().join("allOf").join(index),
+                    UnsatisfiableReason::UnresolvedEntityType(ancestor.url.clone()),
+                ));
+                continue;
+            };
+
+            hierarchy.push(ancestor_type);
+            stack.extend(
+                ancestor_type
+                    .inherits_from
+                    .all_of()
+                    .iter()
+                    .rev()
+                    .map(|parent| (index, parent)),
+            );
+        }
+
+        hierarchy
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::{json, Value};
+
+    use super::*;
+    use crate::utils::tests::{base_url, entity_type, entity_type_json, url, TestOntology};
+
+    const NAME: &str = "https://example.com/property-type/name/";
+    const FRIEND_OF: &str = "https://example.com/entity-type/friend-of/v/1";
+    const USER_ID: &str = "https://blockprotocol.org/@alice/types/property-type/user-id/";
+
+    fn names(min_items: usize, max_items: usize) -> Value {
+        json!({
+            NAME: {
+                "type": "array",
+                "items": { "$ref": format!("{NAME}v/1") },
+                "minItems": min_items,
+                "maxItems": max_items
+            }
+        })
+    }
+
+    #[test]
+    fn satisfiable() {
+        let mut ontology = TestOntology::new();
+        ontology.add_entity_type(&entity_type_json(
+            "parent/v/1",
+            &[],
+            &names(1, 5),
+            &[],
+            &json!({}),
+        ));
+        let child = entity_type(&entity_type_json(
+            "child/v/1",
+            &["parent/v/1"],
+            &names(0, 1),
+            &[],
+            &json!({}),
+        ));
+
+        child
+            .check_satisfiable(&ontology)
+            .expect("child should be satisfiable");
+    }
+
+    #[test]
+    fn contradicting_parents() {
+        let mut ontology = TestOntology::new();
+        ontology.add_entity_type(&entity_type_json(
+            "few/v/1",
+            &[],
+            &names(0, 1),
+            &[NAME],
+            &json!({}),
+        ));
+        ontology.add_entity_type(&entity_type_json(
+            "many/v/1",
+            &[],
+            &names(2, 5),
+            &[],
+            &json!({}),
+        ));
+        let child = entity_type(&entity_type_json(
+            "child/v/1",
+            &["few/v/1", "many/v/1"],
+            &json!({}),
+            &[],
+            &json!({}),
+        ));
+
+        assert_eq!(
+            child.check_satisfiable(&ontology),
+            Err(vec![Unsatisfiable::new(
+                JsonPointer::root().join("properties").join(NAME),
+                UnsatisfiableReason::MinItemsExceedsMaxItems {
+                    min_items: 2,
+                    max_items: 1,
+                },
+            )])
+        );
+    }
+
+    #[test]
+    fn optional_contradictions() {
+        let mut ontology = TestOntology::new();
+        ontology.add_entity_type(&entity_type_json(
+            "single/v/1",
+            &[],
+            &json!({ NAME: { "$ref": format!("{NAME}v/1") } }),
+            &[],
+            &json!({}),
+        ));
+        ontology.add_entity_type(&entity_type_json(
+            "few/v/1",
+            &[],
+            &names(0, 1),
+            &[],
+            &json!({}),
+        ));
+        ontology.add_entity_type(&entity_type_json(
+            "many/v/1",
+            &[],
+            &names(2, 5),
+            &[],
+            &json!({ FRIEND_OF: { "type": "array", "items": {}, "ordered": false, "minItems": 2 } }),
+        ));
+        let child = entity_type(&entity_type_json(
+            "child/v/1",
+            &["single/v/1", "few/v/1", "many/v/1"],
+            &json!({}),
+            &[],
+            &json!({ FRIEND_OF: { "type": "array", "items": {}, "ordered": false, "maxItems": 1 } }),
+        ));
+
+        // Neither the property nor the link is required, so an entity can leave them out
+        child
+            .check_satisfiable(&ontology)
+            .expect("optional contradictions should be satisfiable");
+    }
+
+    #[test]
+    fn required_without_items() {
+        let mut ontology = TestOntology::new();
+        let mut parent = serde_json::from_str::<Value>(&entity_type_json(
+            "parent/v/1",
+            &[],
+            &json!({}),
+            &[],
+            &json!({ FRIEND_OF: { "type": "array", "items": {}, "ordered": false } }),
+        ))
+        .expect("invalid JSON");
+        parent["required"] = json!([NAME]);
+        parent["properties"] = names(0, 3);
+        parent["requiredLinks"] = json!([FRIEND_OF]);
+        ontology.add_entity_type(&parent.to_string());
+        let child = entity_type(&entity_type_json(
+            "child/v/1",
+            &["parent/v/1"],
+            &names(0, 0),
+            &[],
+            &json!({
+                FRIEND_OF: { "type": "array", "items": {}, "ordered": false, "maxItems": 0 }
+            }),
+        ));
+
+        let friend_of = url(FRIEND_OF);
+        assert_eq!(
+            child.check_satisfiable(&ontology),
+            Err(vec![
+                Unsatisfiable::new(
+                    JsonPointer::root().join("properties").join(NAME),
+                    UnsatisfiableReason::RequiredPropertyWithoutItems(base_url(NAME)),
+                ),
+                Unsatisfiable::new(
+                    JsonPointer::root().join("links").join(&friend_of),
+                    UnsatisfiableReason::RequiredLinkWithoutItems(friend_of),
+                ),
+            ])
+        );
+    }
+
+    #[test]
+    fn value_and_array() {
+        let mut ontology = TestOntology::new();
+        ontology.add_entity_type(&entity_type_json(
+            "single/v/1",
+            &[],
+            &json!({ NAME: { "$ref": format!("{NAME}v/1") } }),
+            &[NAME],
+            &json!({}),
+        ));
+        ontology.add_entity_type(&entity_type_json(
+            "many/v/1",
+            &[],
+            &names(0, 5),
+            &[],
+            &json!({}),
+        ));
+        let child = entity_type(&entity_type_json(
+            "child/v/1",
+            &["single/v/1", "many/v/1"],
+            &json!({}),
+            &[],
+            &json!({}),
+        ));
+
+        assert_eq!(
+            child.check_satisfiable(&ontology),
+            Err(vec![Unsatisfiable::new(
+                JsonPointer::root().join("properties").join(NAME),
+                UnsatisfiableReason::PropertyValueAndArray(base_url(NAME)),
+            )])
+        );
+    }
+
+    #[test]
+    fn property_type_versions() {
+        let mut ontology = TestOntology::new();
+        ontology.add_property_type(
+            &json!({
+                "$schema": "https://blockprotocol.org/types/modules/graph/0.3/schema/property-type",
+                "kind": "propertyType",
+                "$id": format!("{USER_ID}v/3"),
+                "title": "User ID",
+                "oneOf": [{ "$ref": "https://blockprotocol.org/@blockprotocol/types/data-type/number/v/1" }]
+            })
+            .to_string(),
+        );
+        for version in 1..=3 {
+            ontology.add_entity_type(&entity_type_json(
+                &format!("user-v{version}/v/1"),
+                &[],
+                &json!({ USER_ID: { "$ref": format!("{USER_ID}v/{version}") } }),
+                &[USER_ID],
+                &json!({}),
+            ));
+        }
+
+        // Every text is valid for both versions
+        entity_type(&entity_type_json(
+            "child/v/1",
+            &["user-v1/v/1", "user-v2/v/1"],
+            &json!({}),
+            &[],
+            &json!({}),
+        ))
+        .check_satisfiable(&ontology)
+        .expect("compatible property types should be satisfiable");
+
+        let child = entity_type(&entity_type_json(
+            "child/v/1",
+            &["user-v1/v/1", "user-v2/v/1", "user-v3/v/1"],
+            &json!({}),
+            &[],
+            &json!({}),
+        ));
+        assert_eq!(
+            child.check_satisfiable(&ontology),
+            Err(vec![Unsatisfiable::new(
+                JsonPointer::root().join("properties").join(USER_ID),
+                UnsatisfiableReason::ConflictingPropertyTypes {
+                    property: base_url(USER_ID),
+                    property_type: url(&format!("{USER_ID}v/1")),
+                    other_property_type: url(&format!("{USER_ID}v/3")),
+                },
+            )])
+        );
+    }
+
+    #[test]
+    fn overlapping_property_types() {
+        let mut ontology = TestOntology::new();
+        for (version, data_types) in [
+            (1, ["text", "number"].as_slice()),
+            (2, ["number", "boolean"].as_slice()),
+            (3, ["boolean"].as_slice()),
+        ] {
+            ontology.add_property_type(
+                &json!({
+                    "$schema": "https://blockprotocol.org/types/modules/graph/0.3/schema/property-type",
+                    "kind": "propertyType",
+                    "$id": format!("{NAME}v/{version}"),
+                    "title": "Name",
+                    "oneOf": data_types
+                        .iter()
+                        .map(|data_type| json!({
+                            "$ref": format!("https://blockprotocol.org/@blockprotocol/types/data-type/{data_type}/v/1")
+                        }))
+                        .collect::<Vec<_>>()
+                })
+                .to_string(),
+            );
+            ontology.add_entity_type(&entity_type_json(
+                &format!("name-v{version}/v/1"),
+                &[],
+                &json!({ NAME: { "$ref": format!("{NAME}v/{version}") } }),
+                &[NAME],
+                &json!({}),
+            ));
+        }
+
+        // Neither version is compatible with the other, but every number is valid for both
+        entity_type(&entity_type_json(
+            "child/v/1",
+            &["name-v1/v/1", "name-v2/v/1"],
+            &json!({}),
+            &[],
+            &json!({}),
+        ))
+        .check_satisfiable(&ontology)
+        .expect("overlapping property types should be satisfiable");
+
+        let child = entity_type(&entity_type_json(
+            "child/v/1",
+            &["name-v1/v/1", "name-v3/v/1"],
+            &json!({}),
+            &[],
+            &json!({}),
+        ));
+        assert_eq!(
+            child.check_satisfiable(&ontology),
+            Err(vec![Unsatisfiable::new(
+                JsonPointer::root().join("properties").join(NAME),
+                UnsatisfiableReason::ConflictingPropertyTypes {
+                    property: base_url(NAME),
+                    property_type: url(&format!("{NAME}v/1")),
+                    other_property_type: url(&format!("{NAME}v/3")),
+                },
+            )])
+        );
+    }
+
+    #[test]
+    fn unresolved_ancestor() {
+        let mut ontology = TestOntology::new();
+        ontology.add_entity_type(&entity_type_json(
+            "parent/v/1",
+            &["missing/v/1"],
+            &json!({}),
+            &[],
+            &json!({}),
+        ));
+        let child = entity_type(&entity_type_json(
+            "child/v/1",
+            &["parent/v/1"],
+            &json!({}),
+            &[],
+            &json!({}),
+        ));
+
+        assert_eq!(
+            child.check_satisfiable(&ontology),
+            Err(vec![Unsatisfiable::new(
+                JsonPointer::root().join("allOf").join(0),
+                UnsatisfiableReason::UnresolvedEntityType(url(
+                    "https://example.com/entity-type/missing/v/1"
+                )),
+            )])
+        );
+    }
+}
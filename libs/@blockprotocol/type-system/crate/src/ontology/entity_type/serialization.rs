@@ -0,0 +1,250 @@
+use serde::{
+    de::{self, MapAccess},
+    ser::SerializeStruct,
+    Deserialize, Deserializer, Serialize, Serializer,
+};
+
+use super::{links::serialization::LinksFields, raw::META_SCHEMA_ID};
+use crate::{
+    ontology::shared::{
+        object::serialization::ObjectFields,
+        serialization::{
+            deserialize_map, deserialize_reference, next_id, next_meta_schema, next_tag,
+            next_value, required, required_tag, serialize_optional_field, serialize_reference,
+            FromMap, Key,
+        },
+    },
+    AllOf, EntityType, EntityTypeReference, JsonPointer, ParseEntityTypeError,
+};
+
+const FIELDS: &[&str] = &[
+    "$schema",
+    "kind",
+    "$id",
+    "title",
+    "description",
+    "allOf",
+    "examples",
+    "type",
+    "properties",
+    "required",
+    "links",
+    "requiredLinks",
+];
+
+impl Serialize for EntityType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("EntityType", FIELDS.len())?;
+        state.serialize_field("$schema", META_SCHEMA_ID)?;
+        state.serialize_field("kind", "entityType")?;
+        state.serialize_field("$id", &self.id)?;
+        state.serialize_field("title", &self.title)?;
+        serialize_optional_field(&mut state, "description", self.description.as_ref())?;
+        serialize_optional_field(
+            &mut state,
+            "allOf",
+            (!self.inherits_from.all_of().is_empty()).then(|| self.inherits_from.all_of()),
+        )?;
+        serialize_optional_field(
+            &mut state,
+            "examples",
+            (!self.examples.is_empty()).then_some(&self.examples),
+        )?;
+        self.property_object.serialize_fields(&mut state)?;
+        self.links.serialize_fields(&mut state)?;
+        state.end()
+    }
+}
+
+impl<'de> FromMap<'de> for EntityType {
+    const EXPECTING: &'static str = "an entity type";
+
+    /// Deserializes an entity type, checking its properties and links once they are complete.
+    ///
+    /// Examples are not checked, see [`EntityType::validate_examples`].
+    fn from_map<A>(mut map: A) -> Result<Self, A::Error>
+    where
+        A: MapAccess<'de>,
+    {
+        let path = JsonPointer::root();
+
+        let mut schema = false;
+        let mut kind = false;
+        let mut id = None;
+        let mut title = None;
+        let mut description = None;
+        let mut all_of = None::<Vec<EntityTypeReference>>;
+        let mut examples = None;
+        let mut r#type = false;
+        let mut property_object = ObjectFields::default();
+        let mut links = LinksFields::default();
+        while let Some(key) = map.next_key::<Key>()? {
+            match &*key {
+                "$schema" => {
+                    next_meta_schema(&mut map, &mut schema, META_SCHEMA_ID, |meta_schema| {
+                        ParseEntityTypeError::InvalidMetaSchema {
+                            path: path.join("$schema"),
+                            meta_schema,
+                        }
+                    })?;
+                }
+                "kind" => next_tag(&mut map, &mut kind, "kind", "entityType")?,
+                "$id" => {
+                    next_id(&mut map, &mut id, |error| {
+                        ParseEntityTypeError::InvalidVersionedUrl {
+                            path: path.join("$id"),
+                            error,
+                        }
+                    })?;
+                }
+                "title" => next_value(&mut map, &mut title, "title")?,
+                // An explicit `null` is deserialized as absent
+                "description" => next_value(&mut map, &mut description, "description")?,
+                "allOf" => next_value(&mut map, &mut all_of, "allOf")?,
+                "examples" => next_value(&mut map, &mut examples, "examples")?,
+                "type" => next_tag(&mut map, &mut r#type, "type", "object")?,
+                key => {
+                    if !property_object.next_value(key, &mut map)?
+                        && !links.next_value(key, &mut map)?
+                    {
+                        return Err(de::Error::unknown_field(key, FIELDS));
+                    }
+                }
+            }
+        }
+
+        required_tag(schema, "$schema")?;
+        required_tag(kind, "kind")?;
+        required_tag(r#type, "type")?;
+        Ok(Self::new(
+            required(id, "$id")?,
+            required(title, "title")?,
+            description.flatten(),
+            property_object.finish()?,
+            AllOf::new(all_of.unwrap_or_default()),
+            links.finish()?,
+            examples.unwrap_or_default(),
+        ))
+    }
+}
+
+impl<'de> Deserialize<'de> for EntityType {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserialize_map(deserializer)
+    }
+}
+
+impl Serialize for EntityTypeReference {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serialize_reference("EntityTypeReference", self.url(), serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for EntityTypeReference {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserialize_reference(deserializer).map(Self::new)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    fn entity_type(keywords: &serde_json::Value) -> Result<EntityType, String> {
+        let mut entity_type = json!({
+            "$schema": META_SCHEMA_ID,
+            "kind": "entityType",
+            "$id": "https://example.com/entity-type/v/1",
+            "title": "Example",
+            "type": "object",
+            "properties": {},
+        });
+        entity_type
+            .as_object_mut()
+            .expect("entity type should be an object")
+            .extend(
+                keywords
+                    .as_object()
+                    .expect("keywords should be an object")
+                    .clone(),
+            );
+        serde_json::from_value(entity_type).map_err(|error| error.to_string())
+    }
+
+    #[test]
+    fn missing_required_property() {
+        let error = entity_type(&json!({ "required": ["https://example.com/property-type/"] }))
+            .expect_err("deserialized a missing required property");
+        assert!(
+            error.starts_with("failed validation at `/required`"),
+            "{error}"
+        );
+    }
+
+    #[test]
+    fn missing_required_link() {
+        let error = entity_type(&json!({ "requiredLinks": ["https://example.com/link/v/1"] }))
+            .expect_err("deserialized a missing required link");
+        assert!(
+            error.starts_with("failed validation at `/requiredLinks`"),
+            "{error}"
+        );
+    }
+
+    #[test]
+    fn links() {
+        let entity_type = entity_type(&json!({
+            "links": {
+                "https://example.com/link-a/v/1": {
+                    "type": "array",
+                    "items": {},
+                    "ordered": false,
+                },
+                "https://example.com/link-b/v/1": {
+                    "type": "array",
+                    "items": { "oneOf": [{ "$ref": "https://example.com/entity-type/v/1" }] },
+                    "maxItems": 1,
+                    "ordered": true,
+                },
+            },
+        }))
+        .expect("failed to deserialize links");
+        assert_eq!(entity_type.links().len(), 2);
+
+        let error = self::entity_type(&json!({
+            "links": {
+                "https://example.com/link/v/1": {
+                    "type": "array",
+                    "items": { "oneOf": [] },
+                    "ordered": false,
+                },
+            },
+        }))
+        .expect_err("deserialized an empty `oneOf`");
+        assert!(
+            error.starts_with("failed validation at `/oneOf`"),
+            "{error}"
+        );
+    }
+
+    #[test]
+    fn unknown_keyword() {
+        let error =
+            entity_type(&json!({ "additional": 10 })).expect_err("deserialized an unknown keyword");
+        assert!(error.starts_with("unknown field `additional`"), "{error}");
+    }
+}
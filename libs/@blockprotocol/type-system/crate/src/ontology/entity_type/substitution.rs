@@ -0,0 +1,365 @@
+use std::collections::HashSet;
+
+use crate::{
+    EntityType, Incompatibility, IncompatibilityReason, JsonPointer, OntologyTypeProvider,
+    SubstitutionError,
+};
+
+impl EntityType {
+    /// Checks that this entity type can be used anywhere one of its parents is expected, as
+    /// required by [RFC 0428].
+    ///
+    /// Every property and link which this entity type defines itself and which is also defined by
+    /// one of its ancestors has to narrow the ancestor's definition, see
+    /// [`Object::is_compatible_with`] and [`is_compatible_with`]. In particular this means that
+    /// `maxItems` must not be loosened, property types must not be replaced by incompatible ones
+    /// and a redefined property or link which is required by an ancestor has to stay required.
+    ///
+    /// The types in [`inherits_from`] and their ancestors are looked up through `provider`.
+    /// Ancestors which are reachable through multiple parents are checked for each of them, and
+    /// inheritance cycles are tolerated.
+    ///
+    /// # Errors
+    ///
+    /// - a [`SubstitutionError`] for every parent in [`inherits_from`] which cannot be substituted
+    ///   by this entity type, listing the incompatibilities found through that parent
+    ///
+    /// [RFC 0428]: https://github.com/blockprotocol/blockprotocol/blob/main/rfcs/text/0428-extending-and-duplicating-types.md
+    /// [`Object::is_compatible_with`]: crate::Object::is_compatible_with
+    /// [`is_compatible_with`]: Self::is_compatible_with
+    /// [`inherits_from`]: Self::inherits_from
+    pub fn check_substitutability(
+        &self,
+        provider: &impl OntologyTypeProvider,
+    ) -> Result<(), Vec<SubstitutionError>> {
+        let mut errors = Vec::new();
+
+        for (index, parent) in self.inherits_from.all_of().iter().enumerate() {
+            let parent_path = JsonPointer::root().join("allOf").join(index);
+            let mut incompatibilities = Vec::new();
+            let mut visited = HashSet::from([&self.id]);
+            let mut stack = vec![parent];
+            while let Some(ancestor) = stack.pop() {
+                if !visited.insert(&ancestor.url) {
+                    continue;
+                }
+
+                let Some(ancestor_type) = provider.entity_type(ancestor) else {
+                    incompatibilities.push(Incompatibility::new(
+                        parent_path.clone(),
+                        IncompatibilityReason::UnresolvedEntityType(ancestor.url.clone()),
+                    ));
+                    continue;
+                };
+
+                let mut found = Vec::new();
+                self.check_narrowing_at(ancestor_type, provider, &mut found);
+                for incompatibility in found {
+                    // Ancestors sharing a definition would report the same incompatibility
+                    if !incompatibilities.contains(&incompatibility) {
+                        incompatibilities.push(incompatibility);
+                    }
+                }
+
+                stack.extend(ancestor_type.inherits_from.all_of().iter().rev());
+            }
+
+            if !incompatibilities.is_empty() {
+                errors.push(SubstitutionError {
+                    child: self.id.clone(),
+                    parent: parent.url.clone(),
+                    incompatibilities,
+                });
+            }
+        }
+
+        if errors.is_empty() {
+            Ok(())
+        } else {
+            Err(errors)
+        }
+    }
+
+    /// Checks that the properties and links defined by this entity type narrow the ones defined
+    /// by `ancestor`. Properties and links only defined by one of them are not taken into account.
+    fn check_narrowing_at(
+        &self,
+        ancestor: &Self,
+        provider: &impl OntologyTypeProvider,
+        incompatibilities: &mut Vec<Incompatibility>,
+    ) {
+        let path = JsonPointer::root();
+
+        for (base_url, ancestor_property) in ancestor.properties() {
+            let Some(property) = self.properties().get(base_url) else {
+                continue;
+            };
+
+            property.check_compatibility_at(
+                ancestor_property,
+                &path.join("properties").join(base_url),
+                provider,
+                incompatibilities,
+            );
+
+            if ancestor.required().contains(base_url) && !self.required().contains(base_url) {
+                incompatibilities.push(Incompatibility::new(
+                    path.join("required"),
+                    IncompatibilityReason::RequiredProperty(base_url.clone()),
+                ));
+            }
+        }
+
+        for (link, ancestor_array) in ancestor.links() {
+            let Some(array) = self.links().get(link) else {
+                continue;
+            };
+
+            array.check_compatibility_at(
+                ancestor_array,
+                &path.join("links").join(link),
+                provider,
+                incompatibilities,
+            );
+
+            if ancestor.required_links().contains(link) && !self.required_links().contains(link) {
+                incompatibilities.push(Incompatibility::new(
+                    path.join("requiredLinks"),
+                    IncompatibilityReason::RequiredLink(link.clone()),
+                ));
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::{json, Value};
+
+    use super::*;
+    use crate::utils::tests::{base_url, entity_type, entity_type_json, url, TestOntology};
+
+    const NAME: &str = "https://example.com/property-type/name/";
+    const AGE: &str = "https://example.com/property-type/age/";
+    const FRIEND_OF: &str = "https://example.com/entity-type/friend-of/v/1";
+
+    fn names(max_items: usize) -> Value {
+        json!({
+            NAME: {
+                "type": "array",
+                "items": { "$ref": format!("{NAME}v/1") },
+                "maxItems": max_items
+            }
+        })
+    }
+
+    fn friends(max_items: usize) -> Value {
+        json!({
+            FRIEND_OF: { "type": "array", "items": {}, "ordered": false, "maxItems": max_items }
+        })
+    }
+
+    #[test]
+    fn narrowed() {
+        let mut ontology = TestOntology::new();
+        ontology.add_entity_type(&entity_type_json(
+            "parent/v/1",
+            &[],
+            &names(5),
+            &[NAME],
+            &friends(5),
+        ));
+        let child = entity_type(&entity_type_json(
+            "child/v/1",
+            &["parent/v/1"],
+            &names(3),
+            &[NAME],
+            &friends(3),
+        ));
+
+        child
+            .check_substitutability(&ontology)
+            .expect("child should be substitutable for its parent");
+
+        let flattened = child
+            .flatten(&ontology)
+            .expect("narrowed child should be flattened");
+        assert_eq!(
+            flattened.properties(),
+            entity_type(&entity_type_json(
+                "child/v/1",
+                &[],
+                &names(3),
+                &[],
+                &json!({})
+            ))
+            .properties()
+        );
+        assert_eq!(
+            flattened.links(),
+            entity_type(&entity_type_json(
+                "child/v/1",
+                &[],
+                &json!({}),
+                &[],
+                &friends(3)
+            ))
+            .links()
+        );
+    }
+
+    #[test]
+    fn new_definitions() {
+        let mut ontology = TestOntology::new();
+        ontology.add_entity_type(&entity_type_json(
+            "parent/v/1",
+            &[],
+            &names(5),
+            &[NAME],
+            &json!({}),
+        ));
+        let child = entity_type(&entity_type_json(
+            "child/v/1",
+            &["parent/v/1"],
+            &json!({ AGE: { "$ref": format!("{AGE}v/1") } }),
+            &[],
+            &friends(3),
+        ));
+
+        child
+            .check_substitutability(&ontology)
+            .expect("new properties and links should not break substitutability");
+    }
+
+    #[test]
+    fn loosened() {
+        let mut ontology = TestOntology::new();
+        ontology.add_entity_type(&entity_type_json(
+            "parent/v/1",
+            &[],
+            &names(3),
+            &[NAME],
+            &friends(3),
+        ));
+        let child = entity_type(&entity_type_json(
+            "child/v/1",
+            &["parent/v/1"],
+            &names(5),
+            &[],
+            &friends(5),
+        ));
+
+        let errors = child
+            .check_substitutability(&ontology)
+            .expect_err("child should loosen its parent");
+        assert_eq!(errors, [SubstitutionError {
+            child: url("https://example.com/entity-type/child/v/1"),
+            parent: url("https://example.com/entity-type/parent/v/1"),
+            incompatibilities: vec![
+                Incompatibility::new(
+                    JsonPointer::root()
+                        .join("properties")
+                        .join(NAME)
+                        .join("maxItems"),
+                    IncompatibilityReason::MaxItemsDecreased {
+                        max_items: Some(5),
+                        other_max_items: 3,
+                    },
+                ),
+                Incompatibility::new(
+                    JsonPointer::root().join("required"),
+                    IncompatibilityReason::RequiredProperty(base_url(NAME)),
+                ),
+                Incompatibility::new(
+                    JsonPointer::root()
+                        .join("links")
+                        .join(FRIEND_OF)
+                        .join("maxItems"),
+                    IncompatibilityReason::MaxItemsDecreased {
+                        max_items: Some(5),
+                        other_max_items: 3,
+                    },
+                ),
+            ],
+        }]);
+    }
+
+    #[test]
+    fn conflicting_property_reference() {
+        let mut ontology = TestOntology::new();
+        ontology.add_entity_type(&entity_type_json(
+            "parent/v/1",
+            &[],
+            &json!({ NAME: { "$ref": format!("{NAME}v/1") } }),
+            &[],
+            &json!({}),
+        ));
+        let child = entity_type(&entity_type_json(
+            "child/v/1",
+            &["parent/v/1"],
+            &names(3),
+            &[],
+            &json!({}),
+        ));
+
+        let errors = child
+            .check_substitutability(&ontology)
+            .expect_err("child should conflict with its parent");
+        assert_eq!(errors.len(), 1);
+        assert_eq!(errors[0].incompatibilities, [Incompatibility::new(
+            JsonPointer::root().join("properties").join(NAME),
+            IncompatibilityReason::UnexpectedArray,
+        )]);
+    }
+
+    #[test]
+    fn listed_per_parent() {
+        let mut ontology = TestOntology::new();
+        ontology.add_entity_type(&entity_type_json(
+            "grandparent/v/1",
+            &[],
+            &names(3),
+            &[],
+            &json!({}),
+        ));
+        ontology.add_entity_type(&entity_type_json(
+            "parent/v/1",
+            &["grandparent/v/1"],
+            &json!({}),
+            &[],
+            &json!({}),
+        ));
+        ontology.add_entity_type(&entity_type_json(
+            "other-parent/v/1",
+            &[],
+            &json!({}),
+            &[],
+            &friends(5),
+        ));
+        let child = entity_type(&entity_type_json(
+            "child/v/1",
+            &["parent/v/1", "other-parent/v/1", "missing/v/1"],
+            &names(5),
+            &[],
+            &friends(3),
+        ));
+
+        let errors = child
+            .check_substitutability(&ontology)
+            .expect_err("child should loosen its grandparent");
+        let parents = errors
+            .iter()
+            .map(|error| error.parent.clone())
+            .collect::<Vec<_>>();
+        assert_eq!(parents, [
+            url("https://example.com/entity-type/parent/v/1"),
+            url("https://example.com/entity-type/missing/v/1"),
+        ]);
+        assert_eq!(errors[1].incompatibilities, [Incompatibility::new(
+            JsonPointer::root().join("allOf").join(2),
+            IncompatibilityReason::UnresolvedEntityType(url(
+                "https://example.com/entity-type/missing/v/1"
+            )),
+        )]);
+    }
+}
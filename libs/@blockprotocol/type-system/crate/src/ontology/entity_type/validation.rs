@@ -0,0 +1,706 @@
+use std::collections::{BTreeMap, BTreeSet};
+
+use crate::{
+    url::VersionedUrl, EntityType, EntityTypeReference, InstanceValidationError,
+    InstanceValidationReason, JsonPointer, LinkValidationError, LinkValidationReason,
+    OntologyTypeProvider,
+};
+
+impl EntityType {
+    /// Validates the `properties` of an entity against this entity type.
+    ///
+    /// The referenced property types and data types are looked up through `provider`. Properties
+    /// of the types in [`inherits_from`] are not taken into account, parents have to be merged
+    /// into the entity type beforehand, e.g. by using [`flatten`].
+    ///
+    /// Each returned error carries the [`JsonPointer`] of the offending value inside
+    /// `properties`.
+    ///
+    /// # Errors
+    ///
+    /// - every [`InstanceValidationError`] found if `properties` is not a valid instance of this
+    ///   entity type
+    ///
+    /// [`inherits_from`]: Self::inherits_from
+    /// [`flatten`]: Self::flatten
+    pub fn validate_properties(
+        &self,
+        properties: &serde_json::Value,
+        provider: &impl OntologyTypeProvider,
+    ) -> Result<(), Vec<InstanceValidationError>> {
+        let mut errors = Vec::new();
+        self.property_object.validate_value_at(
+            properties,
+            &JsonPointer::root(),
+            provider,
+            &mut errors,
+        );
+
+        if errors.is_empty() {
+            Ok(())
+        } else {
+            Err(errors)
+        }
+    }
+
+    /// Checks that every entry of [`examples`] only uses properties declared by this entity type
+    /// and contains every required property.
+    ///
+    /// This does not require the referenced types to be available, the values themselves are only
+    /// checked by [`validate_example_values`]. As with [`validate_properties`], properties of the
+    /// types in [`inherits_from`] are not taken into account.
+    ///
+    /// Each returned error carries the [`JsonPointer`] of the offending example, e.g.
+    /// `/examples/0`.
+    ///
+    /// # Errors
+    ///
+    /// - every [`UnexpectedProperty`] and [`MissingRequiredProperty`] found in any example
+    ///
+    /// [`examples`]: Self::examples
+    /// [`validate_example_values`]: Self::validate_example_values
+    /// [`validate_properties`]: Self::validate_properties
+    /// [`inherits_from`]: Self::inherits_from
+    /// [`UnexpectedProperty`]: crate::InstanceValidationReason::UnexpectedProperty
+    /// [`MissingRequiredProperty`]: crate::InstanceValidationReason::MissingRequiredProperty
+    pub fn validate_examples(&self) -> Result<(), Vec<InstanceValidationError>> {
+        let mut errors = Vec::new();
+        let examples_path = JsonPointer::root().join("examples");
+
+        for (index, example) in self.examples.iter().enumerate() {
+            let path = examples_path.join(index);
+
+            errors.extend(
+                example
+                    .keys()
+                    .filter(|key| !self.properties().contains_key(*key))
+                    .map(|key| {
+                        InstanceValidationError::new(
+                            path.join(key),
+                            InstanceValidationReason::UnexpectedProperty(key.clone()),
+                        )
+                    }),
+            );
+            errors.extend(
+                self.required()
+                    .iter()
+                    .filter(|required| !example.contains_key(*required))
+                    .map(|required| {
+                        InstanceValidationError::new(
+                            path.clone(),
+                            InstanceValidationReason::MissingRequiredProperty(required.clone()),
+                        )
+                    }),
+            );
+        }
+
+        if errors.is_empty() {
+            Ok(())
+        } else {
+            Err(errors)
+        }
+    }
+
+    /// Validates every entry of [`examples`] against this entity type, including the values of
+    /// the properties.
+    ///
+    /// This is the same check as [`validate_properties`] applied to each example, the referenced
+    /// property types and data types are looked up through `provider`.
+    ///
+    /// Each returned error carries the [`JsonPointer`] of the offending value, e.g.
+    /// `/examples/0/https:~1~1example.com~1property-type~1name~1`.
+    ///
+    /// # Errors
+    ///
+    /// - every [`InstanceValidationError`] found in any example
+    ///
+    /// [`examples`]: Self::examples
+    /// [`validate_properties`]: Self::validate_properties
+    pub fn validate_example_values(
+        &self,
+        provider: &impl OntologyTypeProvider,
+    ) -> Result<(), Vec<InstanceValidationError>> {
+        let mut errors = Vec::new();
+        let examples_path = JsonPointer::root().join("examples");
+
+        for (index, example) in self.examples.iter().enumerate() {
+            let example = serde_json::Value::Object(
+                example
+                    .iter()
+                    .map(|(key, value)| (key.to_string(), value.clone()))
+                    .collect(),
+            );
+            self.property_object.validate_value_at(
+                &example,
+                &examples_path.join(index),
+                provider,
+                &mut errors,
+            );
+        }
+
+        if errors.is_empty() {
+            Ok(())
+        } else {
+            Err(errors)
+        }
+    }
+
+    /// Validates the outgoing links of an entity against the [`links`] of this entity type.
+    ///
+    /// `links` contains one `(link entity type, destination entity type)` pair for every outgoing
+    /// link of the entity. For each link entity type it's checked that
+    ///
+    /// - it is defined in [`links`],
+    /// - it is present if it is listed in [`required_links`],
+    /// - the number of links lies within `minItems` and `maxItems` if the link is present, and
+    /// - every destination is allowed by the `oneOf` constraint of the link, if any.
+    ///
+    /// A destination is allowed if it is listed in `oneOf` or if it is a subtype of a listed
+    /// entity type, see [`is_subtype_of`]. Destinations which are not listed directly are looked
+    /// up through `provider`. As with [`validate_properties`], links of the types in
+    /// [`inherits_from`] are not taken into account.
+    ///
+    /// # Errors
+    ///
+    /// - every [`LinkValidationError`] found, ordered by the link entity type
+    ///
+    /// [`links`]: Self::links
+    /// [`required_links`]: Self::required_links
+    /// [`is_subtype_of`]: Self::is_subtype_of
+    /// [`validate_properties`]: Self::validate_properties
+    /// [`inherits_from`]: Self::inherits_from
+    pub fn validate_links<'l>(
+        &self,
+        links: impl IntoIterator<Item = (&'l VersionedUrl, &'l VersionedUrl)>,
+        provider: &impl OntologyTypeProvider,
+    ) -> Result<(), Vec<LinkValidationError>> {
+        let mut destinations_by_link = BTreeMap::<_, Vec<_>>::new();
+        for (link, destination) in links {
+            destinations_by_link
+                .entry(link)
+                .or_default()
+                .push(destination);
+        }
+
+        let link_types = destinations_by_link
+            .keys()
+            .copied()
+            .chain(self.links().keys())
+            .chain(self.required_links())
+            .collect::<BTreeSet<_>>();
+
+        let mut errors = Vec::new();
+        for link in link_types {
+            let destinations = destinations_by_link
+                .get(link)
+                .map_or(&[][..], Vec::as_slice);
+
+            let Some(constraints) = self.links().get(link) else {
+                errors.push(LinkValidationError::new(
+                    link.clone(),
+                    LinkValidationReason::UnexpectedLink,
+                ));
+                continue;
+            };
+
+            if destinations.is_empty() {
+                if self.required_links().contains(link) {
+                    errors.push(LinkValidationError::new(
+                        link.clone(),
+                        LinkValidationReason::MissingRequiredLink,
+                    ));
+                }
+                continue;
+            }
+
+            let array = constraints.array();
+            if let Some(min_items) = array.min_items() {
+                if destinations.len() < min_items {
+                    errors.push(LinkValidationError::new(
+                        link.clone(),
+                        LinkValidationReason::TooFewLinks {
+                            actual: destinations.len(),
+                            min_items,
+                        },
+                    ));
+                }
+            }
+            if let Some(max_items) = array.max_items() {
+                if destinations.len() > max_items {
+                    errors.push(LinkValidationError::new(
+                        link.clone(),
+                        LinkValidationReason::TooManyLinks {
+                            actual: destinations.len(),
+                            max_items,
+                        },
+                    ));
+                }
+            }
+
+            if let Some(allowed) = array.items() {
+                for destination in destinations {
+                    let reference = <&EntityTypeReference>::from(*destination);
+                    if allowed.one_of().contains(reference) {
+                        continue;
+                    }
+
+                    let Some(destination_type) = provider.entity_type(reference) else {
+                        errors.push(LinkValidationError::new(
+                            link.clone(),
+                            LinkValidationReason::UnresolvedDestination((*destination).clone()),
+                        ));
+                        continue;
+                    };
+
+                    if !allowed
+                        .one_of()
+                        .iter()
+                        .any(|allowed| destination_type.is_subtype_of(allowed, provider))
+                    {
+                        errors.push(LinkValidationError::new(
+                            link.clone(),
+                            LinkValidationReason::InvalidDestination((*destination).clone()),
+                        ));
+                    }
+                }
+            }
+        }
+
+        if errors.is_empty() {
+            Ok(())
+        } else {
+            Err(errors)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use crate::{
+        utils::tests::{base_url, entity_type, url, TestOntology},
+        EntityType, InstanceValidationError, InstanceValidationReason, JsonPointer, JsonType,
+    };
+
+    const NAME: &str = "https://blockprotocol.org/@alice/types/property-type/name/";
+    const BLURB: &str = "https://blockprotocol.org/@alice/types/property-type/blurb/";
+
+    #[test]
+    fn valid() {
+        let block = entity_type(crate::test_data::entity_type::BLOCK_V1);
+
+        block
+            .validate_properties(&json!({ NAME: "Alice" }), &TestOntology::new())
+            .expect("validation failed");
+    }
+
+    #[test]
+    fn missing_required() {
+        let block = entity_type(crate::test_data::entity_type::BLOCK_V1);
+
+        assert_eq!(
+            block.validate_properties(&json!({}), &TestOntology::new()),
+            Err(vec![InstanceValidationError::new(
+                JsonPointer::root(),
+                InstanceValidationReason::MissingRequiredProperty(base_url(NAME)),
+            )])
+        );
+    }
+
+    #[test]
+    fn invalid_type() {
+        let block = entity_type(crate::test_data::entity_type::BLOCK_V1);
+
+        assert_eq!(
+            block.validate_properties(&json!({ NAME: 10 }), &TestOntology::new()),
+            Err(vec![InstanceValidationError::new(
+                JsonPointer::root().join(NAME),
+                InstanceValidationReason::NoMatchingOneOf {
+                    closest: 0,
+                    errors: vec![InstanceValidationError::new(
+                        JsonPointer::root().join(NAME),
+                        InstanceValidationReason::InvalidType {
+                            expected: JsonType::String,
+                            actual: JsonType::Number,
+                        },
+                    )],
+                },
+            )])
+        );
+        assert_eq!(
+            block.validate_properties(&json!([]), &TestOntology::new()),
+            Err(vec![InstanceValidationError::new(
+                JsonPointer::root(),
+                InstanceValidationReason::InvalidType {
+                    expected: JsonType::Object,
+                    actual: JsonType::Array,
+                },
+            )])
+        );
+    }
+
+    #[test]
+    fn unexpected_property() {
+        let block = entity_type(crate::test_data::entity_type::BLOCK_V1);
+        let unknown = "https://example.com/property-type/unknown/";
+
+        assert_eq!(
+            block.validate_properties(
+                &json!({ NAME: "Alice", unknown: "Bob", "not a url": 1 }),
+                &TestOntology::new()
+            ),
+            Err(vec![
+                InstanceValidationError::new(
+                    JsonPointer::root().join(unknown),
+                    InstanceValidationReason::UnexpectedProperty(base_url(unknown)),
+                ),
+                InstanceValidationError::new(
+                    JsonPointer::root().join("not a url"),
+                    InstanceValidationReason::InvalidPropertyKey("not a url".to_owned()),
+                ),
+            ])
+        );
+    }
+
+    #[test]
+    fn array_property() {
+        let book = entity_type(crate::test_data::entity_type::BOOK_V1);
+        let ontology = TestOntology::new();
+
+        book.validate_properties(&json!({ NAME: ["Alice", "Bob"] }), &ontology)
+            .expect("validation failed");
+
+        assert_eq!(
+            book.validate_properties(&json!({ NAME: ["Alice", 1] }), &ontology),
+            Err(vec![InstanceValidationError::new(
+                JsonPointer::root().join(NAME).join(1),
+                InstanceValidationReason::NoMatchingOneOf {
+                    closest: 0,
+                    errors: vec![InstanceValidationError::new(
+                        JsonPointer::root().join(NAME).join(1),
+                        InstanceValidationReason::InvalidType {
+                            expected: JsonType::String,
+                            actual: JsonType::Number,
+                        },
+                    )],
+                },
+            )])
+        );
+        assert_eq!(
+            book.validate_properties(&json!({ NAME: "Alice" }), &ontology),
+            Err(vec![InstanceValidationError::new(
+                JsonPointer::root().join(NAME),
+                InstanceValidationReason::InvalidType {
+                    expected: JsonType::Array,
+                    actual: JsonType::String,
+                },
+            )])
+        );
+    }
+
+    #[test]
+    fn nested_property_values() {
+        const CONTRIVED: &str =
+            "https://blockprotocol.org/@alice/types/property-type/contrived-property/";
+        const ADDRESS: &str = "https://example.com/property-type/address/";
+        const STREET: &str = "https://example.com/property-type/street/";
+
+        let mut ontology = TestOntology::new();
+        ontology.add_property_type(
+            &json!({
+                "$schema": "https://blockprotocol.org/types/modules/graph/0.3/schema/property-type",
+                "kind": "propertyType",
+                "$id": "https://example.com/property-type/street/v/1",
+                "title": "Street",
+                "oneOf": [
+                    { "$ref": "https://blockprotocol.org/@blockprotocol/types/data-type/text/v/1" }
+                ]
+            })
+            .to_string(),
+        );
+        ontology.add_property_type(
+            &json!({
+                "$schema": "https://blockprotocol.org/types/modules/graph/0.3/schema/property-type",
+                "kind": "propertyType",
+                "$id": "https://example.com/property-type/address/v/1",
+                "title": "Address",
+                "oneOf": [{
+                    "type": "object",
+                    "properties": {
+                        STREET: { "$ref": "https://example.com/property-type/street/v/1" }
+                    },
+                    "required": [STREET]
+                }]
+            })
+            .to_string(),
+        );
+        let entity_type = entity_type(
+            &json!({
+                "$schema": "https://blockprotocol.org/types/modules/graph/0.3/schema/entity-type",
+                "kind": "entityType",
+                "$id": "https://example.com/entity-type/house/v/1",
+                "type": "object",
+                "title": "House",
+                "properties": {
+                    CONTRIVED: {
+                        "$ref": "https://blockprotocol.org/@alice/types/property-type/contrived-property/v/1"
+                    },
+                    ADDRESS: { "$ref": "https://example.com/property-type/address/v/1" }
+                }
+            })
+            .to_string(),
+        );
+
+        entity_type
+            .validate_properties(
+                &json!({ CONTRIVED: [1, 2, 3, 4], ADDRESS: { STREET: "Main Street" } }),
+                &ontology,
+            )
+            .expect("validation failed");
+        entity_type
+            .validate_properties(&json!({ CONTRIVED: 5 }), &ontology)
+            .expect("validation failed");
+
+        assert_eq!(
+            entity_type.validate_properties(
+                &json!({ CONTRIVED: [1, 2, 3, 4, 5], ADDRESS: { STREET: 1 } }),
+                &ontology,
+            ),
+            Err(vec![
+                InstanceValidationError::new(
+                    JsonPointer::root().join(CONTRIVED),
+                    InstanceValidationReason::NoMatchingOneOf {
+                        closest: 1,
+                        errors: vec![InstanceValidationError::new(
+                            JsonPointer::root().join(CONTRIVED),
+                            InstanceValidationReason::TooManyItems {
+                                actual: 5,
+                                max_items: 4,
+                            },
+                        )],
+                    },
+                ),
+                InstanceValidationError::new(
+                    JsonPointer::root().join(ADDRESS),
+                    InstanceValidationReason::NoMatchingOneOf {
+                        closest: 0,
+                        errors: vec![InstanceValidationError::new(
+                            JsonPointer::root().join(ADDRESS).join(STREET),
+                            InstanceValidationReason::NoMatchingOneOf {
+                                closest: 0,
+                                errors: vec![InstanceValidationError::new(
+                                    JsonPointer::root().join(ADDRESS).join(STREET),
+                                    InstanceValidationReason::InvalidType {
+                                        expected: JsonType::String,
+                                        actual: JsonType::Number,
+                                    },
+                                )],
+                            },
+                        )],
+                    },
+                ),
+            ])
+        );
+    }
+
+    #[test]
+    fn unresolved_property_type() {
+        let book = entity_type(crate::test_data::entity_type::BOOK_V1);
+
+        assert_eq!(
+            book.validate_properties(
+                &json!({ NAME: ["Alice"], BLURB: "A book" }),
+                &TestOntology::new()
+            ),
+            Err(vec![InstanceValidationError::new(
+                JsonPointer::root().join(BLURB),
+                InstanceValidationReason::UnresolvedPropertyType(url(
+                    "https://blockprotocol.org/@alice/types/property-type/blurb/v/1"
+                )),
+            )])
+        );
+    }
+
+    fn entity_type_with_examples(examples: serde_json::Value) -> EntityType {
+        let mut block =
+            serde_json::from_str::<serde_json::Value>(crate::test_data::entity_type::BLOCK_V1)
+                .expect("invalid JSON");
+        block["examples"] = examples;
+        entity_type(&block.to_string())
+    }
+
+    #[test]
+    fn valid_examples() {
+        let block = entity_type_with_examples(json!([{ NAME: "Alice" }, { NAME: "Bob" }]));
+
+        block.validate_examples().expect("validation failed");
+        block
+            .validate_example_values(&TestOntology::new())
+            .expect("validation failed");
+    }
+
+    #[test]
+    fn invalid_example_keys() {
+        let block = entity_type_with_examples(json!([{ NAME: "Alice" }, { BLURB: "Block" }]));
+        let examples = JsonPointer::root().join("examples");
+
+        assert_eq!(
+            block.validate_examples(),
+            Err(vec![
+                InstanceValidationError::new(
+                    examples.join(1).join(BLURB),
+                    InstanceValidationReason::UnexpectedProperty(base_url(BLURB)),
+                ),
+                InstanceValidationError::new(
+                    examples.join(1),
+                    InstanceValidationReason::MissingRequiredProperty(base_url(NAME)),
+                ),
+            ])
+        );
+    }
+
+    #[test]
+    fn invalid_example_values() {
+        let block = entity_type_with_examples(json!([{ NAME: 1 }]));
+        let path = JsonPointer::root().join("examples").join(0).join(NAME);
+
+        block.validate_examples().expect("validation failed");
+        assert_eq!(
+            block.validate_example_values(&TestOntology::new()),
+            Err(vec![InstanceValidationError::new(
+                path.clone(),
+                InstanceValidationReason::NoMatchingOneOf {
+                    closest: 0,
+                    errors: vec![InstanceValidationError::new(
+                        path,
+                        InstanceValidationReason::InvalidType {
+                            expected: JsonType::String,
+                            actual: JsonType::Number,
+                        },
+                    )],
+                },
+            )])
+        );
+    }
+
+    mod links {
+        use super::*;
+        use crate::{LinkValidationError, LinkValidationReason};
+
+        const PERSON: &str = "https://blockprotocol.org/@alice/types/entity-type/person/v/1";
+        const BOOK: &str = "https://blockprotocol.org/@alice/types/entity-type/book/v/1";
+        const FRIEND_OF: &str = "https://blockprotocol.org/@alice/types/entity-type/friend-of/v/1";
+        const OWNS: &str = "https://blockprotocol.org/@alice/types/entity-type/owns/v/1";
+
+        fn validate_links(
+            entity_type: &EntityType,
+            links: &[(&str, &str)],
+        ) -> Result<(), Vec<LinkValidationError>> {
+            let links = links
+                .iter()
+                .map(|(link, destination)| (url(link), url(destination)))
+                .collect::<Vec<_>>();
+            entity_type.validate_links(
+                links.iter().map(|(link, destination)| (link, destination)),
+                &TestOntology::new(),
+            )
+        }
+
+        #[test]
+        fn valid() {
+            let person = entity_type(crate::test_data::entity_type::PERSON_V1);
+
+            validate_links(&person, &[]).expect("validation failed");
+            validate_links(&person, &[
+                (FRIEND_OF, PERSON),
+                (FRIEND_OF, PERSON),
+                (OWNS, BOOK),
+            ])
+            .expect("validation failed");
+        }
+
+        #[test]
+        fn invalid_destination() {
+            let person = entity_type(crate::test_data::entity_type::PERSON_V1);
+            let unknown = "https://example.com/entity-type/unknown/v/1";
+
+            assert_eq!(
+                validate_links(&person, &[
+                    (FRIEND_OF, PERSON),
+                    (FRIEND_OF, BOOK),
+                    (unknown, PERSON)
+                ]),
+                Err(vec![
+                    LinkValidationError::new(
+                        url(FRIEND_OF),
+                        LinkValidationReason::InvalidDestination(url(BOOK)),
+                    ),
+                    LinkValidationError::new(url(unknown), LinkValidationReason::UnexpectedLink),
+                ])
+            );
+        }
+
+        #[test]
+        fn bounds_and_required_links() {
+            let mut person =
+                serde_json::from_str::<serde_json::Value>(crate::test_data::entity_type::PERSON_V1)
+                    .expect("invalid JSON");
+            person["links"][FRIEND_OF]["minItems"] = json!(2);
+            person["links"][FRIEND_OF]["maxItems"] = json!(3);
+            person["requiredLinks"] = json!([OWNS]);
+            let person = entity_type(&person.to_string());
+
+            assert_eq!(
+                validate_links(&person, &[(FRIEND_OF, PERSON)]),
+                Err(vec![
+                    LinkValidationError::new(url(FRIEND_OF), LinkValidationReason::TooFewLinks {
+                        actual: 1,
+                        min_items: 2
+                    },),
+                    LinkValidationError::new(url(OWNS), LinkValidationReason::MissingRequiredLink),
+                ])
+            );
+            assert_eq!(
+                validate_links(&person, &[(FRIEND_OF, PERSON); 4]),
+                Err(vec![
+                    LinkValidationError::new(url(FRIEND_OF), LinkValidationReason::TooManyLinks {
+                        actual: 4,
+                        max_items: 3
+                    },),
+                    LinkValidationError::new(url(OWNS), LinkValidationReason::MissingRequiredLink),
+                ])
+            );
+        }
+
+        #[test]
+        fn subtype_destination() {
+            const BUILDING: &str =
+                "https://blockprotocol.org/@alice/types/entity-type/building/v/1";
+            const CHURCH: &str = "https://blockprotocol.org/@alice/types/entity-type/church/v/1";
+            let unknown = "https://example.com/entity-type/unknown/v/1";
+
+            let mut person =
+                serde_json::from_str::<serde_json::Value>(crate::test_data::entity_type::PERSON_V1)
+                    .expect("invalid JSON");
+            person["links"][OWNS]["items"] = json!({ "oneOf": [{ "$ref": BUILDING }] });
+            let person = entity_type(&person.to_string());
+
+            validate_links(&person, &[(OWNS, BUILDING), (OWNS, CHURCH)])
+                .expect("validation failed");
+            assert_eq!(
+                validate_links(&person, &[(OWNS, PERSON), (OWNS, unknown)]),
+                Err(vec![
+                    LinkValidationError::new(
+                        url(OWNS),
+                        LinkValidationReason::InvalidDestination(url(PERSON)),
+                    ),
+                    LinkValidationError::new(
+                        url(OWNS),
+                        LinkValidationReason::UnresolvedDestination(url(unknown)),
+                    ),
+                ])
+            );
+        }
+    }
+}
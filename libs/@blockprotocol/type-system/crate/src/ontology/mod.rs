@@ -8,18 +8,31 @@
 mod data_type;
 mod entity_type;
 mod property_type;
+mod provider;
+mod resolver;
+mod store;
 pub mod url;
 
 mod shared;
 
-pub use data_type::{DataType, DataTypeReference, ParseDataTypeError};
+pub use data_type::{
+    DataType, DataTypeConstraintError, DataTypeConstraints, DataTypeReference, ParseDataTypeError,
+    Pattern,
+};
 pub use entity_type::{
-    links::{Links, MaybeOrderedArray, ParseLinksError},
-    EntityType, EntityTypeReference, ParseEntityTypeError,
+    links::{LinkValidationError, LinkValidationReason, Links, MaybeOrderedArray, ParseLinksError},
+    DuplicateEntityTypeError, EntityType, EntityTypeReference, MergeEntityTypeError,
+    ParseEntityTypeError, SubstitutionError,
 };
 pub use property_type::{
     ParsePropertyTypeError, PropertyType, PropertyTypeReference, PropertyValues,
 };
+pub use provider::OntologyTypeProvider;
+#[cfg(not(target_arch = "wasm32"))]
+pub use resolver::DirectoryResolver;
+#[cfg(all(not(target_arch = "wasm32"), feature = "http"))]
+pub use resolver::HttpResolver;
+pub use resolver::{CachingResolver, ResolveTypeError, TypeResolver};
 pub use shared::{
     all_of::{error::ParseAllOfError, AllOf},
     array::{
@@ -29,10 +42,18 @@ pub use shared::{
         },
         Array, ValueOrArray,
     },
+    compatibility::{Incompatibility, IncompatibilityReason},
+    diff::{Change, ChangeClassification, ChangeKind},
+    instance::{InstanceValidationError, InstanceValidationReason},
+    json_pointer::JsonPointer,
+    json_schema::JsonSchemaError,
+    json_type::JsonType,
     object::{error::ParsePropertyTypeObjectError, Object},
     one_of::{error::ParseOneOfError, OneOf},
+    satisfiability::{Unsatisfiable, UnsatisfiableReason},
     validate::{ValidateUrl, ValidationError},
 };
+pub use store::{DanglingReference, InsertTypeError, OntologyStore};
 
 // Re-export the repr contents so they're nicely grouped and so that they're easier to import in
 // a non-ambiguous way where they don't get confused with their non repr counterparts.
@@ -54,3 +75,11 @@ pub(crate) mod raw {
         },
     };
 }
+
+// This is not part of the public API, it's only exported with the `bench` feature to benchmark
+// against the raw representation.
+#[cfg(feature = "bench")]
+#[doc(hidden)]
+pub mod bench_raw {
+    pub use super::raw::*;
+}
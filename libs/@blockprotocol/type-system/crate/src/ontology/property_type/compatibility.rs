@@ -0,0 +1,605 @@
+use crate::{
+    ontology::shared::compatibility::into_result, Array, DataTypeReference, Incompatibility,
+    IncompatibilityReason, JsonPointer, Object, OneOf, OntologyTypeProvider, PropertyType,
+    PropertyTypeReference, PropertyValues, ValueOrArray,
+};
+
+impl PropertyType {
+    /// Checks if this property type is compatible with `other` as defined in [RFC 0408].
+    ///
+    /// A property type is compatible with another one if both share the same base URL and every
+    /// value which is valid for this property type is also valid for `other`. This means that
+    /// `other` may loosen the constraints of this property type, but must not tighten them. The
+    /// referenced property types and data types are looked up through `provider`.
+    ///
+    /// # Errors
+    ///
+    /// - every [`Incompatibility`] found, addressed by its location inside the schema of this
+    ///   property type
+    ///
+    /// [RFC 0408]: https://github.com/blockprotocol/blockprotocol/blob/main/rfcs/text/0408-versioning-types.md#property-types
+    pub fn is_compatible_with(
+        &self,
+        other: &Self,
+        provider: &impl OntologyTypeProvider,
+    ) -> Result<(), Vec<Incompatibility>> {
+        let mut incompatibilities = Vec::new();
+        self.check_compatibility_at(
+            other,
+            &JsonPointer::root(),
+            provider,
+            &mut incompatibilities,
+        );
+        into_result(incompatibilities)
+    }
+
+    fn check_compatibility_at(
+        &self,
+        other: &Self,
+        path: &JsonPointer,
+        provider: &impl OntologyTypeProvider,
+        incompatibilities: &mut Vec<Incompatibility>,
+    ) {
+        if self.id.base_url != other.id.base_url {
+            incompatibilities.push(Incompatibility::new(
+                path.join("$id"),
+                IncompatibilityReason::BaseUrlMismatch {
+                    base_url: self.id.base_url.clone(),
+                    other_base_url: other.id.base_url.clone(),
+                },
+            ));
+        }
+
+        self.one_of.check_compatibility_at(
+            &other.one_of,
+            &path.join("oneOf"),
+            provider,
+            incompatibilities,
+        );
+    }
+}
+
+impl PropertyTypeReference {
+    pub(crate) fn check_compatibility_at(
+        &self,
+        other: &Self,
+        path: &JsonPointer,
+        provider: &impl OntologyTypeProvider,
+        incompatibilities: &mut Vec<Incompatibility>,
+    ) {
+        // Types are immutable, so a type is always compatible with itself
+        if self == other {
+            return;
+        }
+
+        match (provider.property_type(self), provider.property_type(other)) {
+            (Some(property_type), Some(other_property_type)) => property_type
+                .check_compatibility_at(other_property_type, path, provider, incompatibilities),
+            (None, _) => incompatibilities.push(Incompatibility::new(
+                path.clone(),
+                IncompatibilityReason::UnresolvedPropertyType(self.url().clone()),
+            )),
+            (_, None) => incompatibilities.push(Incompatibility::new(
+                path.clone(),
+                IncompatibilityReason::UnresolvedPropertyType(other.url().clone()),
+            )),
+        }
+    }
+}
+
+impl DataTypeReference {
+    fn check_compatibility_at(
+        &self,
+        other: &Self,
+        path: &JsonPointer,
+        provider: &impl OntologyTypeProvider,
+        incompatibilities: &mut Vec<Incompatibility>,
+    ) {
+        if self == other {
+            return;
+        }
+
+        match (provider.data_type(self), provider.data_type(other)) {
+            (Some(data_type), Some(other_data_type)) => {
+                if let Err(data_type_incompatibilities) =
+                    data_type.is_compatible_with(other_data_type)
+                {
+                    incompatibilities.push(Incompatibility::new(
+                        path.clone(),
+                        IncompatibilityReason::IncompatibleDataType {
+                            data_type: self.url().clone(),
+                            other_data_type: other.url().clone(),
+                            incompatibilities: data_type_incompatibilities,
+                        },
+                    ));
+                }
+            }
+            (None, _) => incompatibilities.push(Incompatibility::new(
+                path.clone(),
+                IncompatibilityReason::UnresolvedDataType(self.url().clone()),
+            )),
+            (_, None) => incompatibilities.push(Incompatibility::new(
+                path.clone(),
+                IncompatibilityReason::UnresolvedDataType(other.url().clone()),
+            )),
+        }
+    }
+}
+
+impl OneOf<PropertyValues> {
+    /// Checks that each possibility is compatible with at least one possibility of `other`.
+    ///
+    /// For every possibility which is not, a single [`NoCompatibleOneOf`] incompatibility is
+    /// reported, carrying the incompatibilities of the possibility of `other` which came closest.
+    ///
+    /// [`NoCompatibleOneOf`]: IncompatibilityReason::NoCompatibleOneOf
+    fn check_compatibility_at(
+        &self,
+        other: &Self,
+        path: &JsonPointer,
+        provider: &impl OntologyTypeProvider,
+        incompatibilities: &mut Vec<Incompatibility>,
+    ) {
+        for (index, possibility) in self.one_of().iter().enumerate() {
+            let possibility_path = path.join(index);
+            let mut closest: Option<(usize, Vec<Incompatibility>)> = None;
+
+            for (other_index, other_possibility) in other.one_of().iter().enumerate() {
+                let mut possibility_incompatibilities = Vec::new();
+                possibility.check_compatibility_at(
+                    other_possibility,
+                    &possibility_path,
+                    provider,
+                    &mut possibility_incompatibilities,
+                );
+                if possibility_incompatibilities.is_empty() {
+                    closest = None;
+                    break;
+                }
+
+                let is_closer = closest
+                    .as_ref()
+                    .is_none_or(|(_, closest_incompatibilities)| {
+                        distance(&possibility_incompatibilities)
+                            < distance(closest_incompatibilities)
+                    });
+                if is_closer {
+                    closest = Some((other_index, possibility_incompatibilities));
+                }
+            }
+
+            if let Some((closest, closest_incompatibilities)) = closest {
+                incompatibilities.push(Incompatibility::new(
+                    possibility_path,
+                    IncompatibilityReason::NoCompatibleOneOf {
+                        closest,
+                        incompatibilities: closest_incompatibilities,
+                    },
+                ));
+            }
+        }
+    }
+}
+
+/// Ranks how far off a possibility was from another one given the `incompatibilities` found.
+///
+/// Possibilities of a different kind are always considered the furthest apart.
+fn distance(incompatibilities: &[Incompatibility]) -> (bool, usize) {
+    let is_kind_mismatch = incompatibilities.iter().any(|incompatibility| {
+        matches!(
+            incompatibility.reason,
+            IncompatibilityReason::PropertyValuesMismatch
+        )
+    });
+    (is_kind_mismatch, incompatibilities.len())
+}
+
+impl PropertyValues {
+    /// Checks if these property values are compatible with `other` as defined in [RFC 0408].
+    ///
+    /// Property values are only compatible with property values of the same kind. The referenced
+    /// property types and data types are looked up through `provider`.
+    ///
+    /// # Errors
+    ///
+    /// - every [`Incompatibility`] found, addressed by its location inside the schema of these
+    ///   property values
+    ///
+    /// [RFC 0408]: https://github.com/blockprotocol/blockprotocol/blob/main/rfcs/text/0408-versioning-types.md#property-types
+    pub fn is_compatible_with(
+        &self,
+        other: &Self,
+        provider: &impl OntologyTypeProvider,
+    ) -> Result<(), Vec<Incompatibility>> {
+        let mut incompatibilities = Vec::new();
+        self.check_compatibility_at(
+            other,
+            &JsonPointer::root(),
+            provider,
+            &mut incompatibilities,
+        );
+        into_result(incompatibilities)
+    }
+
+    fn check_compatibility_at(
+        &self,
+        other: &Self,
+        path: &JsonPointer,
+        provider: &impl OntologyTypeProvider,
+        incompatibilities: &mut Vec<Incompatibility>,
+    ) {
+        match (self, other) {
+            (Self::DataTypeReference(reference), Self::DataTypeReference(other_reference)) => {
+                reference.check_compatibility_at(
+                    other_reference,
+                    &path.join("$ref"),
+                    provider,
+                    incompatibilities,
+                );
+            }
+            (Self::PropertyTypeObject(object), Self::PropertyTypeObject(other_object)) => {
+                object.check_compatibility_at(other_object, path, provider, incompatibilities);
+            }
+            (Self::ArrayOfPropertyValues(array), Self::ArrayOfPropertyValues(other_array)) => {
+                array.check_compatibility_at(other_array, path, provider, incompatibilities);
+            }
+            _ => incompatibilities.push(Incompatibility::new(
+                path.clone(),
+                IncompatibilityReason::PropertyValuesMismatch,
+            )),
+        }
+    }
+}
+
+impl Array<OneOf<PropertyValues>> {
+    /// Checks if this array is compatible with `other` as defined in [RFC 0408].
+    ///
+    /// Each possibility of the items has to be compatible with a possibility of the items of
+    /// `other`, and `other` must not allow fewer items than this array. The referenced property
+    /// types and data types are looked up through `provider`.
+    ///
+    /// # Errors
+    ///
+    /// - every [`Incompatibility`] found, addressed by its location inside the schema of this array
+    ///
+    /// [RFC 0408]: https://github.com/blockprotocol/blockprotocol/blob/main/rfcs/text/0408-versioning-types.md#an-array-of-property-values
+    pub fn is_compatible_with(
+        &self,
+        other: &Self,
+        provider: &impl OntologyTypeProvider,
+    ) -> Result<(), Vec<Incompatibility>> {
+        let mut incompatibilities = Vec::new();
+        self.check_compatibility_at(
+            other,
+            &JsonPointer::root(),
+            provider,
+            &mut incompatibilities,
+        );
+        into_result(incompatibilities)
+    }
+
+    fn check_compatibility_at(
+        &self,
+        other: &Self,
+        path: &JsonPointer,
+        provider: &impl OntologyTypeProvider,
+        incompatibilities: &mut Vec<Incompatibility>,
+    ) {
+        self.items().check_compatibility_at(
+            other.items(),
+            &path.join("items").join("oneOf"),
+            provider,
+            incompatibilities,
+        );
+        self.check_bounds_compatibility(other, path, incompatibilities);
+    }
+}
+
+impl ValueOrArray<PropertyTypeReference> {
+    /// Checks that this property definition is compatible with `other`, i.e. both are either a
+    /// single value or an array with at most the same bounds, referencing compatible property
+    /// types.
+    pub(crate) fn check_compatibility_at(
+        &self,
+        other: &Self,
+        path: &JsonPointer,
+        provider: &impl OntologyTypeProvider,
+        incompatibilities: &mut Vec<Incompatibility>,
+    ) {
+        match (self, other) {
+            (Self::Value(reference), Self::Value(other_reference)) => {
+                reference.check_compatibility_at(
+                    other_reference,
+                    path,
+                    provider,
+                    incompatibilities,
+                );
+            }
+            (Self::Array(array), Self::Array(other_array)) => {
+                array.items().check_compatibility_at(
+                    other_array.items(),
+                    &path.join("items"),
+                    provider,
+                    incompatibilities,
+                );
+                array.check_bounds_compatibility(other_array, path, incompatibilities);
+            }
+            (Self::Value(_), Self::Array(_)) => {
+                incompatibilities.push(Incompatibility::new(
+                    path.clone(),
+                    IncompatibilityReason::ExpectedArray,
+                ));
+            }
+            (Self::Array(_), Self::Value(_)) => {
+                incompatibilities.push(Incompatibility::new(
+                    path.clone(),
+                    IncompatibilityReason::UnexpectedArray,
+                ));
+            }
+        }
+    }
+}
+
+impl<const MIN: usize> Object<ValueOrArray<PropertyTypeReference>, MIN> {
+    /// Checks if this object is compatible with `other` as defined in [RFC 0408].
+    ///
+    /// Every property of this object has to be defined in `other` in the same way, i.e. either as
+    /// a single value or as an array with at most the same bounds, and has to reference a
+    /// compatible property type. Every property required by `other` has to be required by this
+    /// object. The referenced property types and data types are looked up through `provider`.
+    ///
+    /// # Errors
+    ///
+    /// - every [`Incompatibility`] found, addressed by its location inside the schema of this
+    ///   object
+    ///
+    /// [RFC 0408]: https://github.com/blockprotocol/blockprotocol/blob/main/rfcs/text/0408-versioning-types.md#property-type-object
+    pub fn is_compatible_with(
+        &self,
+        other: &Self,
+        provider: &impl OntologyTypeProvider,
+    ) -> Result<(), Vec<Incompatibility>> {
+        let mut incompatibilities = Vec::new();
+        self.check_compatibility_at(
+            other,
+            &JsonPointer::root(),
+            provider,
+            &mut incompatibilities,
+        );
+        into_result(incompatibilities)
+    }
+
+    pub(crate) fn check_compatibility_at(
+        &self,
+        other: &Self,
+        path: &JsonPointer,
+        provider: &impl OntologyTypeProvider,
+        incompatibilities: &mut Vec<Incompatibility>,
+    ) {
+        for (base_url, property) in self.properties() {
+            let property_path = path.join("properties").join(base_url);
+            if let Some(other_property) = other.properties().get(base_url) {
+                property.check_compatibility_at(
+                    other_property,
+                    &property_path,
+                    provider,
+                    incompatibilities,
+                );
+            } else {
+                incompatibilities.push(Incompatibility::new(
+                    property_path,
+                    IncompatibilityReason::MissingProperty(base_url.clone()),
+                ));
+            }
+        }
+
+        for required in other.required() {
+            if !self.required().contains(required) {
+                incompatibilities.push(Incompatibility::new(
+                    path.join("required"),
+                    IncompatibilityReason::RequiredProperty(required.clone()),
+                ));
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+    use crate::{
+        raw,
+        utils::tests::{base_url, check_serialization_from_str, url, TestOntology},
+        JsonType,
+    };
+
+    const NAME: &str = "https://blockprotocol.org/@alice/types/property-type/name/";
+    const AGE: &str = "https://blockprotocol.org/@alice/types/property-type/age/";
+
+    fn property_type(input: &str) -> PropertyType {
+        check_serialization_from_str::<PropertyType, raw::PropertyType>(input, None)
+    }
+
+    fn object_property_type(version: u32, object: &serde_json::Value) -> PropertyType {
+        property_type(
+            &json!({
+                "$schema": "https://blockprotocol.org/types/modules/graph/0.3/schema/property-type",
+                "kind": "propertyType",
+                "$id": format!("https://example.com/property-type/person/v/{version}"),
+                "title": "Person",
+                "oneOf": [object]
+            })
+            .to_string(),
+        )
+    }
+
+    #[test]
+    fn added_possibility() {
+        let v1 = property_type(crate::test_data::property_type::USER_ID_V1);
+        let v2 = property_type(crate::test_data::property_type::USER_ID_V2);
+        let ontology = TestOntology::new();
+
+        v1.is_compatible_with(&v2, &ontology)
+            .expect("adding a possibility should be compatible");
+        assert_eq!(
+            v2.is_compatible_with(&v1, &ontology),
+            Err(vec![Incompatibility::new(
+                JsonPointer::root().join("oneOf").join(1),
+                IncompatibilityReason::NoCompatibleOneOf {
+                    closest: 0,
+                    incompatibilities: vec![Incompatibility::new(
+                        JsonPointer::root().join("oneOf").join(1).join("$ref"),
+                        IncompatibilityReason::IncompatibleDataType {
+                            data_type: url("https://blockprotocol.org/@blockprotocol/types/data-type/number/v/1"),
+                            other_data_type: url("https://blockprotocol.org/@blockprotocol/types/data-type/text/v/1"),
+                            incompatibilities: vec![Incompatibility::new(
+                                JsonPointer::root().join("type"),
+                                IncompatibilityReason::JsonTypeMismatch {
+                                    json_type: JsonType::Number,
+                                    other_json_type: JsonType::String,
+                                },
+                            )],
+                        },
+                    )],
+                },
+            )])
+        );
+    }
+
+    #[test]
+    fn different_base_url() {
+        let user_id = property_type(crate::test_data::property_type::USER_ID_V1);
+        let text = property_type(crate::test_data::property_type::TEXT_V1);
+
+        assert_eq!(
+            user_id.is_compatible_with(&text, &TestOntology::new()),
+            Err(vec![Incompatibility::new(
+                JsonPointer::root().join("$id"),
+                IncompatibilityReason::BaseUrlMismatch {
+                    base_url: user_id.id().base_url.clone(),
+                    other_base_url: text.id().base_url.clone(),
+                },
+            )])
+        );
+    }
+
+    #[test]
+    fn object_properties() {
+        let ontology = TestOntology::new();
+        let strict = object_property_type(
+            1,
+            &json!({
+                "type": "object",
+                "properties": {
+                    NAME: { "$ref": "https://blockprotocol.org/@alice/types/property-type/name/v/1" }
+                },
+                "required": [NAME]
+            }),
+        );
+        let loose = object_property_type(
+            2,
+            &json!({
+                "type": "object",
+                "properties": {
+                    NAME: { "$ref": "https://blockprotocol.org/@alice/types/property-type/name/v/1" },
+                    AGE: { "$ref": "https://blockprotocol.org/@alice/types/property-type/age/v/1" }
+                },
+                "required": [AGE]
+            }),
+        );
+
+        assert_eq!(
+            strict.is_compatible_with(&loose, &ontology),
+            Err(vec![Incompatibility::new(
+                JsonPointer::root().join("oneOf").join(0),
+                IncompatibilityReason::NoCompatibleOneOf {
+                    closest: 0,
+                    incompatibilities: vec![Incompatibility::new(
+                        JsonPointer::root().join("oneOf").join(0).join("required"),
+                        IncompatibilityReason::RequiredProperty(base_url(AGE)),
+                    )],
+                },
+            )])
+        );
+        assert_eq!(
+            loose.is_compatible_with(&strict, &ontology),
+            Err(vec![Incompatibility::new(
+                JsonPointer::root().join("oneOf").join(0),
+                IncompatibilityReason::NoCompatibleOneOf {
+                    closest: 0,
+                    incompatibilities: vec![
+                        Incompatibility::new(
+                            JsonPointer::root()
+                                .join("oneOf")
+                                .join(0)
+                                .join("properties")
+                                .join(AGE),
+                            IncompatibilityReason::MissingProperty(base_url(AGE)),
+                        ),
+                        Incompatibility::new(
+                            JsonPointer::root().join("oneOf").join(0).join("required"),
+                            IncompatibilityReason::RequiredProperty(base_url(NAME)),
+                        ),
+                    ],
+                },
+            )])
+        );
+    }
+
+    #[test]
+    fn array_bounds() {
+        let ontology = TestOntology::new();
+        let array = |min_items: Option<usize>, max_items: Option<usize>| {
+            let mut array = json!({
+                "type": "array",
+                "items": {
+                    "oneOf": [
+                        { "$ref": "https://blockprotocol.org/@blockprotocol/types/data-type/number/v/1" }
+                    ]
+                }
+            });
+            if let Some(min_items) = min_items {
+                array["minItems"] = json!(min_items);
+            }
+            if let Some(max_items) = max_items {
+                array["maxItems"] = json!(max_items);
+            }
+            serde_json::from_value::<raw::PropertyValues>(array)
+                .expect("failed to deserialize property values")
+                .try_into()
+                .map(|values| match values {
+                    PropertyValues::ArrayOfPropertyValues(array) => array,
+                    _ => unreachable!("deserialized an array"),
+                })
+                .expect("failed to convert property values")
+        };
+
+        array(Some(2), Some(5))
+            .is_compatible_with(&array(Some(1), Some(10)), &ontology)
+            .expect("loosening the bounds should be compatible");
+        array(Some(2), Some(5))
+            .is_compatible_with(&array(None, None), &ontology)
+            .expect("removing the bounds should be compatible");
+        assert_eq!(
+            array(None, Some(10)).is_compatible_with(&array(Some(1), Some(5)), &ontology),
+            Err(vec![
+                Incompatibility::new(
+                    JsonPointer::root().join("minItems"),
+                    IncompatibilityReason::MinItemsIncreased {
+                        min_items: None,
+                        other_min_items: 1,
+                    },
+                ),
+                Incompatibility::new(
+                    JsonPointer::root().join("maxItems"),
+                    IncompatibilityReason::MaxItemsDecreased {
+                        max_items: Some(10),
+                        other_max_items: 5,
+                    },
+                ),
+            ])
+        );
+    }
+}
@@ -0,0 +1,227 @@
+use indexmap::IndexSet;
+
+use crate::{
+    ontology::shared::diff::diff_annotations, Change, ChangeKind, JsonPointer, Object,
+    PropertyType, PropertyTypeReference, PropertyValues, ValueOrArray,
+};
+
+impl PropertyType {
+    /// Returns every change from this property type to `other`, typically a later version of it.
+    ///
+    /// The possibilities in `oneOf` are compared by their position. Two differing property type
+    /// objects at the same position are compared property by property, any other differing
+    /// possibility is reported as [`PossibilityChanged`]. Changes of the `title` and the
+    /// `description` are reported as semantic-annotation changes, everything else as constraint
+    /// changes.
+    ///
+    /// [`PossibilityChanged`]: ChangeKind::PossibilityChanged
+    #[must_use]
+    pub fn diff(&self, other: &Self) -> Vec<Change> {
+        let mut changes = Vec::new();
+        let one_of_path = JsonPointer::root().join("oneOf");
+
+        diff_annotations(
+            (self.title(), self.description()),
+            (other.title(), other.description()),
+            &mut changes,
+        );
+
+        for index in 0..self.one_of().len().max(other.one_of().len()) {
+            let path = one_of_path.join(index);
+            match (self.one_of().get(index), other.one_of().get(index)) {
+                (Some(values), Some(other_values)) if values == other_values => {}
+                (
+                    Some(PropertyValues::PropertyTypeObject(object)),
+                    Some(PropertyValues::PropertyTypeObject(other_object)),
+                ) => object.diff_at(other_object, &path, &mut changes),
+                (
+                    Some(PropertyValues::ArrayOfPropertyValues(array)),
+                    Some(PropertyValues::ArrayOfPropertyValues(other_array)),
+                ) if array.items() == other_array.items() => {
+                    array.diff_bounds_at(other_array, &path, &mut changes);
+                }
+                (Some(_), Some(_)) => {
+                    changes.push(Change::new(path, ChangeKind::PossibilityChanged));
+                }
+                (Some(_), None) => {
+                    changes.push(Change::new(path, ChangeKind::PossibilityRemoved));
+                }
+                (None, Some(_)) => changes.push(Change::new(path, ChangeKind::PossibilityAdded)),
+                (None, None) => {}
+            }
+        }
+
+        changes
+    }
+}
+
+impl<const MIN: usize> Object<ValueOrArray<PropertyTypeReference>, MIN> {
+    /// Pushes every change of the `properties` and `required` fields from this object to `other`
+    /// to `changes`.
+    pub(crate) fn diff_at(&self, other: &Self, path: &JsonPointer, changes: &mut Vec<Change>) {
+        let properties = self
+            .properties()
+            .keys()
+            .chain(other.properties().keys())
+            .collect::<IndexSet<_>>();
+
+        for base_url in properties {
+            let property_path = path.join("properties").join(base_url);
+            let (property, other_property) = match (
+                self.properties().get(base_url),
+                other.properties().get(base_url),
+            ) {
+                (Some(property), Some(other_property)) => (property, other_property),
+                (Some(_), None) => {
+                    changes.push(Change::new(
+                        property_path,
+                        ChangeKind::PropertyRemoved(base_url.clone()),
+                    ));
+                    continue;
+                }
+                (None, Some(_)) => {
+                    changes.push(Change::new(
+                        property_path,
+                        ChangeKind::PropertyAdded(base_url.clone()),
+                    ));
+                    continue;
+                }
+                (None, None) => continue,
+            };
+
+            let (reference, other_reference) = match (property, other_property) {
+                (ValueOrArray::Value(reference), ValueOrArray::Value(other_reference)) => {
+                    (reference, other_reference)
+                }
+                (ValueOrArray::Value(reference), ValueOrArray::Array(other_array)) => {
+                    changes.push(Change::new(property_path.clone(), ChangeKind::ArrayAdded));
+                    (reference, other_array.items())
+                }
+                (ValueOrArray::Array(array), ValueOrArray::Value(other_reference)) => {
+                    changes.push(Change::new(property_path.clone(), ChangeKind::ArrayRemoved));
+                    (array.items(), other_reference)
+                }
+                (ValueOrArray::Array(array), ValueOrArray::Array(other_array)) => {
+                    array.diff_bounds_at(other_array, &property_path, changes);
+                    (array.items(), other_array.items())
+                }
+            };
+
+            if reference != other_reference {
+                changes.push(Change::new(
+                    property_path,
+                    ChangeKind::PropertyTypeChanged {
+                        old: reference.url().clone(),
+                        new: other_reference.url().clone(),
+                    },
+                ));
+            }
+        }
+
+        let required_path = path.join("required");
+        for required in self.required() {
+            if !other.required().contains(required) {
+                changes.push(Change::new(
+                    required_path.clone(),
+                    ChangeKind::RequiredRemoved(required.clone()),
+                ));
+            }
+        }
+        for required in other.required() {
+            if !self.required().contains(required) {
+                changes.push(Change::new(
+                    required_path.clone(),
+                    ChangeKind::RequiredAdded(required.clone()),
+                ));
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+    use crate::{
+        raw,
+        utils::tests::{base_url, check_serialization_from_str},
+    };
+
+    const STREET: &str = "https://example.com/property-type/street/";
+    const CITY: &str = "https://example.com/property-type/city/";
+
+    fn property_type(version: u32, one_of: &serde_json::Value) -> PropertyType {
+        check_serialization_from_str::<PropertyType, raw::PropertyType>(
+            &json!({
+                "$schema": "https://blockprotocol.org/types/modules/graph/0.3/schema/property-type",
+                "kind": "propertyType",
+                "$id": format!("https://example.com/property-type/address/v/{version}"),
+                "title": "Address",
+                "oneOf": one_of
+            })
+            .to_string(),
+            None,
+        )
+    }
+
+    #[test]
+    fn changes() {
+        let text =
+            json!({ "$ref": "https://blockprotocol.org/@blockprotocol/types/data-type/text/v/1" });
+        let old = property_type(
+            1,
+            &json!([{
+                "type": "object",
+                "properties": {
+                    STREET: { "$ref": format!("{STREET}v/1") },
+                    CITY: { "$ref": format!("{CITY}v/1") }
+                },
+                "required": [STREET]
+            }]),
+        );
+        let new = property_type(
+            2,
+            &json!([
+                {
+                    "type": "object",
+                    "properties": {
+                        STREET: {
+                            "type": "array",
+                            "items": { "$ref": format!("{STREET}v/2") },
+                            "maxItems": 2
+                        }
+                    },
+                    "required": [STREET]
+                },
+                text
+            ]),
+        );
+
+        assert!(old.diff(&old).is_empty());
+
+        let path = JsonPointer::root().join("oneOf").join(0);
+        assert_eq!(old.diff(&new), [
+            Change::new(path.join("properties").join(STREET), ChangeKind::ArrayAdded),
+            Change::new(
+                path.join("properties").join(STREET),
+                ChangeKind::PropertyTypeChanged {
+                    old: format!("{STREET}v/1")
+                        .parse()
+                        .expect("invalid versioned URL"),
+                    new: format!("{STREET}v/2")
+                        .parse()
+                        .expect("invalid versioned URL"),
+                },
+            ),
+            Change::new(
+                path.join("properties").join(CITY),
+                ChangeKind::PropertyRemoved(base_url(CITY)),
+            ),
+            Change::new(
+                JsonPointer::root().join("oneOf").join(1),
+                ChangeKind::PossibilityAdded
+            ),
+        ]);
+    }
+}
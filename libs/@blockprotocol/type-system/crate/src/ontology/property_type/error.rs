@@ -4,20 +4,30 @@ use thiserror::Error;
 use tsify::Tsify;
 
 use crate::{
-    url::ParseVersionedUrlError, ParseOneOfArrayError, ParseOneOfError,
-    ParsePropertyTypeObjectError,
+    ontology::shared::json_pointer::{Located, ROOT},
+    url::ParseVersionedUrlError,
+    JsonPointer, ParseOneOfArrayError, ParseOneOfError, ParsePropertyTypeObjectError,
 };
 
 #[cfg_attr(target_arch = "wasm32", derive(Tsify))]
 #[derive(Debug, Deserialize, Serialize, PartialEq, Eq, Error)]
 #[serde(tag = "reason", content = "inner")]
 pub enum ParsePropertyTypeError {
-    #[error("invalid `$schema` property, expected `\"https://blockprotocol.org/types/modules/graph/0.3/schema/property-type\"` but received: `{0}`")]
-    InvalidMetaSchema(String),
-    #[error("invalid versioned URL: `{0}`")]
-    InvalidVersionedUrl(ParseVersionedUrlError),
-    #[error("invalid data type reference: `{0}`")]
-    InvalidDataTypeReference(ParseVersionedUrlError),
+    #[error("invalid `$schema` property at `{path}`, expected `\"https://blockprotocol.org/types/modules/graph/0.3/schema/property-type\"` but received: `{meta_schema}`")]
+    InvalidMetaSchema {
+        path: JsonPointer,
+        meta_schema: String,
+    },
+    #[error("invalid versioned URL at `{path}`: `{error}`")]
+    InvalidVersionedUrl {
+        path: JsonPointer,
+        error: ParseVersionedUrlError,
+    },
+    #[error("invalid data type reference at `{path}`: `{error}`")]
+    InvalidDataTypeReference {
+        path: JsonPointer,
+        error: ParseVersionedUrlError,
+    },
     #[error("invalid property type object: `{0}`")]
     InvalidPropertyTypeObject(ParsePropertyTypeObjectError),
     // Boxes to avoid infinitely sized enum due to recursion
@@ -28,3 +38,38 @@ pub enum ParsePropertyTypeError {
     #[error("error in JSON: `{0}`")]
     InvalidJson(String),
 }
+
+impl ParsePropertyTypeError {
+    /// Returns the location of the node which failed to parse, relative to the property type.
+    ///
+    /// [`InvalidJson`] errors are located at the root, as the deserializer does not report a
+    /// location.
+    ///
+    /// [`InvalidJson`]: Self::InvalidJson
+    #[must_use]
+    pub fn path(&self) -> &JsonPointer {
+        match self {
+            Self::InvalidMetaSchema { path, .. }
+            | Self::InvalidVersionedUrl { path, .. }
+            | Self::InvalidDataTypeReference { path, .. } => path,
+            Self::InvalidPropertyTypeObject(error) => error.path(),
+            Self::InvalidOneOf(error) => error.path(),
+            Self::InvalidArrayItems(error) => error.path(),
+            Self::InvalidJson(_) => &ROOT,
+        }
+    }
+}
+
+impl Located for ParsePropertyTypeError {
+    fn prepend_path(&mut self, prefix: &JsonPointer) {
+        match self {
+            Self::InvalidMetaSchema { path, .. }
+            | Self::InvalidVersionedUrl { path, .. }
+            | Self::InvalidDataTypeReference { path, .. } => path.prepend(prefix),
+            Self::InvalidPropertyTypeObject(error) => error.prepend_path(prefix),
+            Self::InvalidOneOf(error) => error.prepend_path(prefix),
+            Self::InvalidArrayItems(error) => error.prepend_path(prefix),
+            Self::InvalidJson(_) => {}
+        }
+    }
+}
@@ -0,0 +1,280 @@
+use serde_json::{Map, Value};
+
+use crate::{
+    ontology::shared::json_schema::{array_schema, JSON_SCHEMA_DIALECT},
+    url::VersionedUrl,
+    JsonSchemaError, Object, OntologyTypeProvider, PropertyType, PropertyTypeReference,
+    PropertyValues, ValueOrArray,
+};
+
+impl PropertyType {
+    /// Returns this property type as a self-contained JSON Schema (draft 2020-12).
+    ///
+    /// Every referenced property type and data type is looked up through `provider` and inlined,
+    /// and every property type object is closed by `additionalProperties: false`.
+    ///
+    /// The possibilities of the `oneOf` are emitted as `anyOf`, as a value only has to match one
+    /// of them to be valid, even if it matches several, see [`validate_value`].
+    ///
+    /// # Errors
+    ///
+    /// - [`UnresolvedDataType`] if a referenced data type could not be found through `provider`
+    /// - [`UnresolvedPropertyType`] if a referenced property type could not be found through
+    ///   `provider`
+    /// - [`RecursivePropertyType`] if a property type references itself, which can't be inlined
+    ///
+    /// [`UnresolvedDataType`]: JsonSchemaError::UnresolvedDataType
+    /// [`UnresolvedPropertyType`]: JsonSchemaError::UnresolvedPropertyType
+    /// [`RecursivePropertyType`]: JsonSchemaError::RecursivePropertyType
+    /// [`validate_value`]: Self::validate_value
+    pub fn to_json_schema(
+        &self,
+        provider: &impl OntologyTypeProvider,
+    ) -> Result<Value, JsonSchemaError> {
+        let mut schema = self.json_schema_object(provider, &mut Vec::new())?;
+        schema.insert("$schema".to_owned(), JSON_SCHEMA_DIALECT.into());
+        schema.insert("$id".to_owned(), self.id.to_string().into());
+        Ok(Value::Object(schema))
+    }
+
+    /// Returns the JSON Schema of this property type without `$schema` and `$id`, so it can be
+    /// inlined into the schema of another type.
+    ///
+    /// `chain` contains the property types which are currently being inlined.
+    fn json_schema_object(
+        &self,
+        provider: &impl OntologyTypeProvider,
+        chain: &mut Vec<VersionedUrl>,
+    ) -> Result<Map<String, Value>, JsonSchemaError> {
+        if chain.contains(&self.id) {
+            return Err(JsonSchemaError::RecursivePropertyType(self.id.clone()));
+        }
+        chain.push(self.id.clone());
+
+        let mut schema = Map::new();
+        schema.insert("title".to_owned(), self.title.clone().into());
+        if let Some(description) = &self.description {
+            schema.insert("description".to_owned(), description.clone().into());
+        }
+        schema.insert(
+            "anyOf".to_owned(),
+            any_of_schema(self.one_of(), provider, chain)?,
+        );
+
+        chain.pop();
+        Ok(schema)
+    }
+}
+
+fn any_of_schema(
+    possibilities: &[PropertyValues],
+    provider: &impl OntologyTypeProvider,
+    chain: &mut Vec<VersionedUrl>,
+) -> Result<Value, JsonSchemaError> {
+    possibilities
+        .iter()
+        .map(|values| values.json_schema(provider, chain))
+        .collect()
+}
+
+impl PropertyValues {
+    fn json_schema(
+        &self,
+        provider: &impl OntologyTypeProvider,
+        chain: &mut Vec<VersionedUrl>,
+    ) -> Result<Value, JsonSchemaError> {
+        match self {
+            Self::DataTypeReference(reference) => provider
+                .data_type(reference)
+                .map(|data_type| Value::Object(data_type.json_schema_object()))
+                .ok_or_else(|| JsonSchemaError::UnresolvedDataType(reference.url().clone())),
+            Self::PropertyTypeObject(object) => {
+                let mut schema = object.json_schema_object(provider, chain)?;
+                schema.insert("additionalProperties".to_owned(), false.into());
+                Ok(Value::Object(schema))
+            }
+            Self::ArrayOfPropertyValues(array) => {
+                let mut items = Map::new();
+                items.insert(
+                    "anyOf".to_owned(),
+                    any_of_schema(array.items().one_of(), provider, chain)?,
+                );
+                Ok(array_schema(Value::Object(items), array))
+            }
+        }
+    }
+}
+
+impl<const MIN: usize> Object<ValueOrArray<PropertyTypeReference>, MIN> {
+    /// Returns the JSON Schema of this object with every referenced property type inlined.
+    ///
+    /// The schema is left open, i.e. it doesn't specify `additionalProperties` or
+    /// `unevaluatedProperties`.
+    pub(crate) fn json_schema_object(
+        &self,
+        provider: &impl OntologyTypeProvider,
+        chain: &mut Vec<VersionedUrl>,
+    ) -> Result<Map<String, Value>, JsonSchemaError> {
+        let mut properties = Map::new();
+        for (base_url, property) in self.properties() {
+            let reference = match property {
+                ValueOrArray::Value(reference) => reference,
+                ValueOrArray::Array(array) => array.items(),
+            };
+            let property_type = provider
+                .property_type(reference)
+                .ok_or_else(|| JsonSchemaError::UnresolvedPropertyType(reference.url().clone()))?;
+            let property_schema = Value::Object(property_type.json_schema_object(provider, chain)?);
+
+            properties.insert(base_url.to_string(), match property {
+                ValueOrArray::Value(_) => property_schema,
+                ValueOrArray::Array(array) => array_schema(property_schema, array),
+            });
+        }
+
+        let mut schema = Map::new();
+        schema.insert("type".to_owned(), "object".into());
+        schema.insert("properties".to_owned(), Value::Object(properties));
+        if !self.required().is_empty() {
+            schema.insert(
+                "required".to_owned(),
+                self.required()
+                    .iter()
+                    .map(|base_url| Value::from(base_url.to_string()))
+                    .collect(),
+            );
+        }
+
+        Ok(schema)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+    use crate::{
+        raw,
+        utils::tests::{check_serialization_from_str, data_type_json, TestOntology},
+    };
+
+    const ADDRESS: &str = "https://example.com/property-type/address/";
+    const STREET: &str = "https://example.com/property-type/street/";
+
+    fn property_type_json(id: &str, one_of: &Value) -> String {
+        json!({
+            "$schema": "https://blockprotocol.org/types/modules/graph/0.3/schema/property-type",
+            "kind": "propertyType",
+            "$id": format!("{id}v/1"),
+            "title": "Property",
+            "oneOf": one_of
+        })
+        .to_string()
+    }
+
+    #[test]
+    fn closed_objects() {
+        let mut ontology = TestOntology::new();
+        ontology.add_property_type(&property_type_json(
+            STREET,
+            &json!([{ "$ref": "https://blockprotocol.org/@blockprotocol/types/data-type/text/v/1" }]),
+        ));
+        let address = check_serialization_from_str::<PropertyType, raw::PropertyType>(
+            &property_type_json(
+                ADDRESS,
+                &json!([{
+                    "type": "object",
+                    "properties": {
+                        STREET: {
+                            "type": "array",
+                            "items": { "$ref": format!("{STREET}v/1") },
+                            "minItems": 1
+                        }
+                    },
+                    "required": [STREET]
+                }]),
+            ),
+            None,
+        );
+
+        let schema = address
+            .to_json_schema(&ontology)
+            .expect("failed to generate JSON Schema");
+        assert_eq!(
+            schema["anyOf"][0]["properties"][STREET],
+            json!({
+                "type": "array",
+                "items": {
+                    "title": "Property",
+                    "anyOf": [{
+                        "title": "Text",
+                        "description": "An ordered sequence of characters",
+                        "type": "string"
+                    }]
+                },
+                "minItems": 1
+            })
+        );
+
+        let validator = jsonschema::validator_for(&schema).expect("invalid JSON Schema");
+        assert!(validator.is_valid(&json!({ STREET: ["Main Street"] })));
+        assert!(!validator.is_valid(&json!({ STREET: [] })));
+        assert!(!validator.is_valid(&json!({ STREET: ["Main Street"], "city": "Berlin" })));
+    }
+
+    #[test]
+    fn overlapping_possibilities() {
+        let mut ontology = TestOntology::new();
+        ontology.add_data_type(
+            &data_type_json("short-text/v/1", "string", &json!({ "maxLength": 10 })).to_string(),
+        );
+        let property_type = check_serialization_from_str::<PropertyType, raw::PropertyType>(
+            &property_type_json(
+                STREET,
+                &json!([
+                    { "$ref": "https://blockprotocol.org/@blockprotocol/types/data-type/text/v/1" },
+                    { "$ref": "https://example.com/data-type/short-text/v/1" }
+                ]),
+            ),
+            None,
+        );
+
+        let schema = property_type
+            .to_json_schema(&ontology)
+            .expect("failed to generate JSON Schema");
+        let validator = jsonschema::validator_for(&schema).expect("invalid JSON Schema");
+
+        // Matches both possibilities
+        let value = json!("a@b.com");
+        property_type
+            .validate_value(&value, &ontology)
+            .expect("value should match both possibilities");
+        assert!(validator.is_valid(&value));
+
+        let value = json!(1);
+        property_type
+            .validate_value(&value, &ontology)
+            .expect_err("value should match no possibility");
+        assert!(!validator.is_valid(&value));
+    }
+
+    #[test]
+    fn recursive_property_type() {
+        let mut ontology = TestOntology::new();
+        let one_of = json!([{
+            "type": "object",
+            "properties": { ADDRESS: { "$ref": format!("{ADDRESS}v/1") } }
+        }]);
+        ontology.add_property_type(&property_type_json(ADDRESS, &one_of));
+        let address = check_serialization_from_str::<PropertyType, raw::PropertyType>(
+            &property_type_json(ADDRESS, &one_of),
+            None,
+        );
+
+        assert_eq!(
+            address.to_json_schema(&ontology),
+            Err(JsonSchemaError::RecursivePropertyType(address.id().clone()))
+        );
+    }
+}
@@ -1,20 +1,24 @@
 use std::collections::HashSet;
 
 pub use error::ParsePropertyTypeError;
-use serde::{Deserialize, Serialize};
 
 use crate::{
+    ontology::shared::parse::parse_all,
     url::{BaseUrl, VersionedUrl},
     Array, DataTypeReference, Object, OneOf, ValidateUrl, ValidationError, ValueOrArray,
 };
 
+mod compatibility;
+mod diff;
 mod error;
+mod json_schema;
 pub(in crate::ontology) mod raw;
+mod serialization;
+mod validation;
 #[cfg(target_arch = "wasm32")]
 mod wasm;
 
-#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
-#[serde(try_from = "raw::PropertyType", into = "raw::PropertyType")]
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub struct PropertyType {
     id: VersionedUrl,
     title: String,
@@ -39,6 +43,24 @@ impl PropertyType {
         }
     }
 
+    /// Parses the JSON `schema` of a property type, reporting every error instead of only the first
+    /// one.
+    ///
+    /// Deserializing `schema` fails fast, as it has to have the shape of a property type before its
+    /// values can be checked.
+    ///
+    /// # Errors
+    ///
+    /// - [`InvalidJson`] if `schema` could not be deserialized, this is the only error reported
+    ///   then
+    /// - every other [`ParsePropertyTypeError`] found in `schema`, in the order they are
+    ///   encountered
+    ///
+    /// [`InvalidJson`]: ParsePropertyTypeError::InvalidJson
+    pub fn parse_all(schema: serde_json::Value) -> Result<Self, Vec<ParsePropertyTypeError>> {
+        parse_all::<raw::PropertyType, _>(schema, ParsePropertyTypeError::InvalidJson)
+    }
+
     #[must_use]
     pub const fn id(&self) -> &VersionedUrl {
         &self.id
@@ -172,7 +194,7 @@ mod tests {
         test_data,
         url::ParseVersionedUrlError,
         utils::tests::{check_serialization_from_str, ensure_failed_validation},
-        ParseOneOfError,
+        JsonPointer, ParseOneOfArrayError, ParseOneOfError,
     };
 
     fn test_property_type_data_refs(
@@ -332,7 +354,10 @@ mod tests {
                   ]
                 }
             ),
-            ParsePropertyTypeError::InvalidMetaSchema(invalid_schema_url.to_owned()),
+            ParsePropertyTypeError::InvalidMetaSchema {
+                path: JsonPointer::root().join("$schema"),
+                meta_schema: invalid_schema_url.to_owned(),
+            },
         );
     }
 
@@ -352,7 +377,10 @@ mod tests {
                   ]
                 }
             ),
-            ParsePropertyTypeError::InvalidVersionedUrl(ParseVersionedUrlError::MissingVersion),
+            ParsePropertyTypeError::InvalidVersionedUrl {
+                path: JsonPointer::root().join("$id"),
+                error: ParseVersionedUrlError::MissingVersion,
+            },
         );
     }
 
@@ -368,9 +396,10 @@ mod tests {
                   "oneOf": []
                 }
             ),
-            ParsePropertyTypeError::InvalidOneOf(Box::new(ParseOneOfError::ValidationError(
-                ValidationError::EmptyOneOf,
-            ))),
+            ParsePropertyTypeError::InvalidOneOf(Box::new(ParseOneOfError::ValidationError {
+                path: JsonPointer::root().join("oneOf"),
+                error: ValidationError::EmptyOneOf,
+            })),
         );
     }
 
@@ -391,10 +420,111 @@ mod tests {
                 }
             ),
             ParsePropertyTypeError::InvalidOneOf(Box::new(ParseOneOfError::PropertyValuesError(
-                ParsePropertyTypeError::InvalidDataTypeReference(
-                    ParseVersionedUrlError::IncorrectFormatting,
-                ),
+                ParsePropertyTypeError::InvalidDataTypeReference {
+                    path: JsonPointer::root().join("oneOf").join(0).join("$ref"),
+                    error: ParseVersionedUrlError::IncorrectFormatting,
+                },
+            ))),
+        );
+    }
+
+    #[test]
+    fn parse_all() {
+        let errors = PropertyType::parse_all(json!(
+            {
+              "$schema": "https://blockprotocol.org/types/modules/graph/0.3/schema/property-type",
+              "kind": "propertyType",
+              "$id": "https://blockprotocol.org/@alice/types/property-type/age/v/1",
+              "title": "Age",
+              "oneOf": [
+                {
+                  "$ref": "https://blockprotocol.org/@blockprotocol/types/data-type/number"
+                },
+                {
+                  "$ref": "https://blockprotocol.org/@blockprotocol/types/data-type/text/v/1"
+                },
+                {
+                  "type": "array",
+                  "items": {
+                    "oneOf": []
+                  }
+                }
+              ]
+            }
+        ))
+        .expect_err("property type should be invalid");
+
+        assert_eq!(errors, [
+            ParsePropertyTypeError::InvalidOneOf(Box::new(ParseOneOfError::PropertyValuesError(
+                ParsePropertyTypeError::InvalidDataTypeReference {
+                    path: JsonPointer::root().join("oneOf").join(0).join("$ref"),
+                    error: ParseVersionedUrlError::IncorrectFormatting,
+                },
             ))),
+            ParsePropertyTypeError::InvalidOneOf(Box::new(ParseOneOfError::PropertyValuesError(
+                ParsePropertyTypeError::InvalidArrayItems(Box::new(
+                    ParseOneOfArrayError::InvalidItems(ParseOneOfError::ValidationError {
+                        path: JsonPointer::root()
+                            .join("oneOf")
+                            .join(2)
+                            .join("items")
+                            .join("oneOf"),
+                        error: ValidationError::EmptyOneOf,
+                    }),
+                )),
+            ))),
+        ]);
+    }
+
+    fn property_values_error(property_values: serde_json::Value) -> String {
+        serde_json::from_value::<raw::PropertyValues>(property_values)
+            .expect_err("property values should be invalid")
+            .to_string()
+    }
+
+    #[test]
+    fn property_values_variant_error() {
+        assert_eq!(
+            property_values_error(json!({
+                "$ref": "https://example.com/data-type/text/v/1",
+                "title": "Text"
+            })),
+            "unknown field `title`, expected `$ref`"
+        );
+        assert!(property_values_error(json!({
+            "type": "object",
+            "properties": {},
+            "requird": []
+        }))
+        .starts_with("unknown field `requird`"));
+        assert!(property_values_error(json!({
+            "type": "array",
+            "items": { "oneOf": [] },
+            "minItem": 1
+        }))
+        .starts_with("unknown field `minItem`"));
+    }
+
+    #[test]
+    fn property_values_nested_error() {
+        assert!(property_values_error(json!({
+            "type": "array",
+            "items": {
+                "oneOf": [
+                    { "$ref": "https://example.com/data-type/text/v/1" },
+                    { "type": "object", "properties": {}, "requird": [] }
+                ]
+            }
+        }))
+        .starts_with("unknown field `requird`"));
+    }
+
+    #[test]
+    fn property_values_unknown_variant() {
+        assert_eq!(
+            property_values_error(json!({ "type": "string" })),
+            "expected a data type reference with `$ref`, a property type object with `\"type\": \
+             \"object\"` or an array with `\"type\": \"array\"`"
         );
     }
 
@@ -1,16 +1,17 @@
 use std::str::FromStr;
 
-use serde::{Deserialize, Serialize};
+use serde::{de, Deserialize, Deserializer, Serialize};
 #[cfg(target_arch = "wasm32")]
 use {tsify::Tsify, wasm_bindgen::prelude::*};
 
 use crate::{
+    ontology::shared::parse::{collect, first_error, map_errors, TryFromRaw},
     raw,
     url::{ParseVersionedUrlError, VersionedUrl},
-    ParsePropertyTypeError,
+    Array, JsonPointer, Object, OneOf, ParsePropertyTypeError,
 };
 
-const META_SCHEMA_ID: &str =
+pub(super) const META_SCHEMA_ID: &str =
     "https://blockprotocol.org/types/modules/graph/0.3/schema/property-type";
 
 /// Will serialize as a constant value `"propertyType"`
@@ -43,28 +44,52 @@ pub struct PropertyType {
     one_of: raw::OneOf<PropertyValues>,
 }
 
-impl TryFrom<PropertyType> for super::PropertyType {
+impl TryFromRaw<PropertyType> for super::PropertyType {
     type Error = ParsePropertyTypeError;
 
-    fn try_from(property_type_repr: PropertyType) -> Result<Self, Self::Error> {
+    fn try_from_raw(property_type_repr: PropertyType) -> Result<Self, Vec<Self::Error>> {
+        let path = JsonPointer::root();
+        let mut errors = Vec::new();
+
         let id = VersionedUrl::from_str(&property_type_repr.id)
-            .map_err(ParsePropertyTypeError::InvalidVersionedUrl)?;
+            .map_err(|error| {
+                errors.push(ParsePropertyTypeError::InvalidVersionedUrl {
+                    path: path.join("$id"),
+                    error,
+                });
+            })
+            .ok();
 
         if property_type_repr.schema != META_SCHEMA_ID {
-            return Err(ParsePropertyTypeError::InvalidMetaSchema(
-                property_type_repr.schema,
-            ));
+            errors.push(ParsePropertyTypeError::InvalidMetaSchema {
+                path: path.join("$schema"),
+                meta_schema: property_type_repr.schema,
+            });
         }
 
-        Ok(Self::new(
-            id,
-            property_type_repr.title,
-            property_type_repr.description,
-            property_type_repr
-                .one_of
-                .try_into()
-                .map_err(|err| ParsePropertyTypeError::InvalidOneOf(Box::new(err)))?,
-        ))
+        let one_of = collect(
+            OneOf::try_from_raw(property_type_repr.one_of),
+            &mut errors,
+            |error| ParsePropertyTypeError::InvalidOneOf(Box::new(error)),
+        );
+
+        match (id, one_of) {
+            (Some(id), Some(one_of)) if errors.is_empty() => Ok(Self::new(
+                id,
+                property_type_repr.title,
+                property_type_repr.description,
+                one_of,
+            )),
+            _ => Err(errors),
+        }
+    }
+}
+
+impl TryFrom<PropertyType> for super::PropertyType {
+    type Error = ParsePropertyTypeError;
+
+    fn try_from(property_type_repr: PropertyType) -> Result<Self, Self::Error> {
+        first_error(Self::try_from_raw(property_type_repr))
     }
 }
 
@@ -114,8 +139,9 @@ impl From<super::PropertyTypeReference> for PropertyTypeReference {
     }
 }
 
+// Only serialization is untagged, see the `Deserialize` implementation below
 #[cfg_attr(target_arch = "wasm32", derive(Tsify))]
-#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
 #[serde(untagged)]
 pub enum PropertyValues {
     DataTypeReference(raw::DataTypeReference),
@@ -128,32 +154,72 @@ pub enum PropertyValues {
     ),
 }
 
-impl TryFrom<PropertyValues> for super::PropertyValues {
+impl<'de> Deserialize<'de> for PropertyValues {
+    /// Deserializes the variant identified by the keywords of `deserializer`.
+    ///
+    /// An untagged enum only reports that none of its variants matched. Instead, a schema with
+    /// `$ref` is a data type reference, one with `"type": "object"` is a property type object and
+    /// one with `"type": "array"` is an array, and the error of that variant is reported.
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let value = serde_json::Value::deserialize(deserializer)?;
+        let property_values = if value.get("$ref").is_some() {
+            serde_json::from_value(value).map(Self::DataTypeReference)
+        } else {
+            match value.get("type").and_then(serde_json::Value::as_str) {
+                Some("object") => serde_json::from_value(value).map(Self::PropertyTypeObject),
+                Some("array") => serde_json::from_value(value).map(Self::ArrayOfPropertyValues),
+                _ => {
+                    return Err(de::Error::custom(
+                        "expected a data type reference with `$ref`, a property type object with \
+                         `\"type\": \"object\"` or an array with `\"type\": \"array\"`",
+                    ));
+                }
+            }
+        };
+
+        property_values.map_err(de::Error::custom)
+    }
+}
+
+impl TryFromRaw<PropertyValues> for super::PropertyValues {
     type Error = ParsePropertyTypeError;
 
-    fn try_from(property_values_repr: PropertyValues) -> Result<Self, Self::Error> {
+    fn try_from_raw(property_values_repr: PropertyValues) -> Result<Self, Vec<Self::Error>> {
         Ok(match property_values_repr {
-            PropertyValues::DataTypeReference(data_type_ref_repr) => Self::DataTypeReference(
-                data_type_ref_repr
-                    .try_into()
-                    .map_err(ParsePropertyTypeError::InvalidDataTypeReference)?,
-            ),
+            PropertyValues::DataTypeReference(data_type_ref_repr) => {
+                Self::DataTypeReference(data_type_ref_repr.try_into().map_err(|error| {
+                    vec![ParsePropertyTypeError::InvalidDataTypeReference {
+                        path: JsonPointer::root().join("$ref"),
+                        error,
+                    }]
+                })?)
+            }
             PropertyValues::PropertyTypeObject(property_type_object_repr) => {
-                Self::PropertyTypeObject(
-                    property_type_object_repr
-                        .try_into()
-                        .map_err(ParsePropertyTypeError::InvalidPropertyTypeObject)?,
-                )
+                Self::PropertyTypeObject(map_errors(
+                    Object::try_from_raw(property_type_object_repr),
+                    ParsePropertyTypeError::InvalidPropertyTypeObject,
+                )?)
+            }
+            PropertyValues::ArrayOfPropertyValues(array_repr) => {
+                Self::ArrayOfPropertyValues(map_errors(Array::try_from_raw(array_repr), |err| {
+                    ParsePropertyTypeError::InvalidArrayItems(Box::new(err))
+                })?)
             }
-            PropertyValues::ArrayOfPropertyValues(array_repr) => Self::ArrayOfPropertyValues(
-                array_repr
-                    .try_into()
-                    .map_err(|err| ParsePropertyTypeError::InvalidArrayItems(Box::new(err)))?,
-            ),
         })
     }
 }
 
+impl TryFrom<PropertyValues> for super::PropertyValues {
+    type Error = ParsePropertyTypeError;
+
+    fn try_from(property_values_repr: PropertyValues) -> Result<Self, Self::Error> {
+        first_error(Self::try_from_raw(property_values_repr))
+    }
+}
+
 impl From<super::PropertyValues> for PropertyValues {
     fn from(property_values: super::PropertyValues) -> Self {
         match property_values {
@@ -0,0 +1,299 @@
+use serde::{
+    de::{self, MapAccess},
+    ser::SerializeStruct,
+    Deserialize, Deserializer, Serialize, Serializer,
+};
+
+use super::raw::META_SCHEMA_ID;
+use crate::{
+    ontology::shared::{
+        array::serialization::ArrayFields,
+        object::serialization::ObjectFields,
+        serialization::{
+            deserialize_map, deserialize_reference, next_id, next_meta_schema, next_tag,
+            next_value, required, required_tag, serialize_optional_field, serialize_reference,
+            FromMap, Key,
+        },
+    },
+    DataTypeReference, JsonPointer, OneOf, ParsePropertyTypeError, PropertyType,
+    PropertyTypeReference, PropertyValues,
+};
+
+const FIELDS: &[&str] = &["$schema", "kind", "$id", "title", "description", "oneOf"];
+const PROPERTY_VALUES_FIELDS: &[&str] = &[
+    "$ref",
+    "type",
+    "properties",
+    "required",
+    "items",
+    "minItems",
+    "maxItems",
+];
+
+impl Serialize for PropertyType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("PropertyType", 6)?;
+        state.serialize_field("$schema", META_SCHEMA_ID)?;
+        state.serialize_field("kind", "propertyType")?;
+        state.serialize_field("$id", &self.id)?;
+        state.serialize_field("title", &self.title)?;
+        serialize_optional_field(&mut state, "description", self.description.as_ref())?;
+        state.serialize_field("oneOf", self.one_of.one_of())?;
+        state.end()
+    }
+}
+
+impl<'de> FromMap<'de> for PropertyType {
+    const EXPECTING: &'static str = "a property type";
+
+    fn from_map<A>(mut map: A) -> Result<Self, A::Error>
+    where
+        A: MapAccess<'de>,
+    {
+        let path = JsonPointer::root();
+
+        let mut schema = false;
+        let mut kind = false;
+        let mut id = None;
+        let mut title = None;
+        let mut description = None;
+        let mut one_of = None;
+        while let Some(key) = map.next_key::<Key>()? {
+            match &*key {
+                "$schema" => {
+                    next_meta_schema(&mut map, &mut schema, META_SCHEMA_ID, |meta_schema| {
+                        ParsePropertyTypeError::InvalidMetaSchema {
+                            path: path.join("$schema"),
+                            meta_schema,
+                        }
+                    })?;
+                }
+                "kind" => next_tag(&mut map, &mut kind, "kind", "propertyType")?,
+                "$id" => {
+                    next_id(&mut map, &mut id, |error| {
+                        ParsePropertyTypeError::InvalidVersionedUrl {
+                            path: path.join("$id"),
+                            error,
+                        }
+                    })?;
+                }
+                "title" => next_value(&mut map, &mut title, "title")?,
+                // An explicit `null` is deserialized as absent
+                "description" => next_value(&mut map, &mut description, "description")?,
+                "oneOf" => {
+                    if one_of.is_some() {
+                        return Err(de::Error::duplicate_field("oneOf"));
+                    }
+                    one_of = Some(OneOf::from_deserialized(map.next_value()?)?);
+                }
+                key => return Err(de::Error::unknown_field(key, FIELDS)),
+            }
+        }
+
+        required_tag(schema, "$schema")?;
+        required_tag(kind, "kind")?;
+        Ok(Self::new(
+            required(id, "$id")?,
+            required(title, "title")?,
+            description.flatten(),
+            required(one_of, "oneOf")?,
+        ))
+    }
+}
+
+impl<'de> Deserialize<'de> for PropertyType {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserialize_map(deserializer)
+    }
+}
+
+impl Serialize for PropertyTypeReference {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serialize_reference("PropertyTypeReference", self.url(), serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for PropertyTypeReference {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserialize_reference(deserializer).map(Self::new)
+    }
+}
+
+impl Serialize for PropertyValues {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        match self {
+            Self::DataTypeReference(reference) => reference.serialize(serializer),
+            Self::PropertyTypeObject(object) => object.serialize(serializer),
+            Self::ArrayOfPropertyValues(array) => array.serialize(serializer),
+        }
+    }
+}
+
+impl<'de> FromMap<'de> for PropertyValues {
+    const EXPECTING: &'static str = "a data type reference, a property type object or an array";
+
+    /// Deserializes the variant identified by the keywords of the schema, see the `Deserialize`
+    /// implementation of [`raw::PropertyValues`].
+    ///
+    /// The keywords of every variant are collected until the end of the schema, as `$ref` or
+    /// `type` may come after them.
+    ///
+    /// [`raw::PropertyValues`]: crate::raw::PropertyValues
+    fn from_map<A>(mut map: A) -> Result<Self, A::Error>
+    where
+        A: MapAccess<'de>,
+    {
+        let mut reference = None;
+        let mut r#type = None::<Key>;
+        let mut object = ObjectFields::default();
+        let mut array = ArrayFields::default();
+        while let Some(key) = map.next_key::<Key>()? {
+            match &*key {
+                "$ref" => next_value(&mut map, &mut reference, "$ref")?,
+                "type" => next_value(&mut map, &mut r#type, "type")?,
+                key => {
+                    if !object.next_value(key, &mut map)? && !array.next_value(key, &mut map)? {
+                        return Err(de::Error::unknown_field(key, PROPERTY_VALUES_FIELDS));
+                    }
+                }
+            }
+        }
+
+        match (reference, r#type.as_deref()) {
+            (Some(url), None) => {
+                if let Some(key) = object.first_key().or_else(|| array.first_key()) {
+                    return Err(de::Error::unknown_field(key, &["$ref"]));
+                }
+                Ok(Self::DataTypeReference(DataTypeReference::new(url)))
+            }
+            (Some(_), Some(_)) => Err(de::Error::unknown_field("type", &["$ref"])),
+            (None, Some("object")) => {
+                if let Some(key) = array.first_key() {
+                    return Err(de::Error::unknown_field(key, &[
+                        "type",
+                        "properties",
+                        "required",
+                    ]));
+                }
+                Ok(Self::PropertyTypeObject(object.finish()?))
+            }
+            (None, Some("array")) => {
+                if let Some(key) = object.first_key() {
+                    return Err(de::Error::unknown_field(key, &[
+                        "type", "items", "minItems", "maxItems",
+                    ]));
+                }
+                Ok(Self::ArrayOfPropertyValues(array.finish()?))
+            }
+            (None, Some(r#type)) => Err(de::Error::invalid_value(
+                de::Unexpected::Str(r#type),
+                &"`object` or `array`",
+            )),
+            (None, None) => Err(de::Error::custom(
+                "expected a data type reference with `$ref`, a property type object with \
+                 `\"type\": \"object\"` or an array with `\"type\": \"array\"`",
+            )),
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for PropertyValues {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserialize_map(deserializer)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    fn property_values(property_values: serde_json::Value) -> Result<PropertyValues, String> {
+        serde_json::from_value(property_values).map_err(|error| error.to_string())
+    }
+
+    #[test]
+    fn variants() {
+        for input in [
+            json!({ "$ref": "https://example.com/data-type/v/1" }),
+            json!({
+                "properties": {
+                    "https://example.com/property-type/": { "$ref": "https://example.com/property-type/v/1" }
+                },
+                "type": "object",
+            }),
+            json!({
+                "items": { "oneOf": [{ "$ref": "https://example.com/data-type/v/1" }] },
+                "type": "array",
+            }),
+        ] {
+            let property_values =
+                property_values(input.clone()).expect("failed to deserialize property values");
+            assert_eq!(
+                serde_json::to_value(&property_values)
+                    .expect("failed to serialize property values"),
+                input
+            );
+        }
+    }
+
+    #[test]
+    fn mixed_variants() {
+        assert_eq!(
+            property_values(json!({
+                "type": "object",
+                "properties": {},
+                "items": { "oneOf": [{ "$ref": "https://example.com/data-type/v/1" }] },
+            })),
+            Err(
+                "unknown field `items`, expected one of `type`, `properties`, `required`"
+                    .to_owned()
+            )
+        );
+        assert_eq!(
+            property_values(json!({
+                "$ref": "https://example.com/data-type/v/1",
+                "type": "array",
+            })),
+            Err("unknown field `type`, expected `$ref`".to_owned())
+        );
+    }
+
+    #[test]
+    fn unknown_variant() {
+        assert_eq!(
+            property_values(json!({ "type": "string" })),
+            Err("invalid value: string \"string\", expected `object` or `array`".to_owned())
+        );
+        property_values(json!({ "items": {} })).expect_err("deserialized an unknown variant");
+    }
+
+    #[test]
+    fn validated() {
+        let error = property_values(json!({ "type": "object", "properties": {} }))
+            .expect_err("deserialized a property type object without properties");
+        assert!(
+            error.starts_with("failed validation at `/properties`"),
+            "{error}"
+        );
+    }
+}
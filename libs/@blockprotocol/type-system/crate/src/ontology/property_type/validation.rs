@@ -0,0 +1,379 @@
+use crate::{
+    url::BaseUrl, InstanceValidationError, InstanceValidationReason, JsonPointer, JsonType, Object,
+    OneOf, OntologyTypeProvider, PropertyType, PropertyTypeReference, PropertyValues, ValueOrArray,
+};
+
+impl PropertyType {
+    /// Validates `value` against this property type.
+    ///
+    /// The referenced property types and data types are looked up through `provider`.
+    ///
+    /// # Errors
+    ///
+    /// - every [`InstanceValidationError`] found if `value` is not a valid instance of this
+    ///   property type
+    pub fn validate_value(
+        &self,
+        value: &serde_json::Value,
+        provider: &impl OntologyTypeProvider,
+    ) -> Result<(), Vec<InstanceValidationError>> {
+        let mut errors = Vec::new();
+        self.validate_value_at(value, &JsonPointer::root(), provider, &mut errors);
+
+        if errors.is_empty() {
+            Ok(())
+        } else {
+            Err(errors)
+        }
+    }
+
+    /// Validates `value` against this property type, pushing every problem found to `errors`.
+    pub(crate) fn validate_value_at(
+        &self,
+        value: &serde_json::Value,
+        path: &JsonPointer,
+        provider: &impl OntologyTypeProvider,
+        errors: &mut Vec<InstanceValidationError>,
+    ) {
+        self.one_of.validate_value_at(value, path, provider, errors);
+    }
+}
+
+impl PropertyTypeReference {
+    fn validate_value_at(
+        &self,
+        value: &serde_json::Value,
+        path: &JsonPointer,
+        provider: &impl OntologyTypeProvider,
+        errors: &mut Vec<InstanceValidationError>,
+    ) {
+        match provider.property_type(self) {
+            Some(property_type) => property_type.validate_value_at(value, path, provider, errors),
+            None => errors.push(InstanceValidationError::new(
+                path.clone(),
+                InstanceValidationReason::UnresolvedPropertyType(self.url().clone()),
+            )),
+        }
+    }
+}
+
+impl OneOf<PropertyValues> {
+    /// Validates `value` against each of the possibilities in turn.
+    ///
+    /// The value is accepted as soon as one possibility matches. If none does, a single
+    /// [`NoMatchingOneOf`] error is reported for `path`, carrying the errors of the possibility
+    /// which came closest to matching. A possibility is considered closer if the value has the
+    /// JSON type it expects, and then if fewer errors were found.
+    ///
+    /// [`NoMatchingOneOf`]: InstanceValidationReason::NoMatchingOneOf
+    fn validate_value_at(
+        &self,
+        value: &serde_json::Value,
+        path: &JsonPointer,
+        provider: &impl OntologyTypeProvider,
+        errors: &mut Vec<InstanceValidationError>,
+    ) {
+        let mut closest: Option<(usize, Vec<InstanceValidationError>)> = None;
+
+        for (index, possibility) in self.one_of().iter().enumerate() {
+            let mut possibility_errors = Vec::new();
+            possibility.validate_value_at(value, path, provider, &mut possibility_errors);
+            if possibility_errors.is_empty() {
+                return;
+            }
+
+            let is_closer = closest.as_ref().is_none_or(|(_, closest_errors)| {
+                distance(path, &possibility_errors) < distance(path, closest_errors)
+            });
+            if is_closer {
+                closest = Some((index, possibility_errors));
+            }
+        }
+
+        if let Some((closest, closest_errors)) = closest {
+            errors.push(InstanceValidationError::new(
+                path.clone(),
+                InstanceValidationReason::NoMatchingOneOf {
+                    closest,
+                    errors: closest_errors,
+                },
+            ));
+        }
+    }
+}
+
+/// Ranks how far off a value was from a possibility given the `errors` found for it.
+///
+/// A mismatching JSON type at `path` itself outweighs any number of errors further down.
+fn distance(path: &JsonPointer, errors: &[InstanceValidationError]) -> (bool, usize) {
+    let is_type_mismatch = errors.iter().any(|error| {
+        &error.path == path && matches!(error.reason, InstanceValidationReason::InvalidType { .. })
+    });
+    (is_type_mismatch, errors.len())
+}
+
+impl PropertyValues {
+    fn validate_value_at(
+        &self,
+        value: &serde_json::Value,
+        path: &JsonPointer,
+        provider: &impl OntologyTypeProvider,
+        errors: &mut Vec<InstanceValidationError>,
+    ) {
+        match self {
+            Self::DataTypeReference(reference) => match provider.data_type(reference) {
+                Some(data_type) => data_type.validate_value_at(value, path, errors),
+                None => errors.push(InstanceValidationError::new(
+                    path.clone(),
+                    InstanceValidationReason::UnresolvedDataType(reference.url().clone()),
+                )),
+            },
+            Self::PropertyTypeObject(object) => {
+                object.validate_value_at(value, path, provider, errors);
+            }
+            Self::ArrayOfPropertyValues(array) => {
+                if let Some(items) = array.validate_value_at(value, path, errors) {
+                    for (index, item) in items.iter().enumerate() {
+                        array
+                            .items()
+                            .validate_value_at(item, &path.join(index), provider, errors);
+                    }
+                }
+            }
+        }
+    }
+}
+
+impl<const MIN: usize> Object<ValueOrArray<PropertyTypeReference>, MIN> {
+    /// Validates that `value` is an object whose keys are all defined in this object, and that
+    /// every required key is present.
+    ///
+    /// The value of each property is validated against the referenced property type, which is
+    /// looked up through `provider`.
+    pub(crate) fn validate_value_at(
+        &self,
+        value: &serde_json::Value,
+        path: &JsonPointer,
+        provider: &impl OntologyTypeProvider,
+        errors: &mut Vec<InstanceValidationError>,
+    ) {
+        let Some(object) = value.as_object() else {
+            errors.push(InstanceValidationError::new(
+                path.clone(),
+                InstanceValidationReason::InvalidType {
+                    expected: JsonType::Object,
+                    actual: JsonType::of(value),
+                },
+            ));
+            return;
+        };
+
+        for (key, property_value) in object {
+            let property_path = path.join(key);
+            let Ok(base_url) = BaseUrl::new(key.clone()) else {
+                errors.push(InstanceValidationError::new(
+                    property_path,
+                    InstanceValidationReason::InvalidPropertyKey(key.clone()),
+                ));
+                continue;
+            };
+
+            match self.properties().get(&base_url) {
+                Some(ValueOrArray::Value(reference)) => {
+                    reference.validate_value_at(property_value, &property_path, provider, errors);
+                }
+                Some(ValueOrArray::Array(array)) => {
+                    if let Some(items) =
+                        array.validate_value_at(property_value, &property_path, errors)
+                    {
+                        for (index, item) in items.iter().enumerate() {
+                            array.items().validate_value_at(
+                                item,
+                                &property_path.join(index),
+                                provider,
+                                errors,
+                            );
+                        }
+                    }
+                }
+                None => errors.push(InstanceValidationError::new(
+                    property_path,
+                    InstanceValidationReason::UnexpectedProperty(base_url),
+                )),
+            }
+        }
+
+        for required in self.required() {
+            if !object.contains_key(required.as_str()) {
+                errors.push(InstanceValidationError::new(
+                    path.clone(),
+                    InstanceValidationReason::MissingRequiredProperty(required.clone()),
+                ));
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+    use crate::{
+        raw,
+        utils::tests::{check_serialization_from_str, TestOntology},
+    };
+
+    fn property_type(input: &str) -> PropertyType {
+        check_serialization_from_str::<PropertyType, raw::PropertyType>(input, None)
+    }
+
+    #[test]
+    fn data_type_possibilities() {
+        let user_id = property_type(crate::test_data::property_type::USER_ID_V2);
+        let ontology = TestOntology::new();
+
+        user_id
+            .validate_value(&json!("alice"), &ontology)
+            .expect("validation failed");
+        user_id
+            .validate_value(&json!(42), &ontology)
+            .expect("validation failed");
+        assert_eq!(
+            user_id.validate_value(&json!(true), &ontology),
+            Err(vec![InstanceValidationError::new(
+                JsonPointer::root(),
+                InstanceValidationReason::NoMatchingOneOf {
+                    closest: 0,
+                    errors: vec![InstanceValidationError::new(
+                        JsonPointer::root(),
+                        InstanceValidationReason::InvalidType {
+                            expected: JsonType::String,
+                            actual: JsonType::Boolean,
+                        },
+                    )],
+                },
+            )])
+        );
+    }
+
+    #[test]
+    fn closest_possibility() {
+        let contrived = property_type(crate::test_data::property_type::CONTRIVED_PROPERTY_V1);
+        let ontology = TestOntology::new();
+
+        contrived
+            .validate_value(&json!(1), &ontology)
+            .expect("validation failed");
+        contrived
+            .validate_value(&json!([1, 2]), &ontology)
+            .expect("validation failed");
+        assert_eq!(
+            contrived.validate_value(&json!([1, "2", 3, 4, 5]), &ontology),
+            Err(vec![InstanceValidationError::new(
+                JsonPointer::root(),
+                InstanceValidationReason::NoMatchingOneOf {
+                    closest: 1,
+                    errors: vec![
+                        InstanceValidationError::new(
+                            JsonPointer::root(),
+                            InstanceValidationReason::TooManyItems {
+                                actual: 5,
+                                max_items: 4,
+                            },
+                        ),
+                        InstanceValidationError::new(
+                            JsonPointer::root().join(1),
+                            InstanceValidationReason::NoMatchingOneOf {
+                                closest: 0,
+                                errors: vec![InstanceValidationError::new(
+                                    JsonPointer::root().join(1),
+                                    InstanceValidationReason::InvalidType {
+                                        expected: JsonType::Number,
+                                        actual: JsonType::String,
+                                    },
+                                )],
+                            },
+                        ),
+                    ],
+                },
+            )])
+        );
+    }
+
+    #[test]
+    fn data_type_constraints() {
+        let mut ontology = TestOntology::new();
+        ontology.add_data_type(
+            &json!({
+                "$schema": "https://blockprotocol.org/types/modules/graph/0.3/schema/data-type",
+                "kind": "dataType",
+                "$id": "https://example.com/data-type/percentage/v/1",
+                "title": "Percentage",
+                "type": "number",
+                "minimum": 0,
+                "maximum": 100
+            })
+            .to_string(),
+        );
+        let percentage = property_type(
+            &json!({
+                "$schema": "https://blockprotocol.org/types/modules/graph/0.3/schema/property-type",
+                "kind": "propertyType",
+                "$id": "https://example.com/property-type/percentage/v/1",
+                "title": "Percentage",
+                "oneOf": [
+                    { "$ref": "https://blockprotocol.org/@blockprotocol/types/data-type/text/v/1" },
+                    { "$ref": "https://example.com/data-type/percentage/v/1" }
+                ]
+            })
+            .to_string(),
+        );
+
+        percentage
+            .validate_value(&json!(50), &ontology)
+            .expect("validation failed");
+        assert_eq!(
+            percentage.validate_value(&json!(150), &ontology),
+            Err(vec![InstanceValidationError::new(
+                JsonPointer::root(),
+                InstanceValidationReason::NoMatchingOneOf {
+                    closest: 1,
+                    errors: vec![InstanceValidationError::new(
+                        JsonPointer::root(),
+                        InstanceValidationReason::AboveMaximum {
+                            actual: 150.into(),
+                            maximum: 100.into(),
+                        },
+                    )],
+                },
+            )])
+        );
+    }
+
+    #[test]
+    fn object_possibility() {
+        let contact_information =
+            property_type(crate::test_data::property_type::CONTACT_INFORMATION_V1);
+
+        assert_eq!(
+            contact_information.validate_value(&json!({}), &TestOntology::new()),
+            Err(vec![InstanceValidationError::new(
+                JsonPointer::root(),
+                InstanceValidationReason::NoMatchingOneOf {
+                    closest: 0,
+                    errors: vec![InstanceValidationError::new(
+                        JsonPointer::root(),
+                        InstanceValidationReason::MissingRequiredProperty(
+                            BaseUrl::new(
+                                "https://blockprotocol.org/@blockprotocol/types/property-type/email/"
+                                    .to_owned()
+                            )
+                            .expect("invalid base URL")
+                        ),
+                    )],
+                },
+            )])
+        );
+    }
+}
@@ -0,0 +1,21 @@
+use crate::{
+    DataType, DataTypeReference, EntityType, EntityTypeReference, PropertyType,
+    PropertyTypeReference,
+};
+
+/// Looks up the types referenced from other types.
+///
+/// Validating an instance against a type requires the types it references, e.g. the
+/// [`PropertyType`]s used by an [`EntityType`], to be available. Implementors return `None` if
+/// they don't know about the requested type.
+///
+/// [`OntologyStore`] is an in-memory implementation of this trait.
+///
+/// [`OntologyStore`]: crate::OntologyStore
+pub trait OntologyTypeProvider {
+    fn data_type(&self, reference: &DataTypeReference) -> Option<&DataType>;
+
+    fn property_type(&self, reference: &PropertyTypeReference) -> Option<&PropertyType>;
+
+    fn entity_type(&self, reference: &EntityTypeReference) -> Option<&EntityType>;
+}
@@ -0,0 +1,192 @@
+use std::{
+    collections::{hash_map::Entry, HashMap},
+    fs,
+    path::{Path, PathBuf},
+};
+
+use crate::{url::VersionedUrl, ResolveTypeError, TypeResolver};
+
+/// A [`TypeResolver`] reading types from a local directory of JSON files.
+///
+/// The directory is scanned recursively for files with a `.json` extension when the resolver is
+/// created, and each file is indexed by the `$id` of the schema it contains. Files which don't
+/// contain a JSON object with a valid versioned URL as `$id` are ignored. Symbolic links to files
+/// are followed, symbolic links to directories are skipped so the scan can't loop.
+#[derive(Debug, Clone)]
+pub struct DirectoryResolver {
+    files: HashMap<VersionedUrl, PathBuf>,
+}
+
+fn io_error(path: &Path, error: &std::io::Error) -> ResolveTypeError {
+    ResolveTypeError::Io(format!("{}: {error}", path.display()))
+}
+
+fn read_schema(path: &Path) -> Result<serde_json::Value, ResolveTypeError> {
+    let contents = fs::read_to_string(path).map_err(|error| io_error(path, &error))?;
+    serde_json::from_str(&contents)
+        .map_err(|error| ResolveTypeError::InvalidJson(format!("{}: {error}", path.display())))
+}
+
+impl DirectoryResolver {
+    /// Creates a new `DirectoryResolver` for the types inside `directory`.
+    ///
+    /// # Errors
+    ///
+    /// - [`Io`] if the directory or one of its files could not be read
+    /// - [`InvalidJson`] if a `.json` file does not contain valid JSON
+    /// - [`DuplicateId`] if two files contain a schema with the same `$id`
+    ///
+    /// [`Io`]: ResolveTypeError::Io
+    /// [`InvalidJson`]: ResolveTypeError::InvalidJson
+    /// [`DuplicateId`]: ResolveTypeError::DuplicateId
+    pub fn new(directory: impl AsRef<Path>) -> Result<Self, ResolveTypeError> {
+        let mut resolver = Self {
+            files: HashMap::new(),
+        };
+        resolver.scan(directory.as_ref())?;
+        Ok(resolver)
+    }
+
+    fn scan(&mut self, directory: &Path) -> Result<(), ResolveTypeError> {
+        for entry in fs::read_dir(directory).map_err(|error| io_error(directory, &error))? {
+            let entry = entry.map_err(|error| io_error(directory, &error))?;
+            let path = entry.path();
+
+            if entry
+                .file_type()
+                .map_err(|error| io_error(&path, &error))?
+                .is_dir()
+            {
+                self.scan(&path)?;
+            } else if path.is_file()
+                && path
+                    .extension()
+                    .is_some_and(|extension| extension == "json")
+            {
+                let id = read_schema(&path)?
+                    .get("$id")
+                    .and_then(serde_json::Value::as_str)
+                    .and_then(|id| id.parse::<VersionedUrl>().ok());
+                if let Some(id) = id {
+                    match self.files.entry(id) {
+                        Entry::Occupied(entry) => {
+                            return Err(ResolveTypeError::DuplicateId {
+                                id: entry.key().clone(),
+                                first: entry.get().clone(),
+                                second: path,
+                            });
+                        }
+                        Entry::Vacant(entry) => {
+                            entry.insert(path);
+                        }
+                    }
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Returns the URLs of all types found in the directory.
+    pub fn urls(&self) -> impl Iterator<Item = &VersionedUrl> {
+        self.files.keys()
+    }
+}
+
+impl TypeResolver for DirectoryResolver {
+    fn fetch(&self, url: &VersionedUrl) -> Result<serde_json::Value, ResolveTypeError> {
+        let path = self
+            .files
+            .get(url)
+            .ok_or_else(|| ResolveTypeError::NotFound(url.clone()))?;
+        read_schema(path)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{test_data, utils::tests::url};
+
+    /// Creates an empty directory for the test `name` in the temporary directory.
+    fn temp_dir(name: &str) -> PathBuf {
+        let directory =
+            std::env::temp_dir().join(format!("type-system-{name}-{}", std::process::id()));
+        if directory.exists() {
+            fs::remove_dir_all(&directory).expect("failed to remove directory");
+        }
+        fs::create_dir_all(&directory).expect("failed to create directory");
+        directory
+    }
+
+    #[test]
+    fn scan_test_data() {
+        let resolver = DirectoryResolver::new(concat!(env!("CARGO_MANIFEST_DIR"), "/tests/data"))
+            .expect("failed to scan directory");
+
+        let person = url("https://blockprotocol.org/@alice/types/entity-type/person/v/1");
+        assert_eq!(
+            resolver
+                .resolve_entity_type(&person)
+                .expect("failed to resolve entity type")
+                .id(),
+            &person
+        );
+        resolver
+            .resolve_property_type(&url(
+                "https://blockprotocol.org/@alice/types/property-type/user-id/v/2",
+            ))
+            .expect("failed to resolve property type");
+        resolver
+            .resolve_data_type(&url(
+                "https://blockprotocol.org/@blockprotocol/types/data-type/text/v/1",
+            ))
+            .expect("failed to resolve data type");
+
+        let missing = url("https://blockprotocol.org/@alice/types/entity-type/person/v/2");
+        assert_eq!(
+            resolver.fetch(&missing),
+            Err(ResolveTypeError::NotFound(missing))
+        );
+    }
+
+    #[test]
+    fn missing_directory() {
+        assert!(matches!(
+            DirectoryResolver::new(concat!(env!("CARGO_MANIFEST_DIR"), "/tests/missing")),
+            Err(ResolveTypeError::Io(_))
+        ));
+    }
+
+    #[test]
+    fn duplicate_id() {
+        let directory = temp_dir("duplicate-id");
+        for file in ["a.json", "b.json"] {
+            fs::write(directory.join(file), test_data::data_type::TEXT_V1)
+                .expect("failed to write file");
+        }
+
+        let result = DirectoryResolver::new(&directory);
+        fs::remove_dir_all(&directory).expect("failed to remove directory");
+        assert!(matches!(
+            result,
+            Err(ResolveTypeError::DuplicateId { id, .. })
+                if id == url("https://blockprotocol.org/@blockprotocol/types/data-type/text/v/1")
+        ));
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn symlinked_directory() {
+        let directory = temp_dir("symlinked-directory");
+        fs::write(directory.join("text.json"), test_data::data_type::TEXT_V1)
+            .expect("failed to write file");
+        fs::create_dir_all(directory.join("nested")).expect("failed to create directory");
+        std::os::unix::fs::symlink(&directory, directory.join("nested/loop"))
+            .expect("failed to create symlink");
+
+        let result = DirectoryResolver::new(&directory);
+        fs::remove_dir_all(&directory).expect("failed to remove directory");
+        assert_eq!(result.expect("failed to scan directory").urls().count(), 1);
+    }
+}
@@ -0,0 +1,39 @@
+use std::path::PathBuf;
+
+use thiserror::Error;
+
+use crate::{url::VersionedUrl, ParseDataTypeError, ParseEntityTypeError, ParsePropertyTypeError};
+
+#[derive(Debug, PartialEq, Eq, Error)]
+pub enum ResolveTypeError {
+    #[error("the type `{0}` could not be found")]
+    NotFound(VersionedUrl),
+    #[error("failed to read type: `{0}`")]
+    Io(String),
+    #[error("failed to fetch type: `{0}`")]
+    Http(String),
+    #[error("error in JSON: `{0}`")]
+    InvalidJson(String),
+    #[error(
+        "the type `{id}` is defined by both `{}` and `{}`",
+        first.display(),
+        second.display()
+    )]
+    DuplicateId {
+        id: VersionedUrl,
+        first: PathBuf,
+        second: PathBuf,
+    },
+    #[error("expected the type `{expected}` but received `{actual}`")]
+    IdMismatch {
+        expected: VersionedUrl,
+        actual: VersionedUrl,
+    },
+    // Boxed as the parse errors carry the location of the failing node
+    #[error("invalid data type: `{0}`")]
+    InvalidDataType(Box<ParseDataTypeError>),
+    #[error("invalid property type: `{0}`")]
+    InvalidPropertyType(Box<ParsePropertyTypeError>),
+    #[error("invalid entity type: `{0}`")]
+    InvalidEntityType(Box<ParseEntityTypeError>),
+}
@@ -0,0 +1,157 @@
+use crate::{url::VersionedUrl, ResolveTypeError, TypeResolver};
+
+/// A [`TypeResolver`] fetching types from the web by requesting their `$id`.
+///
+/// The types are requested with an `Accept: application/json` header, so that servers like
+/// `blockprotocol.org` return the schema instead of a rendered page.
+#[derive(Debug, Clone)]
+pub struct HttpResolver {
+    agent: ureq::Agent,
+}
+
+impl HttpResolver {
+    /// Creates a new `HttpResolver` with a default HTTP client.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::with_agent(ureq::Agent::new())
+    }
+
+    /// Creates a new `HttpResolver` sending its requests with `agent`.
+    ///
+    /// This allows configuring e.g. timeouts or proxies of the underlying HTTP client.
+    #[must_use]
+    pub const fn with_agent(agent: ureq::Agent) -> Self {
+        Self { agent }
+    }
+}
+
+impl Default for HttpResolver {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl TypeResolver for HttpResolver {
+    fn fetch(&self, url: &VersionedUrl) -> Result<serde_json::Value, ResolveTypeError> {
+        let response = self
+            .agent
+            .get(&url.to_string())
+            .set("Accept", "application/json")
+            .call()
+            .map_err(|error| match error {
+                ureq::Error::Status(404 | 410, _) => ResolveTypeError::NotFound(url.clone()),
+                error => ResolveTypeError::Http(error.to_string()),
+            })?;
+
+        let body = response
+            .into_string()
+            .map_err(|error| ResolveTypeError::Http(error.to_string()))?;
+        serde_json::from_str(&body)
+            .map_err(|error| ResolveTypeError::InvalidJson(error.to_string()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        io::{BufRead, BufReader, Write},
+        net::TcpListener,
+        str::FromStr,
+        thread,
+    };
+
+    use serde_json::json;
+
+    use super::*;
+
+    /// Starts a server on a random local port which answers `requests` requests.
+    ///
+    /// The server responds to requests for the path of `/data-type/text/v/1` with a data type
+    /// having the respective `$id`, and with a `404` to every other path.
+    fn stub_server(requests: usize) -> (String, thread::JoinHandle<Vec<String>>) {
+        let listener = TcpListener::bind("127.0.0.1:0").expect("failed to bind stub server");
+        let origin = format!(
+            "http://{}",
+            listener.local_addr().expect("failed to get address")
+        );
+
+        let data_type = json!({
+            "$schema": "https://blockprotocol.org/types/modules/graph/0.3/schema/data-type",
+            "kind": "dataType",
+            "$id": format!("{origin}/data-type/text/v/1"),
+            "title": "Text",
+            "type": "string"
+        })
+        .to_string();
+
+        let handle = thread::spawn(move || {
+            let mut accept_headers = Vec::new();
+            for stream in listener.incoming().take(requests) {
+                let mut stream = stream.expect("failed to accept connection");
+                let mut reader = BufReader::new(&stream);
+
+                let mut request_line = String::new();
+                reader
+                    .read_line(&mut request_line)
+                    .expect("failed to read request");
+                loop {
+                    let mut header = String::new();
+                    reader
+                        .read_line(&mut header)
+                        .expect("failed to read header");
+                    if header.trim().is_empty() {
+                        break;
+                    }
+                    if let Some(accept) = header.to_lowercase().strip_prefix("accept:") {
+                        accept_headers.push(accept.trim().to_owned());
+                    }
+                }
+
+                let response = if request_line.starts_with("GET /data-type/text/v/1 ") {
+                    format!(
+                        "HTTP/1.1 200 OK\r\nContent-Type: application/json\r\nContent-Length: \
+                         {}\r\nConnection: close\r\n\r\n{data_type}",
+                        data_type.len()
+                    )
+                } else {
+                    "HTTP/1.1 404 Not Found\r\nContent-Length: 0\r\nConnection: close\r\n\r\n"
+                        .to_owned()
+                };
+                stream
+                    .write_all(response.as_bytes())
+                    .expect("failed to write response");
+            }
+            accept_headers
+        });
+
+        (origin, handle)
+    }
+
+    #[test]
+    fn fetch() {
+        let (origin, server) = stub_server(2);
+        let resolver = HttpResolver::new();
+
+        let text = VersionedUrl::from_str(&format!("{origin}/data-type/text/v/1"))
+            .expect("invalid versioned URL");
+        assert_eq!(
+            resolver
+                .resolve_data_type(&text)
+                .expect("failed to resolve data type")
+                .id(),
+            &text
+        );
+
+        let missing = VersionedUrl::from_str(&format!("{origin}/data-type/number/v/1"))
+            .expect("invalid versioned URL");
+        assert_eq!(
+            resolver.fetch(&missing),
+            Err(ResolveTypeError::NotFound(missing))
+        );
+
+        assert_eq!(server.join().expect("stub server panicked"), [
+            "application/json",
+            "application/json"
+        ]);
+    }
+}
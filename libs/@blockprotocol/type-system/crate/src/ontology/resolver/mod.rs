@@ -0,0 +1,239 @@
+#[cfg(not(target_arch = "wasm32"))]
+mod directory;
+mod error;
+#[cfg(all(not(target_arch = "wasm32"), feature = "http"))]
+mod http;
+
+use std::{
+    collections::HashMap,
+    sync::{Mutex, PoisonError},
+};
+
+#[cfg(not(target_arch = "wasm32"))]
+pub use directory::DirectoryResolver;
+pub use error::ResolveTypeError;
+#[cfg(all(not(target_arch = "wasm32"), feature = "http"))]
+pub use http::HttpResolver;
+
+use crate::{raw, url::VersionedUrl, DataType, EntityType, PropertyType};
+
+/// Parses `schema` into the type `T` through its [`raw`] representation `R`.
+fn parse<R, T>(
+    url: &VersionedUrl,
+    schema: serde_json::Value,
+    into_error: impl FnOnce(T::Error) -> ResolveTypeError,
+    id: impl FnOnce(&T) -> &VersionedUrl,
+) -> Result<T, ResolveTypeError>
+where
+    R: serde::de::DeserializeOwned,
+    T: TryFrom<R>,
+{
+    let repr = serde_json::from_value::<R>(schema)
+        .map_err(|error| ResolveTypeError::InvalidJson(error.to_string()))?;
+    let resolved = T::try_from(repr).map_err(into_error)?;
+
+    let id = id(&resolved);
+    if id == url {
+        Ok(resolved)
+    } else {
+        Err(ResolveTypeError::IdMismatch {
+            expected: url.clone(),
+            actual: id.clone(),
+        })
+    }
+}
+
+/// Fetches the schemas of types by their [`VersionedUrl`].
+///
+/// Types only reference each other by URL, implementors of this trait retrieve the schema
+/// identified by such a URL, e.g. from the local filesystem with [`DirectoryResolver`] or from the
+/// web with `HttpResolver`, which requires the `http` feature. Wrapping a resolver in a
+/// [`CachingResolver`] avoids fetching the same type twice.
+///
+/// The `resolve_*` methods parse the fetched schema and ensure its `$id` matches the requested
+/// URL.
+pub trait TypeResolver {
+    /// Fetches the JSON schema of the type identified by `url`.
+    ///
+    /// # Errors
+    ///
+    /// - [`NotFound`] if the type does not exist
+    /// - [`ResolveTypeError`] if the schema could not be retrieved
+    ///
+    /// [`NotFound`]: ResolveTypeError::NotFound
+    fn fetch(&self, url: &VersionedUrl) -> Result<serde_json::Value, ResolveTypeError>;
+
+    /// Fetches and parses the [`DataType`] identified by `url`.
+    ///
+    /// # Errors
+    ///
+    /// - [`ResolveTypeError`] if the schema could not be fetched
+    /// - [`InvalidDataType`] if the schema is not a valid data type
+    /// - [`IdMismatch`] if the `$id` of the schema is not `url`
+    ///
+    /// [`InvalidDataType`]: ResolveTypeError::InvalidDataType
+    /// [`IdMismatch`]: ResolveTypeError::IdMismatch
+    fn resolve_data_type(&self, url: &VersionedUrl) -> Result<DataType, ResolveTypeError> {
+        parse::<raw::DataType, _>(
+            url,
+            self.fetch(url)?,
+            |error| ResolveTypeError::InvalidDataType(Box::new(error)),
+            DataType::id,
+        )
+    }
+
+    /// Fetches and parses the [`PropertyType`] identified by `url`.
+    ///
+    /// # Errors
+    ///
+    /// - [`ResolveTypeError`] if the schema could not be fetched
+    /// - [`InvalidPropertyType`] if the schema is not a valid property type
+    /// - [`IdMismatch`] if the `$id` of the schema is not `url`
+    ///
+    /// [`InvalidPropertyType`]: ResolveTypeError::InvalidPropertyType
+    /// [`IdMismatch`]: ResolveTypeError::IdMismatch
+    fn resolve_property_type(&self, url: &VersionedUrl) -> Result<PropertyType, ResolveTypeError> {
+        parse::<raw::PropertyType, _>(
+            url,
+            self.fetch(url)?,
+            |error| ResolveTypeError::InvalidPropertyType(Box::new(error)),
+            PropertyType::id,
+        )
+    }
+
+    /// Fetches and parses the [`EntityType`] identified by `url`.
+    ///
+    /// # Errors
+    ///
+    /// - [`ResolveTypeError`] if the schema could not be fetched
+    /// - [`InvalidEntityType`] if the schema is not a valid entity type
+    /// - [`IdMismatch`] if the `$id` of the schema is not `url`
+    ///
+    /// [`InvalidEntityType`]: ResolveTypeError::InvalidEntityType
+    /// [`IdMismatch`]: ResolveTypeError::IdMismatch
+    fn resolve_entity_type(&self, url: &VersionedUrl) -> Result<EntityType, ResolveTypeError> {
+        parse::<raw::EntityType, _>(
+            url,
+            self.fetch(url)?,
+            |error| ResolveTypeError::InvalidEntityType(Box::new(error)),
+            EntityType::id,
+        )
+    }
+}
+
+impl<R: TypeResolver + ?Sized> TypeResolver for &R {
+    fn fetch(&self, url: &VersionedUrl) -> Result<serde_json::Value, ResolveTypeError> {
+        (**self).fetch(url)
+    }
+}
+
+/// A [`TypeResolver`] which remembers every schema fetched by the wrapped resolver.
+///
+/// Types are immutable, so a cached schema never becomes stale. Failed fetches are not cached.
+#[derive(Debug)]
+pub struct CachingResolver<R> {
+    resolver: R,
+    cache: Mutex<HashMap<VersionedUrl, serde_json::Value>>,
+}
+
+impl<R> CachingResolver<R> {
+    /// Creates a new `CachingResolver` wrapping `resolver`.
+    #[must_use]
+    pub fn new(resolver: R) -> Self {
+        Self {
+            resolver,
+            cache: Mutex::new(HashMap::new()),
+        }
+    }
+
+    #[must_use]
+    pub const fn resolver(&self) -> &R {
+        &self.resolver
+    }
+
+    #[must_use]
+    pub fn into_inner(self) -> R {
+        self.resolver
+    }
+}
+
+impl<R: TypeResolver> TypeResolver for CachingResolver<R> {
+    fn fetch(&self, url: &VersionedUrl) -> Result<serde_json::Value, ResolveTypeError> {
+        if let Some(schema) = self
+            .cache
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .get(url)
+        {
+            return Ok(schema.clone());
+        }
+
+        // The lock is not held while fetching, so concurrent fetches of the same type may happen
+        let schema = self.resolver.fetch(url)?;
+        self.cache
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .insert(url.clone(), schema.clone());
+        Ok(schema)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::cell::Cell;
+
+    use super::*;
+    use crate::{test_data, utils::tests::url};
+
+    /// Serves the text data type and counts how often it was fetched.
+    #[derive(Default)]
+    struct CountingResolver {
+        fetches: Cell<usize>,
+    }
+
+    impl TypeResolver for CountingResolver {
+        fn fetch(&self, url: &VersionedUrl) -> Result<serde_json::Value, ResolveTypeError> {
+            self.fetches.set(self.fetches.get() + 1);
+            if url.to_string()
+                == "https://blockprotocol.org/@blockprotocol/types/data-type/text/v/1"
+            {
+                Ok(serde_json::from_str(test_data::data_type::TEXT_V1).expect("invalid JSON"))
+            } else {
+                Err(ResolveTypeError::NotFound(url.clone()))
+            }
+        }
+    }
+
+    #[test]
+    fn caching() {
+        let text = url("https://blockprotocol.org/@blockprotocol/types/data-type/text/v/1");
+        let number = url("https://blockprotocol.org/@blockprotocol/types/data-type/number/v/1");
+        let resolver = CachingResolver::new(CountingResolver::default());
+
+        for _ in 0..2 {
+            assert_eq!(
+                resolver
+                    .resolve_data_type(&text)
+                    .expect("failed to resolve data type")
+                    .id(),
+                &text
+            );
+            assert_eq!(
+                resolver.resolve_data_type(&number),
+                Err(ResolveTypeError::NotFound(number.clone()))
+            );
+        }
+
+        assert_eq!(resolver.resolver().fetches.get(), 3);
+    }
+
+    #[test]
+    fn wrong_kind() {
+        let text = url("https://blockprotocol.org/@blockprotocol/types/data-type/text/v/1");
+
+        assert!(matches!(
+            CountingResolver::default().resolve_property_type(&text),
+            Err(ResolveTypeError::InvalidJson(_))
+        ));
+    }
+}
@@ -3,12 +3,26 @@ use thiserror::Error;
 #[cfg(target_arch = "wasm32")]
 use tsify::Tsify;
 
-use crate::url::ParseVersionedUrlError;
+use crate::{url::ParseVersionedUrlError, JsonPointer};
 
 #[cfg_attr(target_arch = "wasm32", derive(Tsify))]
 #[derive(Debug, Deserialize, Serialize, PartialEq, Eq, Error)]
 #[serde(tag = "reason", content = "inner")]
 pub enum ParseAllOfError {
-    #[error("invalid entity type reference: `{0}`")]
-    EntityTypeReferenceError(ParseVersionedUrlError),
+    #[error("invalid entity type reference at `{path}`: `{error}`")]
+    EntityTypeReferenceError {
+        path: JsonPointer,
+        error: ParseVersionedUrlError,
+    },
+}
+
+impl ParseAllOfError {
+    /// Returns the location of the node which failed to parse, relative to the schema containing
+    /// `allOf`.
+    #[must_use]
+    pub const fn path(&self) -> &JsonPointer {
+        match self {
+            Self::EntityTypeReferenceError { path, .. } => path,
+        }
+    }
 }
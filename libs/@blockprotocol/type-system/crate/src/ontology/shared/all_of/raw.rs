@@ -2,7 +2,10 @@ use serde::{Deserialize, Serialize};
 #[cfg(target_arch = "wasm32")]
 use tsify::Tsify;
 
-use crate::{raw, EntityTypeReference, ParseAllOfError};
+use crate::{
+    ontology::shared::parse::{first_error, TryFromRaw},
+    raw, EntityTypeReference, JsonPointer, ParseAllOfError,
+};
 
 #[cfg_attr(target_arch = "wasm32", derive(Tsify))]
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
@@ -17,20 +20,42 @@ pub struct AllOf<T> {
     pub elements: Vec<T>,
 }
 
-impl TryFrom<AllOf<raw::EntityTypeReference>> for super::AllOf<EntityTypeReference> {
+impl TryFromRaw<AllOf<raw::EntityTypeReference>> for super::AllOf<EntityTypeReference> {
     type Error = ParseAllOfError;
 
-    fn try_from(all_of_repr: AllOf<raw::EntityTypeReference>) -> Result<Self, Self::Error> {
+    fn try_from_raw(
+        all_of_repr: AllOf<raw::EntityTypeReference>,
+    ) -> Result<Self, Vec<Self::Error>> {
+        let mut errors = Vec::new();
         let inner = all_of_repr
             .elements
             .into_iter()
-            .map(|ele| {
+            .enumerate()
+            .filter_map(|(index, ele)| {
                 ele.try_into()
-                    .map_err(ParseAllOfError::EntityTypeReferenceError)
+                    .map_err(|error| {
+                        errors.push(ParseAllOfError::EntityTypeReferenceError {
+                            path: JsonPointer::root().join("allOf").join(index).join("$ref"),
+                            error,
+                        });
+                    })
+                    .ok()
             })
-            .collect::<Result<Vec<_>, Self::Error>>()?;
+            .collect::<Vec<_>>();
+
+        if errors.is_empty() {
+            Ok(Self::new(inner))
+        } else {
+            Err(errors)
+        }
+    }
+}
 
-        Ok(Self::new(inner))
+impl TryFrom<AllOf<raw::EntityTypeReference>> for super::AllOf<EntityTypeReference> {
+    type Error = ParseAllOfError;
+
+    fn try_from(all_of_repr: AllOf<raw::EntityTypeReference>) -> Result<Self, Self::Error> {
+        first_error(Self::try_from_raw(all_of_repr))
     }
 }
 
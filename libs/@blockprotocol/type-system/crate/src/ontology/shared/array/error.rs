@@ -3,7 +3,11 @@ use thiserror::Error;
 #[cfg(target_arch = "wasm32")]
 use tsify::Tsify;
 
-use crate::{url::ParseVersionedUrlError, ParseOneOfError};
+use crate::{
+    ontology::shared::json_pointer::{Located, ROOT},
+    url::ParseVersionedUrlError,
+    JsonPointer, ParseOneOfError,
+};
 
 #[cfg_attr(target_arch = "wasm32", derive(Tsify))]
 #[derive(Debug, Deserialize, Serialize, PartialEq, Eq, Error)]
@@ -15,16 +19,59 @@ pub enum ParseOneOfArrayError {
     InvalidJson(String),
 }
 
+impl ParseOneOfArrayError {
+    /// Returns the location of the node which failed to parse, relative to the array.
+    #[must_use]
+    pub fn path(&self) -> &JsonPointer {
+        match self {
+            Self::InvalidItems(error) => error.path(),
+            Self::InvalidJson(_) => &ROOT,
+        }
+    }
+}
+
+impl Located for ParseOneOfArrayError {
+    fn prepend_path(&mut self, prefix: &JsonPointer) {
+        match self {
+            Self::InvalidItems(error) => error.prepend_path(prefix),
+            Self::InvalidJson(_) => {}
+        }
+    }
+}
+
 #[cfg_attr(target_arch = "wasm32", derive(Tsify))]
 #[derive(Debug, Deserialize, Serialize, PartialEq, Eq, Error)]
 #[serde(tag = "reason", content = "inner")]
 pub enum ParsePropertyTypeReferenceArrayError {
-    #[error("invalid property type reference inside items: `{0}`")]
-    InvalidReference(ParseVersionedUrlError),
+    #[error("invalid property type reference inside items at `{path}`: `{error}`")]
+    InvalidReference {
+        path: JsonPointer,
+        error: ParseVersionedUrlError,
+    },
     #[error("error in JSON: `{0}`")]
     InvalidJson(String),
 }
 
+impl ParsePropertyTypeReferenceArrayError {
+    /// Returns the location of the node which failed to parse, relative to the array.
+    #[must_use]
+    pub fn path(&self) -> &JsonPointer {
+        match self {
+            Self::InvalidReference { path, .. } => path,
+            Self::InvalidJson(_) => &ROOT,
+        }
+    }
+}
+
+impl Located for ParsePropertyTypeReferenceArrayError {
+    fn prepend_path(&mut self, prefix: &JsonPointer) {
+        match self {
+            Self::InvalidReference { path, .. } => path.prepend(prefix),
+            Self::InvalidJson(_) => {}
+        }
+    }
+}
+
 #[cfg_attr(target_arch = "wasm32", derive(Tsify))]
 #[derive(Debug, Deserialize, Serialize, PartialEq, Eq, Error)]
 #[serde(tag = "reason", content = "inner")]
@@ -34,3 +81,23 @@ pub enum ParseEntityTypeReferenceArrayError {
     #[error("error in JSON: `{0}`")]
     InvalidJson(String),
 }
+
+impl ParseEntityTypeReferenceArrayError {
+    /// Returns the location of the node which failed to parse, relative to the array.
+    #[must_use]
+    pub fn path(&self) -> &JsonPointer {
+        match self {
+            Self::InvalidReference(error) => error.path(),
+            Self::InvalidJson(_) => &ROOT,
+        }
+    }
+}
+
+impl Located for ParseEntityTypeReferenceArrayError {
+    fn prepend_path(&mut self, prefix: &JsonPointer) {
+        match self {
+            Self::InvalidReference(error) => error.prepend_path(prefix),
+            Self::InvalidJson(_) => {}
+        }
+    }
+}
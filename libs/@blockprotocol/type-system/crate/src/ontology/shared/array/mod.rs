@@ -1,7 +1,14 @@
 pub(crate) mod error;
 pub(in crate::ontology) mod raw;
+pub(crate) mod serialization;
 
-use crate::{url::BaseUrl, ValidateUrl, ValidationError};
+use crate::{
+    ontology::shared::satisfiability::{check_bounds_at, into_result},
+    url::BaseUrl,
+    Change, ChangeKind, Incompatibility, IncompatibilityReason, InstanceValidationError,
+    InstanceValidationReason, JsonPointer, JsonType, Unsatisfiable, UnsatisfiableReason,
+    ValidateUrl, ValidationError,
+};
 
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct Array<T> {
@@ -11,6 +18,9 @@ pub struct Array<T> {
 }
 
 impl<T> Array<T> {
+    /// Creates a new `Array` without validating the bounds, see [`check_satisfiable`].
+    ///
+    /// [`check_satisfiable`]: Self::check_satisfiable
     #[must_use]
     pub const fn new(items: T, min_items: Option<usize>, max_items: Option<usize>) -> Self {
         Self {
@@ -34,6 +44,150 @@ impl<T> Array<T> {
     pub const fn max_items(&self) -> Option<usize> {
         self.max_items
     }
+
+    /// Checks that `value` is an array with a number of items within the bounds of this array.
+    ///
+    /// Every problem found is pushed to `errors`. The items are returned so they can be validated
+    /// against [`items`], unless `value` is not an array at all.
+    ///
+    /// [`items`]: Self::items
+    pub(crate) fn validate_value_at<'v>(
+        &self,
+        value: &'v serde_json::Value,
+        path: &JsonPointer,
+        errors: &mut Vec<InstanceValidationError>,
+    ) -> Option<&'v [serde_json::Value]> {
+        let Some(items) = value.as_array() else {
+            errors.push(InstanceValidationError::new(
+                path.clone(),
+                InstanceValidationReason::InvalidType {
+                    expected: JsonType::Array,
+                    actual: JsonType::of(value),
+                },
+            ));
+            return None;
+        };
+
+        if let Some(min_items) = self.min_items {
+            if items.len() < min_items {
+                errors.push(InstanceValidationError::new(
+                    path.clone(),
+                    InstanceValidationReason::TooFewItems {
+                        actual: items.len(),
+                        min_items,
+                    },
+                ));
+            }
+        }
+        if let Some(max_items) = self.max_items {
+            if items.len() > max_items {
+                errors.push(InstanceValidationError::new(
+                    path.clone(),
+                    InstanceValidationReason::TooManyItems {
+                        actual: items.len(),
+                        max_items,
+                    },
+                ));
+            }
+        }
+
+        Some(items)
+    }
+
+    /// Checks that every number of items allowed by this array is also allowed by `other`.
+    ///
+    /// Every incompatibility found is pushed to `incompatibilities`, the items are not compared.
+    pub(crate) fn check_bounds_compatibility<U>(
+        &self,
+        other: &Array<U>,
+        path: &JsonPointer,
+        incompatibilities: &mut Vec<Incompatibility>,
+    ) {
+        if let Some(other_min_items) = other.min_items {
+            if self.min_items.unwrap_or(0) < other_min_items {
+                incompatibilities.push(Incompatibility::new(
+                    path.join("minItems"),
+                    IncompatibilityReason::MinItemsIncreased {
+                        min_items: self.min_items,
+                        other_min_items,
+                    },
+                ));
+            }
+        }
+        if let Some(other_max_items) = other.max_items {
+            if self
+                .max_items
+                .is_none_or(|max_items| max_items > other_max_items)
+            {
+                incompatibilities.push(Incompatibility::new(
+                    path.join("maxItems"),
+                    IncompatibilityReason::MaxItemsDecreased {
+                        max_items: self.max_items,
+                        other_max_items,
+                    },
+                ));
+            }
+        }
+    }
+
+    /// Pushes the changes of `minItems` and `maxItems` from this array to `other` to `changes`,
+    /// the items are not compared.
+    pub(crate) fn diff_bounds_at<U>(
+        &self,
+        other: &Array<U>,
+        path: &JsonPointer,
+        changes: &mut Vec<Change>,
+    ) {
+        if self.min_items != other.min_items {
+            changes.push(Change::new(
+                path.join("minItems"),
+                ChangeKind::MinItemsChanged {
+                    old: self.min_items,
+                    new: other.min_items,
+                },
+            ));
+        }
+        if self.max_items != other.max_items {
+            changes.push(Change::new(
+                path.join("maxItems"),
+                ChangeKind::MaxItemsChanged {
+                    old: self.max_items,
+                    new: other.max_items,
+                },
+            ));
+        }
+    }
+
+    /// Checks that the bounds of this array allow at least one number of items.
+    ///
+    /// The items are not checked.
+    ///
+    /// # Errors
+    ///
+    /// - [`MinItemsExceedsMaxItems`] if `minItems` is greater than `maxItems`
+    ///
+    /// [`MinItemsExceedsMaxItems`]: UnsatisfiableReason::MinItemsExceedsMaxItems
+    pub fn check_satisfiable(&self) -> Result<(), Vec<Unsatisfiable>> {
+        let mut unsatisfiable = Vec::new();
+        self.check_satisfiable_at(None, &JsonPointer::root(), &mut unsatisfiable);
+        into_result(unsatisfiable)
+    }
+
+    /// Pushes the unsatisfiable bounds of this array to `unsatisfiable`, see [`check_bounds_at`].
+    pub(crate) fn check_satisfiable_at(
+        &self,
+        required: Option<UnsatisfiableReason>,
+        path: &JsonPointer,
+        unsatisfiable: &mut Vec<Unsatisfiable>,
+    ) {
+        check_bounds_at(
+            self.min_items,
+            self.max_items,
+            required,
+            path,
+            unsatisfiable,
+        );
+    }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -102,4 +256,28 @@ mod tests {
             .validate_url(&url_b.base_url) // Try and validate against a different URL
             .expect_err("expected validation against base URL to fail but it didn't");
     }
+
+    #[test]
+    fn satisfiable() {
+        Array::new((), Some(2), Some(2))
+            .check_satisfiable()
+            .expect("array should be satisfiable");
+        Array::new((), None, Some(0))
+            .check_satisfiable()
+            .expect("array should be satisfiable");
+    }
+
+    #[test]
+    fn min_items_exceeds_max_items() {
+        assert_eq!(
+            Array::new((), Some(3), Some(2)).check_satisfiable(),
+            Err(vec![Unsatisfiable::new(
+                JsonPointer::root(),
+                UnsatisfiableReason::MinItemsExceedsMaxItems {
+                    min_items: 3,
+                    max_items: 2,
+                },
+            )])
+        );
+    }
 }
@@ -3,9 +3,13 @@ use serde::{Deserialize, Serialize};
 use tsify::Tsify;
 
 use crate::{
-    raw, EntityTypeReference, OneOf, ParseEntityTypeReferenceArrayError, ParseOneOfArrayError,
-    ParsePropertyTypeObjectError, ParsePropertyTypeReferenceArrayError, PropertyTypeReference,
-    PropertyValues,
+    ontology::shared::{
+        json_pointer::Located,
+        parse::{first_error, map_errors, TryFromRaw},
+    },
+    raw, EntityTypeReference, JsonPointer, OneOf, ParseEntityTypeReferenceArrayError,
+    ParseOneOfArrayError, ParsePropertyTypeObjectError, ParsePropertyTypeReferenceArrayError,
+    PropertyTypeReference, PropertyValues,
 };
 
 /// Will serialize as a constant value `"array"`
@@ -31,51 +35,74 @@ pub struct Array<T> {
     max_items: Option<usize>,
 }
 
-impl TryFrom<Array<raw::OneOf<raw::PropertyValues>>> for super::Array<OneOf<PropertyValues>> {
+impl TryFromRaw<Array<raw::OneOf<raw::PropertyValues>>> for super::Array<OneOf<PropertyValues>> {
     type Error = ParseOneOfArrayError;
 
-    fn try_from(array_repr: Array<raw::OneOf<raw::PropertyValues>>) -> Result<Self, Self::Error> {
+    fn try_from_raw(
+        array_repr: Array<raw::OneOf<raw::PropertyValues>>,
+    ) -> Result<Self, Vec<Self::Error>> {
         Ok(Self {
-            items: array_repr
-                .items
-                .try_into()
-                .map_err(ParseOneOfArrayError::InvalidItems)?,
+            items: map_errors(OneOf::try_from_raw(array_repr.items), |error| {
+                ParseOneOfArrayError::InvalidItems(
+                    error.prefixed(&JsonPointer::root().join("items")),
+                )
+            })?,
             min_items: array_repr.min_items,
             max_items: array_repr.max_items,
         })
     }
 }
 
-impl TryFrom<Array<raw::PropertyTypeReference>> for super::Array<PropertyTypeReference> {
+impl TryFrom<Array<raw::OneOf<raw::PropertyValues>>> for super::Array<OneOf<PropertyValues>> {
+    type Error = ParseOneOfArrayError;
+
+    fn try_from(array_repr: Array<raw::OneOf<raw::PropertyValues>>) -> Result<Self, Self::Error> {
+        first_error(Self::try_from_raw(array_repr))
+    }
+}
+
+impl TryFromRaw<Array<raw::PropertyTypeReference>> for super::Array<PropertyTypeReference> {
     type Error = ParsePropertyTypeReferenceArrayError;
 
-    fn try_from(array_repr: Array<raw::PropertyTypeReference>) -> Result<Self, Self::Error> {
+    fn try_from_raw(
+        array_repr: Array<raw::PropertyTypeReference>,
+    ) -> Result<Self, Vec<Self::Error>> {
         Ok(Self {
-            items: array_repr
-                .items
-                .try_into()
-                .map_err(ParsePropertyTypeReferenceArrayError::InvalidReference)?,
+            items: array_repr.items.try_into().map_err(|error| {
+                vec![ParsePropertyTypeReferenceArrayError::InvalidReference {
+                    path: JsonPointer::root().join("items").join("$ref"),
+                    error,
+                }]
+            })?,
             min_items: array_repr.min_items,
             max_items: array_repr.max_items,
         })
     }
 }
 
-impl TryFrom<Array<raw::MaybeOneOfEntityTypeReference>>
+impl TryFrom<Array<raw::PropertyTypeReference>> for super::Array<PropertyTypeReference> {
+    type Error = ParsePropertyTypeReferenceArrayError;
+
+    fn try_from(array_repr: Array<raw::PropertyTypeReference>) -> Result<Self, Self::Error> {
+        first_error(Self::try_from_raw(array_repr))
+    }
+}
+
+impl TryFromRaw<Array<raw::MaybeOneOfEntityTypeReference>>
     for super::Array<Option<OneOf<EntityTypeReference>>>
 {
     type Error = ParseEntityTypeReferenceArrayError;
 
-    fn try_from(
+    fn try_from_raw(
         array_repr: Array<raw::MaybeOneOfEntityTypeReference>,
-    ) -> Result<Self, Self::Error> {
+    ) -> Result<Self, Vec<Self::Error>> {
         let items = match array_repr.items.into_inner() {
             None => None,
-            Some(one_of) => Some(
-                one_of
-                    .try_into()
-                    .map_err(ParseEntityTypeReferenceArrayError::InvalidReference)?,
-            ),
+            Some(one_of) => Some(map_errors(OneOf::try_from_raw(one_of), |error| {
+                ParseEntityTypeReferenceArrayError::InvalidReference(
+                    error.prefixed(&JsonPointer::root().join("items")),
+                )
+            })?),
         };
 
         Ok(Self {
@@ -86,6 +113,18 @@ impl TryFrom<Array<raw::MaybeOneOfEntityTypeReference>>
     }
 }
 
+impl TryFrom<Array<raw::MaybeOneOfEntityTypeReference>>
+    for super::Array<Option<OneOf<EntityTypeReference>>>
+{
+    type Error = ParseEntityTypeReferenceArrayError;
+
+    fn try_from(
+        array_repr: Array<raw::MaybeOneOfEntityTypeReference>,
+    ) -> Result<Self, Self::Error> {
+        first_error(Self::try_from_raw(array_repr))
+    }
+}
+
 impl<T, R> From<super::Array<T>> for Array<R>
 where
     R: From<T>,
@@ -108,6 +147,29 @@ pub enum ValueOrArray<T> {
     Array(Array<T>),
 }
 
+impl TryFromRaw<ValueOrArray<raw::PropertyTypeReference>>
+    for super::ValueOrArray<PropertyTypeReference>
+{
+    type Error = ParsePropertyTypeObjectError;
+
+    fn try_from_raw(
+        value_or_array_repr: ValueOrArray<raw::PropertyTypeReference>,
+    ) -> Result<Self, Vec<Self::Error>> {
+        Ok(match value_or_array_repr {
+            ValueOrArray::Value(val) => Self::Value(val.try_into().map_err(|error| {
+                vec![ParsePropertyTypeObjectError::InvalidPropertyTypeReference {
+                    path: JsonPointer::root().join("$ref"),
+                    error,
+                }]
+            })?),
+            ValueOrArray::Array(array) => Self::Array(map_errors(
+                super::Array::try_from_raw(array),
+                ParsePropertyTypeObjectError::InvalidArray,
+            )?),
+        })
+    }
+}
+
 impl TryFrom<ValueOrArray<raw::PropertyTypeReference>>
     for super::ValueOrArray<PropertyTypeReference>
 {
@@ -116,17 +178,7 @@ impl TryFrom<ValueOrArray<raw::PropertyTypeReference>>
     fn try_from(
         value_or_array_repr: ValueOrArray<raw::PropertyTypeReference>,
     ) -> Result<Self, Self::Error> {
-        Ok(match value_or_array_repr {
-            ValueOrArray::Value(val) => Self::Value(
-                val.try_into()
-                    .map_err(ParsePropertyTypeObjectError::InvalidPropertyTypeReference)?,
-            ),
-            ValueOrArray::Array(array) => Self::Array(
-                array
-                    .try_into()
-                    .map_err(ParsePropertyTypeObjectError::InvalidArray)?,
-            ),
-        })
+        first_error(Self::try_from_raw(value_or_array_repr))
     }
 }
 
@@ -0,0 +1,264 @@
+use serde::{
+    de::{self, MapAccess},
+    ser::SerializeStruct,
+    Deserialize, Deserializer, Serialize, Serializer,
+};
+
+use crate::{
+    ontology::shared::serialization::{
+        deserialize_map, next_tag, next_value, required, required_tag, serialize_optional_field,
+        FromMap, Key,
+    },
+    Array, PropertyTypeReference, ValueOrArray,
+};
+
+const FIELDS: &[&str] = &["type", "items", "minItems", "maxItems"];
+const VALUE_OR_ARRAY_FIELDS: &[&str] = &["$ref", "type", "items", "minItems", "maxItems"];
+
+/// The keywords of an [`Array`] apart from its `type`, see [`serialization`].
+///
+/// [`serialization`]: crate::ontology::shared::serialization
+#[expect(clippy::option_option)]
+pub(crate) struct ArrayFields<T> {
+    items: Option<T>,
+    // The bounds are optional, so an explicit `null` is deserialized as absent
+    min_items: Option<Option<usize>>,
+    max_items: Option<Option<usize>>,
+}
+
+impl<T> Default for ArrayFields<T> {
+    fn default() -> Self {
+        Self {
+            items: None,
+            min_items: None,
+            max_items: None,
+        }
+    }
+}
+
+impl<'de, T: Deserialize<'de>> ArrayFields<T> {
+    /// Deserializes the next value of `map` if `key` is a keyword of an array.
+    ///
+    /// Returns `false` without consuming the value otherwise.
+    pub(crate) fn next_value<A>(&mut self, key: &str, map: &mut A) -> Result<bool, A::Error>
+    where
+        A: MapAccess<'de>,
+    {
+        match key {
+            "items" => next_value(map, &mut self.items, "items")?,
+            "minItems" => next_value(map, &mut self.min_items, "minItems")?,
+            "maxItems" => next_value(map, &mut self.max_items, "maxItems")?,
+            _ => return Ok(false),
+        }
+        Ok(true)
+    }
+}
+
+impl<T> ArrayFields<T> {
+    /// Returns the first keyword which has been deserialized, if any.
+    pub(crate) const fn first_key(&self) -> Option<&'static str> {
+        if self.items.is_some() {
+            Some("items")
+        } else if self.min_items.is_some() {
+            Some("minItems")
+        } else if self.max_items.is_some() {
+            Some("maxItems")
+        } else {
+            None
+        }
+    }
+
+    pub(crate) fn finish<E>(self) -> Result<Array<T>, E>
+    where
+        E: de::Error,
+    {
+        Ok(Array::new(
+            required(self.items, "items")?,
+            self.min_items.flatten(),
+            self.max_items.flatten(),
+        ))
+    }
+}
+
+impl<T> Array<T> {
+    /// Converts the items of this array with `f`, keeping its bounds.
+    pub(crate) fn map_items<U>(self, f: impl FnOnce(T) -> U) -> Array<U> {
+        Array::new(f(self.items), self.min_items, self.max_items)
+    }
+}
+
+impl<T: Serialize> Serialize for Array<T> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("Array", 4)?;
+        state.serialize_field("type", "array")?;
+        state.serialize_field("items", self.items())?;
+        serialize_optional_field(&mut state, "minItems", self.min_items().as_ref())?;
+        serialize_optional_field(&mut state, "maxItems", self.max_items().as_ref())?;
+        state.end()
+    }
+}
+
+impl<'de, T: Deserialize<'de>> FromMap<'de> for Array<T> {
+    const EXPECTING: &'static str = "an array schema";
+
+    fn from_map<A>(mut map: A) -> Result<Self, A::Error>
+    where
+        A: MapAccess<'de>,
+    {
+        let mut r#type = false;
+        let mut fields = ArrayFields::default();
+        while let Some(key) = map.next_key::<Key>()? {
+            match &*key {
+                "type" => next_tag(&mut map, &mut r#type, "type", "array")?,
+                key => {
+                    if !fields.next_value(key, &mut map)? {
+                        return Err(de::Error::unknown_field(key, FIELDS));
+                    }
+                }
+            }
+        }
+
+        required_tag(r#type, "type")?;
+        fields.finish()
+    }
+}
+
+impl<'de, T: Deserialize<'de>> Deserialize<'de> for Array<T> {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserialize_map(deserializer)
+    }
+}
+
+impl<T: Serialize> Serialize for ValueOrArray<T> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        match self {
+            Self::Value(value) => value.serialize(serializer),
+            Self::Array(array) => array.serialize(serializer),
+        }
+    }
+}
+
+impl<'de> FromMap<'de> for ValueOrArray<PropertyTypeReference> {
+    const EXPECTING: &'static str = "a property type reference or an array of them";
+
+    /// Deserializes a reference if the schema has a `$ref` and an array otherwise.
+    fn from_map<A>(mut map: A) -> Result<Self, A::Error>
+    where
+        A: MapAccess<'de>,
+    {
+        let mut reference = None;
+        let mut r#type = false;
+        let mut array = ArrayFields::default();
+        while let Some(key) = map.next_key::<Key>()? {
+            match &*key {
+                "$ref" => next_value(&mut map, &mut reference, "$ref")?,
+                "type" => next_tag(&mut map, &mut r#type, "type", "array")?,
+                key => {
+                    if !array.next_value(key, &mut map)? {
+                        return Err(de::Error::unknown_field(key, VALUE_OR_ARRAY_FIELDS));
+                    }
+                }
+            }
+        }
+
+        match reference {
+            Some(url) => {
+                if let Some(key) = r#type.then_some("type").or_else(|| array.first_key()) {
+                    return Err(de::Error::unknown_field(key, &["$ref"]));
+                }
+                Ok(Self::Value(PropertyTypeReference::new(url)))
+            }
+            None if r#type => Ok(Self::Array(array.finish()?)),
+            None => Err(de::Error::custom(
+                "expected a property type reference with `$ref` or an array with `\"type\": \
+                 \"array\"`",
+            )),
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for ValueOrArray<PropertyTypeReference> {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserialize_map(deserializer)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    fn value_or_array(
+        value_or_array: serde_json::Value,
+    ) -> Result<ValueOrArray<PropertyTypeReference>, String> {
+        serde_json::from_value(value_or_array).map_err(|error| error.to_string())
+    }
+
+    #[test]
+    fn round_trip() {
+        let input = json!({
+            "type": "array",
+            "items": { "$ref": "https://example.com/property_type/v/1" },
+            "minItems": 1,
+            "maxItems": 2,
+        });
+        let array = value_or_array(input.clone()).expect("failed to deserialize array");
+        assert!(matches!(array, ValueOrArray::Array(_)));
+        assert_eq!(
+            serde_json::to_value(&array).expect("failed to serialize array"),
+            input
+        );
+
+        let input = json!({ "$ref": "https://example.com/property_type/v/1" });
+        let value = value_or_array(input.clone()).expect("failed to deserialize value");
+        assert!(matches!(value, ValueOrArray::Value(_)));
+        assert_eq!(
+            serde_json::to_value(&value).expect("failed to serialize value"),
+            input
+        );
+    }
+
+    #[test]
+    fn mixed_variants() {
+        assert_eq!(
+            value_or_array(json!({
+                "$ref": "https://example.com/property_type/v/1",
+                "minItems": 1,
+            })),
+            Err("unknown field `minItems`, expected `$ref`".to_owned())
+        );
+    }
+
+    #[test]
+    fn unknown_variant() {
+        value_or_array(json!({ "minItems": 1 })).expect_err("deserialized an unknown variant");
+        value_or_array(json!({
+            "type": "object",
+            "items": { "$ref": "https://example.com/property_type/v/1" },
+        }))
+        .expect_err("deserialized an object as array");
+    }
+
+    #[test]
+    fn additional_properties() {
+        serde_json::from_value::<Array<String>>(json!({
+            "type": "array",
+            "items": "A",
+            "additional": 10,
+        }))
+        .expect_err("deserialized an array with an unknown keyword");
+    }
+}
@@ -0,0 +1,122 @@
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+#[cfg(target_arch = "wasm32")]
+use tsify::Tsify;
+
+use crate::{
+    url::{BaseUrl, VersionedUrl},
+    JsonPointer, JsonType,
+};
+
+/// A reason why a type is not compatible with another type, addressed by the location inside the
+/// schema of the type which was checked.
+///
+/// A type `X` is _compatible with_ a type `Y` if every value which is valid for `X` is also valid
+/// for `Y`, as defined in [RFC 0408].
+///
+/// [RFC 0408]: https://github.com/blockprotocol/blockprotocol/blob/main/rfcs/text/0408-versioning-types.md#determining-type-compatibility
+#[cfg_attr(target_arch = "wasm32", derive(Tsify))]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, Error)]
+#[error("incompatible schema at `{path}`: {reason}")]
+pub struct Incompatibility {
+    pub path: JsonPointer,
+    pub reason: IncompatibilityReason,
+}
+
+impl Incompatibility {
+    #[must_use]
+    pub const fn new(path: JsonPointer, reason: IncompatibilityReason) -> Self {
+        Self { path, reason }
+    }
+}
+
+#[cfg_attr(target_arch = "wasm32", derive(Tsify))]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, Error)]
+#[serde(tag = "reason", content = "inner")]
+pub enum IncompatibilityReason {
+    #[error("the base URL `{base_url}` does not match the base URL `{other_base_url}`")]
+    BaseUrlMismatch {
+        base_url: BaseUrl,
+        other_base_url: BaseUrl,
+    },
+    #[error("the data type `{data_type}` is not compatible with `{other_data_type}`")]
+    IncompatibleDataType {
+        data_type: VersionedUrl,
+        other_data_type: VersionedUrl,
+        /// The incompatibilities found, addressed by their location inside the schema of
+        /// `data_type`.
+        incompatibilities: Vec<Incompatibility>,
+    },
+    #[error("the JSON type `{json_type}` is not compatible with `{other_json_type}`")]
+    JsonTypeMismatch {
+        json_type: JsonType,
+        other_json_type: JsonType,
+    },
+    #[error("the other schema constrains `{keyword}` to `{other_value}`")]
+    ConstraintLoosened {
+        keyword: String,
+        #[cfg_attr(target_arch = "wasm32", tsify(type = "any"))]
+        value: Option<serde_json::Value>,
+        #[cfg_attr(target_arch = "wasm32", tsify(type = "any"))]
+        other_value: serde_json::Value,
+    },
+    #[error("the value `{0}` is not valid in the other schema")]
+    ValueNotAllowed(#[cfg_attr(target_arch = "wasm32", tsify(type = "any"))] serde_json::Value),
+    #[error("the property values are of a different kind")]
+    PropertyValuesMismatch,
+    #[error(
+        "the possibility is not compatible with any possibility in `oneOf`, the closest one was \
+         at index {closest}"
+    )]
+    NoCompatibleOneOf {
+        /// The index of the other possibility which came closest to being compatible.
+        closest: usize,
+        /// The incompatibilities found when checking against the closest possibility.
+        incompatibilities: Vec<Incompatibility>,
+    },
+    #[error("the property `{0}` is not defined in the other schema")]
+    MissingProperty(BaseUrl),
+    #[error("the property `{0}` is required in the other schema")]
+    RequiredProperty(BaseUrl),
+    #[error("the value is not an array but the other schema expects an array")]
+    ExpectedArray,
+    #[error("the value is an array but the other schema does not expect an array")]
+    UnexpectedArray,
+    #[error("the other schema requires at least {other_min_items} items")]
+    MinItemsIncreased {
+        min_items: Option<usize>,
+        other_min_items: usize,
+    },
+    #[error("the other schema allows at most {other_max_items} items")]
+    MaxItemsDecreased {
+        max_items: Option<usize>,
+        other_max_items: usize,
+    },
+    #[error("the link `{0}` is not defined in the other schema")]
+    MissingLink(VersionedUrl),
+    #[error("the link `{0}` is required in the other schema")]
+    RequiredLink(VersionedUrl),
+    #[error("the link is ordered but it is not ordered in the other schema")]
+    OrderingRemoved,
+    #[error("the link destination `{0}` is not allowed in the other schema")]
+    MissingLinkDestination(VersionedUrl),
+    #[error("the link destinations are unconstrained but constrained in the other schema")]
+    LinkDestinationsConstrained,
+    #[error("the data type `{0}` could not be resolved")]
+    UnresolvedDataType(VersionedUrl),
+    #[error("the property type `{0}` could not be resolved")]
+    UnresolvedPropertyType(VersionedUrl),
+    #[error("the entity type `{0}` could not be resolved")]
+    UnresolvedEntityType(VersionedUrl),
+}
+
+/// Turns the collected `incompatibilities` into the result of a compatibility check.
+pub(crate) fn into_result(
+    incompatibilities: Vec<Incompatibility>,
+) -> Result<(), Vec<Incompatibility>> {
+    if incompatibilities.is_empty() {
+        Ok(())
+    } else {
+        Err(incompatibilities)
+    }
+}
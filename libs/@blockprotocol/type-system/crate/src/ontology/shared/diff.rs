@@ -0,0 +1,150 @@
+use serde::{Deserialize, Serialize};
+#[cfg(target_arch = "wasm32")]
+use tsify::Tsify;
+
+use crate::{
+    url::{BaseUrl, VersionedUrl},
+    JsonPointer, JsonType,
+};
+
+/// A single difference between two versions of a type, addressed by its location inside the
+/// schema of the types.
+#[cfg_attr(target_arch = "wasm32", derive(Tsify))]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Change {
+    pub path: JsonPointer,
+    pub kind: ChangeKind,
+}
+
+impl Change {
+    #[must_use]
+    pub const fn new(path: JsonPointer, kind: ChangeKind) -> Self {
+        Self { path, kind }
+    }
+
+    #[must_use]
+    pub const fn classification(&self) -> ChangeClassification {
+        self.kind.classification()
+    }
+}
+
+/// Whether a [`Change`] affects which values are valid for a type.
+#[cfg_attr(target_arch = "wasm32", derive(Tsify))]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum ChangeClassification {
+    /// The change affects which values are valid for the type.
+    Constraint,
+    /// The change only affects the meaning or presentation of the type, e.g. its `title`.
+    SemanticAnnotation,
+}
+
+#[cfg_attr(target_arch = "wasm32", derive(Tsify))]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "type", content = "inner")]
+pub enum ChangeKind {
+    TitleChanged {
+        old: String,
+        new: String,
+    },
+    DescriptionChanged {
+        old: Option<String>,
+        new: Option<String>,
+    },
+    ExamplesChanged,
+    /// A keyword which is not interpreted by the type system has changed.
+    AnnotationChanged {
+        keyword: String,
+        #[cfg_attr(target_arch = "wasm32", tsify(type = "any"))]
+        old: Option<serde_json::Value>,
+        #[cfg_attr(target_arch = "wasm32", tsify(type = "any"))]
+        new: Option<serde_json::Value>,
+    },
+    JsonTypeChanged {
+        old: JsonType,
+        new: JsonType,
+    },
+    /// A constraint keyword of a data type, e.g. `minimum`, has changed.
+    ConstraintChanged {
+        keyword: String,
+        #[cfg_attr(target_arch = "wasm32", tsify(type = "any"))]
+        old: Option<serde_json::Value>,
+        #[cfg_attr(target_arch = "wasm32", tsify(type = "any"))]
+        new: Option<serde_json::Value>,
+    },
+    PossibilityAdded,
+    PossibilityRemoved,
+    PossibilityChanged,
+    ParentAdded(VersionedUrl),
+    ParentRemoved(VersionedUrl),
+    PropertyAdded(BaseUrl),
+    PropertyRemoved(BaseUrl),
+    PropertyTypeChanged {
+        old: VersionedUrl,
+        new: VersionedUrl,
+    },
+    /// A value was turned into an array of values.
+    ArrayAdded,
+    /// An array of values was turned into a single value.
+    ArrayRemoved,
+    RequiredAdded(BaseUrl),
+    RequiredRemoved(BaseUrl),
+    MinItemsChanged {
+        old: Option<usize>,
+        new: Option<usize>,
+    },
+    MaxItemsChanged {
+        old: Option<usize>,
+        new: Option<usize>,
+    },
+    LinkAdded(VersionedUrl),
+    LinkRemoved(VersionedUrl),
+    OrderingChanged {
+        ordered: bool,
+    },
+    LinkDestinationAdded(VersionedUrl),
+    LinkDestinationRemoved(VersionedUrl),
+    LinkDestinationsConstrained,
+    LinkDestinationsUnconstrained,
+    RequiredLinkAdded(VersionedUrl),
+    RequiredLinkRemoved(VersionedUrl),
+}
+
+impl ChangeKind {
+    #[must_use]
+    pub const fn classification(&self) -> ChangeClassification {
+        match self {
+            Self::TitleChanged { .. }
+            | Self::DescriptionChanged { .. }
+            | Self::ExamplesChanged
+            | Self::AnnotationChanged { .. } => ChangeClassification::SemanticAnnotation,
+            _ => ChangeClassification::Constraint,
+        }
+    }
+}
+
+/// Pushes the changes of the `title` and `description` shared by all types to `changes`.
+pub(crate) fn diff_annotations(
+    (title, description): (&str, Option<&str>),
+    (other_title, other_description): (&str, Option<&str>),
+    changes: &mut Vec<Change>,
+) {
+    if title != other_title {
+        changes.push(Change::new(
+            JsonPointer::root().join("title"),
+            ChangeKind::TitleChanged {
+                old: title.to_owned(),
+                new: other_title.to_owned(),
+            },
+        ));
+    }
+    if description != other_description {
+        changes.push(Change::new(
+            JsonPointer::root().join("description"),
+            ChangeKind::DescriptionChanged {
+                old: description.map(ToOwned::to_owned),
+                new: other_description.map(ToOwned::to_owned),
+            },
+        ));
+    }
+}
@@ -0,0 +1,108 @@
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+#[cfg(target_arch = "wasm32")]
+use tsify::Tsify;
+
+use crate::{
+    url::{BaseUrl, VersionedUrl},
+    JsonPointer, JsonType,
+};
+
+/// An error found while validating a value against a type, addressed by the location of the
+/// offending value inside the validated instance.
+#[cfg_attr(target_arch = "wasm32", derive(Tsify))]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, Error)]
+#[error("invalid value at `{path}`: {reason}")]
+pub struct InstanceValidationError {
+    pub path: JsonPointer,
+    pub reason: InstanceValidationReason,
+}
+
+impl InstanceValidationError {
+    #[must_use]
+    pub const fn new(path: JsonPointer, reason: InstanceValidationReason) -> Self {
+        Self { path, reason }
+    }
+}
+
+#[cfg_attr(target_arch = "wasm32", derive(Tsify))]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, Error)]
+#[serde(tag = "reason", content = "inner")]
+pub enum InstanceValidationReason {
+    #[error("expected a value of type `{expected}` but received a value of type `{actual}`")]
+    InvalidType {
+        expected: JsonType,
+        actual: JsonType,
+    },
+    #[error("the key `{0}` is not a valid base URL")]
+    InvalidPropertyKey(String),
+    #[error("the property `{0}` is required but missing")]
+    MissingRequiredProperty(BaseUrl),
+    #[error("the property `{0}` is not defined in the schema")]
+    UnexpectedProperty(BaseUrl),
+    #[error("expected at least {min_items} items but received {actual}")]
+    TooFewItems { actual: usize, min_items: usize },
+    #[error("expected at most {max_items} items but received {actual}")]
+    TooManyItems { actual: usize, max_items: usize },
+    #[error("expected a value of at least {minimum} but received {actual}")]
+    BelowMinimum {
+        #[cfg_attr(target_arch = "wasm32", tsify(type = "number"))]
+        actual: serde_json::Number,
+        #[cfg_attr(target_arch = "wasm32", tsify(type = "number"))]
+        minimum: serde_json::Number,
+    },
+    #[error("expected a value of at most {maximum} but received {actual}")]
+    AboveMaximum {
+        #[cfg_attr(target_arch = "wasm32", tsify(type = "number"))]
+        actual: serde_json::Number,
+        #[cfg_attr(target_arch = "wasm32", tsify(type = "number"))]
+        maximum: serde_json::Number,
+    },
+    #[error("expected a multiple of {multiple_of} but received {actual}")]
+    NotMultipleOf {
+        #[cfg_attr(target_arch = "wasm32", tsify(type = "number"))]
+        actual: serde_json::Number,
+        #[cfg_attr(target_arch = "wasm32", tsify(type = "number"))]
+        multiple_of: serde_json::Number,
+    },
+    #[error("expected at least {min_length} characters but received {actual}")]
+    TooShort { actual: usize, min_length: usize },
+    #[error("expected at most {max_length} characters but received {actual}")]
+    TooLong { actual: usize, max_length: usize },
+    #[error("the value does not match the pattern `{pattern}`")]
+    PatternMismatch { pattern: String },
+    #[error("the value is not a valid `{format}`")]
+    InvalidFormat { format: String },
+    #[error("the value is not one of the values in `enum`")]
+    NotInEnum {
+        #[cfg_attr(target_arch = "wasm32", tsify(type = "any[]"))]
+        expected: Vec<serde_json::Value>,
+    },
+    #[error("the value is not equal to `{expected}`")]
+    ConstMismatch {
+        #[cfg_attr(target_arch = "wasm32", tsify(type = "any"))]
+        expected: serde_json::Value,
+    },
+    #[error(
+        "the value did not match any of the possibilities in `oneOf`, the closest one was at \
+         index {closest}"
+    )]
+    NoMatchingOneOf {
+        /// The index of the possibility which came closest to matching the value.
+        closest: usize,
+        /// The errors reported when validating the value against the closest possibility.
+        errors: Vec<InstanceValidationError>,
+    },
+    #[error("the entity is of a link entity type but does not provide `linkData`")]
+    MissingLinkData,
+    #[error("the entity is not of a link entity type but provides `linkData`")]
+    UnexpectedLinkData,
+    #[error("the field `{0}` is required in `linkData` but missing")]
+    MissingLinkDataField(String),
+    #[error("the field `{0}` is not allowed in `linkData`")]
+    UnexpectedLinkDataField(String),
+    #[error("the data type `{0}` could not be resolved")]
+    UnresolvedDataType(VersionedUrl),
+    #[error("the property type `{0}` could not be resolved")]
+    UnresolvedPropertyType(VersionedUrl),
+}
@@ -0,0 +1,154 @@
+use std::{fmt, str::FromStr};
+
+use serde::{de, Deserialize, Deserializer, Serialize, Serializer};
+#[cfg(target_arch = "wasm32")]
+use tsify::Tsify;
+
+/// A [JSON Pointer] identifying a single node inside a JSON document.
+///
+/// The pointer is stored as its list of unescaped reference tokens and is rendered in the usual
+/// `/foo/0/bar` form, escaping `~` and `/` inside tokens.
+///
+/// [JSON Pointer]: https://datatracker.ietf.org/doc/html/rfc6901
+#[cfg_attr(target_arch = "wasm32", derive(Tsify))]
+#[derive(Debug, Default, Clone, PartialEq, Eq, Hash)]
+pub struct JsonPointer(#[cfg_attr(target_arch = "wasm32", tsify(type = "string"))] Vec<String>);
+
+impl JsonPointer {
+    /// Creates a pointer to the root of a document.
+    #[must_use]
+    pub const fn root() -> Self {
+        Self(Vec::new())
+    }
+
+    /// Returns a new pointer with `token` appended to this one.
+    #[must_use]
+    pub fn join(&self, token: impl fmt::Display) -> Self {
+        let mut pointer = self.clone();
+        pointer.push(token);
+        pointer
+    }
+
+    pub fn push(&mut self, token: impl fmt::Display) {
+        self.0.push(token.to_string());
+    }
+
+    /// Inserts the tokens of `prefix` in front of the tokens of this pointer, e.g. to resolve a
+    /// pointer relative to a nested schema against its parent.
+    pub fn prepend(&mut self, prefix: &Self) {
+        self.0.splice(0..0, prefix.0.iter().cloned());
+    }
+
+    #[must_use]
+    pub fn tokens(&self) -> &[String] {
+        &self.0
+    }
+
+    #[must_use]
+    pub const fn is_root(&self) -> bool {
+        self.0.is_empty()
+    }
+}
+
+/// A pointer to the root of a document, for errors which can not be located more precisely.
+pub(crate) static ROOT: JsonPointer = JsonPointer::root();
+
+/// An error which is located by a [`JsonPointer`] relative to the schema it was found in.
+pub(crate) trait Located: Sized {
+    /// Resolves the location of this error against `prefix`, i.e. the location of the schema it
+    /// was found in relative to its parent.
+    fn prepend_path(&mut self, prefix: &JsonPointer);
+
+    #[must_use]
+    fn prefixed(mut self, prefix: &JsonPointer) -> Self {
+        self.prepend_path(prefix);
+        self
+    }
+}
+
+impl fmt::Display for JsonPointer {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for token in &self.0 {
+            write!(fmt, "/{}", token.replace('~', "~0").replace('/', "~1"))?;
+        }
+        Ok(())
+    }
+}
+
+impl FromStr for JsonPointer {
+    type Err = String;
+
+    fn from_str(pointer: &str) -> Result<Self, Self::Err> {
+        if pointer.is_empty() {
+            return Ok(Self::root());
+        }
+
+        pointer
+            .strip_prefix('/')
+            .ok_or_else(|| format!("JSON Pointer `{pointer}` does not start with `/`"))
+            .map(|tokens| {
+                Self(
+                    tokens
+                        .split('/')
+                        .map(|token| token.replace("~1", "/").replace("~0", "~"))
+                        .collect(),
+                )
+            })
+    }
+}
+
+impl Serialize for JsonPointer {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.to_string().serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for JsonPointer {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(de::Error::custom)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn escaping() {
+        let pointer = JsonPointer::root()
+            .join("https://example.com/property-type/a~b/")
+            .join(3);
+
+        let rendered = pointer.to_string();
+        assert_eq!(rendered, "/https:~1~1example.com~1property-type~1a~0b~1/3");
+        assert_eq!(
+            JsonPointer::from_str(&rendered).expect("failed to parse JSON Pointer"),
+            pointer
+        );
+    }
+
+    #[test]
+    fn prepend() {
+        let mut pointer = JsonPointer::root().join("items").join("$ref");
+        pointer.prepend(&JsonPointer::root().join("properties").join("a"));
+
+        assert_eq!(pointer.to_string(), "/properties/a/items/$ref");
+    }
+
+    #[test]
+    fn root() {
+        assert_eq!(JsonPointer::root().to_string(), "");
+        assert!(JsonPointer::from_str("")
+            .expect("failed to parse JSON Pointer")
+            .is_root());
+        JsonPointer::from_str("foo").expect_err("parsed a JSON Pointer without a leading slash");
+    }
+}
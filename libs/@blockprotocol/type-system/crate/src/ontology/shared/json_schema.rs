@@ -0,0 +1,35 @@
+use thiserror::Error;
+
+use crate::{url::VersionedUrl, Array};
+
+/// The dialect of the JSON Schemas generated from types.
+pub(crate) const JSON_SCHEMA_DIALECT: &str = "https://json-schema.org/draft/2020-12/schema";
+
+#[derive(Debug, PartialEq, Eq, Error)]
+pub enum JsonSchemaError {
+    #[error("the data type `{0}` could not be resolved")]
+    UnresolvedDataType(VersionedUrl),
+    #[error("the property type `{0}` could not be resolved")]
+    UnresolvedPropertyType(VersionedUrl),
+    #[error("the entity type `{0}` could not be resolved")]
+    UnresolvedEntityType(VersionedUrl),
+    #[error("the property type `{0}` references itself and can't be inlined into a JSON Schema")]
+    RecursivePropertyType(VersionedUrl),
+    #[error("the entity type `{0}` inherits from itself")]
+    InheritanceCycle(VersionedUrl),
+}
+
+/// Returns the JSON Schema of an array whose items match `items` and which is bounded like
+/// `array`.
+pub(crate) fn array_schema<T>(items: serde_json::Value, array: &Array<T>) -> serde_json::Value {
+    let mut schema = serde_json::Map::new();
+    schema.insert("type".to_owned(), "array".into());
+    schema.insert("items".to_owned(), items);
+    if let Some(min_items) = array.min_items() {
+        schema.insert("minItems".to_owned(), min_items.into());
+    }
+    if let Some(max_items) = array.max_items() {
+        schema.insert("maxItems".to_owned(), max_items.into());
+    }
+    serde_json::Value::Object(schema)
+}
@@ -0,0 +1,124 @@
+use std::{fmt, str::FromStr};
+
+use serde::{Deserialize, Serialize};
+#[cfg(target_arch = "wasm32")]
+use tsify::Tsify;
+
+/// The primitive types a JSON value can take, as named by the JSON Schema `type` keyword.
+#[cfg_attr(target_arch = "wasm32", derive(Tsify))]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum JsonType {
+    Null,
+    Boolean,
+    Number,
+    Integer,
+    String,
+    Array,
+    Object,
+}
+
+impl JsonType {
+    /// Returns the type of the given value.
+    ///
+    /// JSON itself does not distinguish integers from other numbers, so this never returns
+    /// [`JsonType::Integer`].
+    #[must_use]
+    pub const fn of(value: &serde_json::Value) -> Self {
+        match value {
+            serde_json::Value::Null => Self::Null,
+            serde_json::Value::Bool(_) => Self::Boolean,
+            serde_json::Value::Number(_) => Self::Number,
+            serde_json::Value::String(_) => Self::String,
+            serde_json::Value::Array(_) => Self::Array,
+            serde_json::Value::Object(_) => Self::Object,
+        }
+    }
+
+    /// Checks if the given value is an instance of this type.
+    ///
+    /// A number is an instance of [`JsonType::Integer`] if it has no fractional part.
+    #[must_use]
+    pub fn is_type_of(self, value: &serde_json::Value) -> bool {
+        match (self, value) {
+            (Self::Integer, serde_json::Value::Number(number)) => {
+                number.is_i64()
+                    || number.is_u64()
+                    || number.as_f64().is_some_and(|float| float.fract() == 0.0)
+            }
+            (expected, value) => expected == Self::of(value),
+        }
+    }
+
+    #[must_use]
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::Null => "null",
+            Self::Boolean => "boolean",
+            Self::Number => "number",
+            Self::Integer => "integer",
+            Self::String => "string",
+            Self::Array => "array",
+            Self::Object => "object",
+        }
+    }
+}
+
+impl fmt::Display for JsonType {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt.write_str(self.as_str())
+    }
+}
+
+impl FromStr for JsonType {
+    type Err = String;
+
+    fn from_str(json_type: &str) -> Result<Self, Self::Err> {
+        Ok(match json_type {
+            "null" => Self::Null,
+            "boolean" => Self::Boolean,
+            "number" => Self::Number,
+            "integer" => Self::Integer,
+            "string" => Self::String,
+            "array" => Self::Array,
+            "object" => Self::Object,
+            _ => return Err(json_type.to_owned()),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    #[test]
+    fn integer() {
+        assert!(JsonType::Integer.is_type_of(&json!(1)));
+        assert!(JsonType::Integer.is_type_of(&json!(-1)));
+        assert!(JsonType::Integer.is_type_of(&json!(1.0)));
+        assert!(!JsonType::Integer.is_type_of(&json!(1.5)));
+        assert!(!JsonType::Integer.is_type_of(&json!("1")));
+        assert!(JsonType::Number.is_type_of(&json!(1)));
+    }
+
+    #[test]
+    fn round_trip() {
+        for json_type in [
+            JsonType::Null,
+            JsonType::Boolean,
+            JsonType::Number,
+            JsonType::Integer,
+            JsonType::String,
+            JsonType::Array,
+            JsonType::Object,
+        ] {
+            assert_eq!(JsonType::from_str(json_type.as_str()), Ok(json_type));
+            assert_eq!(
+                serde_json::to_value(json_type).expect("failed to serialize"),
+                json!(json_type.as_str())
+            );
+        }
+    }
+}
@@ -1,5 +1,14 @@
 pub(crate) mod all_of;
 pub(crate) mod array;
+pub(crate) mod compatibility;
+pub(crate) mod diff;
+pub(crate) mod instance;
+pub(crate) mod json_pointer;
+pub(crate) mod json_schema;
+pub(crate) mod json_type;
 pub(crate) mod object;
 pub(crate) mod one_of;
+pub(crate) mod parse;
+pub(crate) mod satisfiability;
+pub(crate) mod serialization;
 pub(crate) mod validate;
@@ -4,24 +4,65 @@ use thiserror::Error;
 use tsify::Tsify;
 
 use crate::{
+    ontology::shared::json_pointer::{Located, ROOT},
     url::{ParseBaseUrlError, ParseVersionedUrlError},
-    ParsePropertyTypeReferenceArrayError, ValidationError,
+    JsonPointer, ParsePropertyTypeReferenceArrayError, ValidationError,
 };
 
 #[cfg_attr(target_arch = "wasm32", derive(Tsify))]
 #[derive(Debug, Deserialize, Serialize, PartialEq, Eq, Error)]
 #[serde(tag = "reason", content = "inner")]
 pub enum ParsePropertyTypeObjectError {
-    #[error("invalid property type reference: `{0}`")]
-    InvalidPropertyTypeReference(ParseVersionedUrlError),
+    #[error("invalid property type reference at `{path}`: `{error}`")]
+    InvalidPropertyTypeReference {
+        path: JsonPointer,
+        error: ParseVersionedUrlError,
+    },
     #[error("invalid array definition: `{0}`")]
     InvalidArray(ParsePropertyTypeReferenceArrayError),
-    #[error("invalid property key: `{0}`")]
-    InvalidPropertyKey(ParseBaseUrlError),
-    #[error("invalid key inside required: `{0}`")]
-    InvalidRequiredKey(ParseBaseUrlError),
-    #[error("failed validation: `{0}`")]
-    ValidationError(ValidationError),
+    #[error("invalid property key at `{path}`: `{error}`")]
+    InvalidPropertyKey {
+        path: JsonPointer,
+        error: ParseBaseUrlError,
+    },
+    #[error("invalid key inside required at `{path}`: `{error}`")]
+    InvalidRequiredKey {
+        path: JsonPointer,
+        error: ParseBaseUrlError,
+    },
+    #[error("failed validation at `{path}`: `{error}`")]
+    ValidationError {
+        path: JsonPointer,
+        error: ValidationError,
+    },
     #[error("error in JSON: `{0}`")]
     InvalidJson(String),
 }
+
+impl ParsePropertyTypeObjectError {
+    /// Returns the location of the node which failed to parse, relative to the object.
+    #[must_use]
+    pub fn path(&self) -> &JsonPointer {
+        match self {
+            Self::InvalidPropertyTypeReference { path, .. }
+            | Self::InvalidPropertyKey { path, .. }
+            | Self::InvalidRequiredKey { path, .. }
+            | Self::ValidationError { path, .. } => path,
+            Self::InvalidArray(error) => error.path(),
+            Self::InvalidJson(_) => &ROOT,
+        }
+    }
+}
+
+impl Located for ParsePropertyTypeObjectError {
+    fn prepend_path(&mut self, prefix: &JsonPointer) {
+        match self {
+            Self::InvalidPropertyTypeReference { path, .. }
+            | Self::InvalidPropertyKey { path, .. }
+            | Self::InvalidRequiredKey { path, .. }
+            | Self::ValidationError { path, .. } => path.prepend(prefix),
+            Self::InvalidArray(error) => error.prepend_path(prefix),
+            Self::InvalidJson(_) => {}
+        }
+    }
+}
@@ -1,13 +1,17 @@
 pub(crate) mod error;
 pub(in crate::ontology) mod raw;
+pub(crate) mod serialization;
 
-use std::collections::HashMap;
+use indexmap::IndexMap;
 
-use crate::{url::BaseUrl, ValidateUrl, ValidationError};
+use crate::{
+    ontology::shared::satisfiability::into_result, url::BaseUrl, JsonPointer, Unsatisfiable,
+    UnsatisfiableReason, ValidateUrl, ValidationError, ValueOrArray,
+};
 
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct Object<T, const MIN: usize = 0> {
-    pub(crate) properties: HashMap<BaseUrl, T>,
+    pub(crate) properties: IndexMap<BaseUrl, T>,
     pub(crate) required: Vec<BaseUrl>,
 }
 
@@ -21,7 +25,7 @@ impl<T: ValidateUrl, const MIN: usize> Object<T, MIN> {
     /// - [`ValidationError::MismatchedPropertyCount`] if the number of properties is less than
     ///   `MIN`.
     pub fn new(
-        properties: HashMap<BaseUrl, T>,
+        properties: IndexMap<BaseUrl, T>,
         required: Vec<BaseUrl>,
     ) -> Result<Self, ValidationError> {
         let object = Self::new_unchecked(properties, required);
@@ -30,32 +34,54 @@ impl<T: ValidateUrl, const MIN: usize> Object<T, MIN> {
     }
 
     fn validate(&self) -> Result<(), ValidationError> {
-        let num_properties = self.properties().len();
+        let mut errors = Vec::new();
+        Self::validate_at(
+            self.properties(),
+            self.required(),
+            |reference| Some(reference),
+            &mut errors,
+        );
+        errors.into_iter().next().map_or(Ok(()), Err)
+    }
+
+    /// Reports every [`ValidationError`] of an object with `properties` and `required`.
+    ///
+    /// `value` returns the parsed value of a property. Properties which failed to parse still count
+    /// as defined, but their base URL is not checked.
+    pub(crate) fn validate_at<V>(
+        properties: &IndexMap<BaseUrl, V>,
+        required: &[BaseUrl],
+        value: impl Fn(&V) -> Option<&T>,
+        errors: &mut Vec<ValidationError>,
+    ) {
+        let num_properties = properties.len();
         if num_properties < MIN {
-            return Err(ValidationError::MismatchedPropertyCount {
+            errors.push(ValidationError::MismatchedPropertyCount {
                 actual: num_properties,
                 expected: MIN,
             });
         };
 
-        for url in self.required() {
-            if !self.properties().contains_key(url) {
-                return Err(ValidationError::MissingRequiredProperty(url.clone()));
+        for url in required {
+            if !properties.contains_key(url) {
+                errors.push(ValidationError::MissingRequiredProperty(url.clone()));
             }
         }
 
-        for (base_url, reference) in self.properties() {
-            reference.validate_url(base_url)?;
+        for (base_url, property) in properties {
+            if let Some(reference) = value(property) {
+                if let Err(error) = reference.validate_url(base_url) {
+                    errors.push(error);
+                }
+            }
         }
-
-        Ok(())
     }
 }
 
 impl<T, const MIN: usize> Object<T, MIN> {
     /// Creates a new `Object` without validating.
     #[must_use]
-    pub fn new_unchecked(properties: HashMap<BaseUrl, T>, required: Vec<BaseUrl>) -> Self {
+    pub fn new_unchecked(properties: IndexMap<BaseUrl, T>, required: Vec<BaseUrl>) -> Self {
         Self {
             properties,
             required,
@@ -63,7 +89,7 @@ impl<T, const MIN: usize> Object<T, MIN> {
     }
 
     #[must_use]
-    pub const fn properties(&self) -> &HashMap<BaseUrl, T> {
+    pub const fn properties(&self) -> &IndexMap<BaseUrl, T> {
         &self.properties
     }
 
@@ -73,6 +99,42 @@ impl<T, const MIN: usize> Object<T, MIN> {
     }
 }
 
+impl<T, const MIN: usize> Object<ValueOrArray<T>, MIN> {
+    /// Checks that the properties of this object can be satisfied.
+    ///
+    /// The bounds of every array have to allow at least one number of items, and a required
+    /// property which is an array has to allow at least one item. The referenced types are not
+    /// checked.
+    ///
+    /// # Errors
+    ///
+    /// - every [`Unsatisfiable`] set of constraints found, addressed by its location inside the
+    ///   schema of this object
+    pub fn check_satisfiable(&self) -> Result<(), Vec<Unsatisfiable>> {
+        let mut unsatisfiable = Vec::new();
+        self.check_satisfiable_at(&JsonPointer::root(), &mut unsatisfiable);
+        into_result(unsatisfiable)
+    }
+
+    pub(crate) fn check_satisfiable_at(
+        &self,
+        path: &JsonPointer,
+        unsatisfiable: &mut Vec<Unsatisfiable>,
+    ) {
+        for (base_url, property) in self.properties() {
+            if let ValueOrArray::Array(array) = property {
+                array.check_satisfiable_at(
+                    self.required().contains(base_url).then(|| {
+                        UnsatisfiableReason::RequiredPropertyWithoutItems(base_url.clone())
+                    }),
+                    &path.join("properties").join(base_url),
+                    unsatisfiable,
+                );
+            }
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use std::str::FromStr;
@@ -81,7 +143,7 @@ mod tests {
 
     use super::*;
     use crate::{
-        raw, url::VersionedUrl, utils::tests::ensure_failed_validation,
+        raw, url::VersionedUrl, utils::tests::ensure_failed_validation, JsonPointer,
         ParsePropertyTypeObjectError, PropertyTypeReference, ValueOrArray,
     };
 
@@ -95,12 +157,13 @@ mod tests {
                 "type": "object",
                 "properties": {}
             }),
-            ParsePropertyTypeObjectError::ValidationError(
-                ValidationError::MismatchedPropertyCount {
+            ParsePropertyTypeObjectError::ValidationError {
+                path: JsonPointer::root().join("properties"),
+                error: ValidationError::MismatchedPropertyCount {
                     actual: 0,
                     expected: 1,
                 },
-            ),
+            },
         );
     }
 
@@ -113,12 +176,19 @@ mod tests {
                     "https://example.com/property_type_a/": { "$ref": "https://example.com/property_type_b/v/1" }
                 }
             }),
-            ParsePropertyTypeObjectError::ValidationError(ValidationError::BaseUrlMismatch {
-                base_url: BaseUrl::new("https://example.com/property_type_a/".to_owned())
-                    .expect("failed to create BaseURI"),
-                versioned_url: VersionedUrl::from_str("https://example.com/property_type_b/v/1")
+            ParsePropertyTypeObjectError::ValidationError {
+                path: JsonPointer::root()
+                    .join("properties")
+                    .join("https://example.com/property_type_a/"),
+                error: ValidationError::BaseUrlMismatch {
+                    base_url: BaseUrl::new("https://example.com/property_type_a/".to_owned())
+                        .expect("failed to create BaseURI"),
+                    versioned_url: VersionedUrl::from_str(
+                        "https://example.com/property_type_b/v/1",
+                    )
                     .expect("failed to create VersionedUrl"),
-            }),
+                },
+            },
         );
     }
 
@@ -135,12 +205,64 @@ mod tests {
                     "https://example.com/property_type_c/"
                 ]
             }),
-            ParsePropertyTypeObjectError::ValidationError(
-                ValidationError::MissingRequiredProperty(
+            ParsePropertyTypeObjectError::ValidationError {
+                path: JsonPointer::root().join("required"),
+                error: ValidationError::MissingRequiredProperty(
                     BaseUrl::new("https://example.com/property_type_c/".to_owned())
                         .expect("failed to create BaseURI"),
                 ),
-            ),
+            },
+        );
+    }
+
+    #[test]
+    fn unsatisfiable() {
+        let object: ObjectRepr = serde_json::from_value(json!({
+            "type": "object",
+            "properties": {
+                "https://example.com/property_type_a/": {
+                    "type": "array",
+                    "items": { "$ref": "https://example.com/property_type_a/v/1" },
+                    "minItems": 2,
+                    "maxItems": 1
+                },
+                "https://example.com/property_type_b/": {
+                    "type": "array",
+                    "items": { "$ref": "https://example.com/property_type_b/v/1" },
+                    "maxItems": 0
+                },
+                "https://example.com/property_type_c/": {
+                    "type": "array",
+                    "items": { "$ref": "https://example.com/property_type_c/v/1" },
+                    "maxItems": 0
+                },
+            },
+            "required": [
+                "https://example.com/property_type_b/"
+            ]
+        }))
+        .expect("failed to deserialize object");
+        let object = Object::try_from(object).expect("failed to parse object");
+
+        let property_a =
+            BaseUrl::new("https://example.com/property_type_a/".to_owned()).expect("invalid URL");
+        let property_b =
+            BaseUrl::new("https://example.com/property_type_b/".to_owned()).expect("invalid URL");
+        assert_eq!(
+            object.check_satisfiable(),
+            Err(vec![
+                Unsatisfiable::new(
+                    JsonPointer::root().join("properties").join(&property_a),
+                    UnsatisfiableReason::MinItemsExceedsMaxItems {
+                        min_items: 2,
+                        max_items: 1,
+                    },
+                ),
+                Unsatisfiable::new(
+                    JsonPointer::root().join("properties").join(&property_b),
+                    UnsatisfiableReason::RequiredPropertyWithoutItems(property_b),
+                ),
+            ])
         );
     }
 }
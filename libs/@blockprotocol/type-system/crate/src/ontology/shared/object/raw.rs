@@ -1,10 +1,19 @@
-use std::{collections::HashMap, fmt::Debug};
+use std::fmt::Debug;
 
+use indexmap::IndexMap;
 use serde::{Deserialize, Serialize};
 #[cfg(target_arch = "wasm32")]
 use tsify::Tsify;
 
-use crate::{raw, url::BaseUrl, ParsePropertyTypeObjectError, PropertyTypeReference, ValueOrArray};
+use crate::{
+    ontology::shared::{
+        json_pointer::Located,
+        parse::{collect, first_error, TryFromRaw},
+    },
+    raw,
+    url::BaseUrl,
+    JsonPointer, ParsePropertyTypeObjectError, PropertyTypeReference, ValueOrArray,
+};
 
 /// Will serialize as a constant value `"object"`
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
@@ -20,41 +29,91 @@ pub struct Object<T> {
     #[cfg_attr(target_arch = "wasm32", tsify(type = "'object'"))]
     r#type: ObjectTypeTag,
     #[cfg_attr(target_arch = "wasm32", tsify(type = "Record<BaseUrl, T>"))]
-    pub properties: HashMap<String, T>,
+    pub properties: IndexMap<String, T>,
     #[cfg_attr(target_arch = "wasm32", tsify(optional, type = "BaseUrl[]"))]
     #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub required: Vec<String>,
 }
 
-impl<const MIN: usize> TryFrom<Object<raw::ValueOrArray<raw::PropertyTypeReference>>>
+impl<const MIN: usize> TryFromRaw<Object<raw::ValueOrArray<raw::PropertyTypeReference>>>
     for super::Object<ValueOrArray<PropertyTypeReference>, MIN>
 {
     type Error = ParsePropertyTypeObjectError;
 
-    fn try_from(
+    fn try_from_raw(
         object_repr: Object<raw::ValueOrArray<raw::PropertyTypeReference>>,
-    ) -> Result<Self, Self::Error> {
-        let properties = object_repr
-            .properties
-            .into_iter()
-            .map(|(base_url, val)| {
-                Ok((
-                    BaseUrl::new(base_url)
-                        .map_err(ParsePropertyTypeObjectError::InvalidPropertyKey)?,
-                    val.try_into()?,
-                ))
-            })
-            .collect::<Result<HashMap<_, _>, Self::Error>>()?;
+    ) -> Result<Self, Vec<Self::Error>> {
+        let path = JsonPointer::root();
+        let mut errors = Vec::new();
+
+        // Properties whose value failed to parse are kept as `None`, they are still defined
+        let mut properties = IndexMap::with_capacity(object_repr.properties.len());
+        for (base_url, val) in object_repr.properties {
+            let property_path = path.join("properties").join(&base_url);
+            let base_url = BaseUrl::new(base_url).map_err(|error| {
+                errors.push(ParsePropertyTypeObjectError::InvalidPropertyKey {
+                    path: property_path.clone(),
+                    error,
+                });
+            });
+            let val = collect(ValueOrArray::try_from_raw(val), &mut errors, |error| {
+                error.prefixed(&property_path)
+            });
+            if let Ok(base_url) = base_url {
+                properties.insert(base_url, val);
+            }
+        }
 
         let required = object_repr
             .required
             .into_iter()
-            .map(|base_url| {
-                BaseUrl::new(base_url).map_err(ParsePropertyTypeObjectError::InvalidRequiredKey)
+            .enumerate()
+            .filter_map(|(index, base_url)| {
+                BaseUrl::new(base_url)
+                    .map_err(|error| {
+                        errors.push(ParsePropertyTypeObjectError::InvalidRequiredKey {
+                            path: path.join("required").join(index),
+                            error,
+                        });
+                    })
+                    .ok()
             })
-            .collect::<Result<Vec<_>, Self::Error>>()?;
+            .collect::<Vec<_>>();
+
+        let mut validation_errors = Vec::new();
+        Self::validate_at(
+            &properties,
+            &required,
+            Option::as_ref,
+            &mut validation_errors,
+        );
+        errors.extend(validation_errors.into_iter().map(|error| {
+            ParsePropertyTypeObjectError::ValidationError {
+                path: error.path(),
+                error,
+            }
+        }));
 
-        Self::new(properties, required).map_err(ParsePropertyTypeObjectError::ValidationError)
+        if !errors.is_empty() {
+            return Err(errors);
+        }
+        let properties = properties
+            .into_iter()
+            .filter_map(|(base_url, val)| Some((base_url, val?)))
+            .collect();
+        Ok(Self::new_unchecked(properties, required))
+    }
+}
+
+impl<const MIN: usize> TryFrom<Object<raw::ValueOrArray<raw::PropertyTypeReference>>>
+    for super::Object<ValueOrArray<PropertyTypeReference>, MIN>
+{
+    type Error = ParsePropertyTypeObjectError;
+
+    fn try_from(
+        object_repr: Object<raw::ValueOrArray<raw::PropertyTypeReference>>,
+    ) -> Result<Self, Self::Error> {
+        first_error(Self::try_from_raw(object_repr))
     }
 }
 
@@ -91,7 +150,10 @@ mod tests {
     use crate::{
         raw::PropertyTypeReference,
         url::VersionedUrl,
-        utils::tests::{check_repr_serialization_from_value, ensure_repr_failed_deserialization},
+        utils::tests::{
+            check_repr_serialization_from_value, check_serialization_from_str,
+            ensure_repr_failed_deserialization,
+        },
     };
 
     mod unconstrained {
@@ -108,7 +170,7 @@ mod tests {
                 }),
                 Some(Object {
                     r#type: ObjectTypeTag::Object,
-                    properties: HashMap::new(),
+                    properties: IndexMap::new(),
                     required: vec![],
                 }),
             );
@@ -128,7 +190,7 @@ mod tests {
                 }),
                 Some(Object {
                     r#type: ObjectTypeTag::Object,
-                    properties: HashMap::from([(
+                    properties: IndexMap::from([(
                         url.base_url.to_string(),
                         PropertyTypeReference::new(url.to_string()),
                     )]),
@@ -154,7 +216,7 @@ mod tests {
                 }),
                 Some(Object {
                     r#type: ObjectTypeTag::Object,
-                    properties: HashMap::from([
+                    properties: IndexMap::from([
                         (
                             url_a.base_url.to_string(),
                             PropertyTypeReference::new(url_a.to_string()),
@@ -189,7 +251,7 @@ mod tests {
                 }),
                 Some(Object {
                     r#type: ObjectTypeTag::Object,
-                    properties: HashMap::from([(
+                    properties: IndexMap::from([(
                         url.base_url.to_string(),
                         PropertyTypeReference::new(url.to_string()),
                     )]),
@@ -215,7 +277,7 @@ mod tests {
                 }),
                 Some(Object {
                     r#type: ObjectTypeTag::Object,
-                    properties: HashMap::from([
+                    properties: IndexMap::from([
                         (
                             url_a.base_url.to_string(),
                             PropertyTypeReference::new(url_a.to_string()),
@@ -251,7 +313,7 @@ mod tests {
             }),
             Some(Object {
                 r#type: ObjectTypeTag::Object,
-                properties: HashMap::from([
+                properties: IndexMap::from([
                     (
                         url_a.base_url.to_string(),
                         PropertyTypeReference::new(url_a.to_string()),
@@ -277,4 +339,40 @@ mod tests {
             "additional_properties": 10
         }));
     }
+
+    #[test]
+    fn declaration_order() {
+        let input = json!({
+            "type": "object",
+            "properties": {
+                "https://example.com/property_type_c/": { "$ref": "https://example.com/property_type_c/v/1" },
+                "https://example.com/property_type_a/": { "$ref": "https://example.com/property_type_a/v/1" },
+                "https://example.com/property_type_b/": { "$ref": "https://example.com/property_type_b/v/1" },
+            }
+        })
+        .to_string();
+
+        let object = check_serialization_from_str::<
+            crate::Object<crate::ValueOrArray<crate::PropertyTypeReference>>,
+            Object<raw::ValueOrArray<PropertyTypeReference>>,
+        >(&input, None);
+
+        let keys = object
+            .properties()
+            .keys()
+            .map(BaseUrl::as_str)
+            .collect::<Vec<_>>();
+        assert_eq!(keys, [
+            "https://example.com/property_type_c/",
+            "https://example.com/property_type_a/",
+            "https://example.com/property_type_b/",
+        ]);
+        assert_eq!(
+            serde_json::to_string(&Object::<raw::ValueOrArray<PropertyTypeReference>>::from(
+                object
+            ))
+            .expect("failed to serialize"),
+            input
+        );
+    }
 }
@@ -0,0 +1,200 @@
+use indexmap::IndexMap;
+use serde::{
+    de::{self, MapAccess},
+    ser::SerializeStruct,
+    Deserialize, Deserializer, Serialize, Serializer,
+};
+
+use crate::{
+    ontology::shared::serialization::{
+        deserialize_map, next_tag, next_value, required, required_tag, serialize_optional_field,
+        FromMap, Key,
+    },
+    url::BaseUrl,
+    Object, ParsePropertyTypeObjectError, ValidateUrl,
+};
+
+const FIELDS: &[&str] = &["type", "properties", "required"];
+
+/// The keywords of an [`Object`] apart from its `type`, see [`serialization`].
+///
+/// [`serialization`]: crate::ontology::shared::serialization
+pub(crate) struct ObjectFields<T> {
+    properties: Option<IndexMap<BaseUrl, T>>,
+    required: Option<Vec<BaseUrl>>,
+}
+
+impl<T> Default for ObjectFields<T> {
+    fn default() -> Self {
+        Self {
+            properties: None,
+            required: None,
+        }
+    }
+}
+
+impl<'de, T: Deserialize<'de>> ObjectFields<T> {
+    /// Deserializes the next value of `map` if `key` is a keyword of an object.
+    ///
+    /// Returns `false` without consuming the value otherwise.
+    pub(crate) fn next_value<A>(&mut self, key: &str, map: &mut A) -> Result<bool, A::Error>
+    where
+        A: MapAccess<'de>,
+    {
+        match key {
+            "properties" => next_value(map, &mut self.properties, "properties")?,
+            "required" => next_value(map, &mut self.required, "required")?,
+            _ => return Ok(false),
+        }
+        Ok(true)
+    }
+}
+
+impl<T> ObjectFields<T> {
+    /// Returns the first keyword which has been deserialized, if any.
+    pub(crate) const fn first_key(&self) -> Option<&'static str> {
+        if self.properties.is_some() {
+            Some("properties")
+        } else if self.required.is_some() {
+            Some("required")
+        } else {
+            None
+        }
+    }
+
+    /// Creates the object from the deserialized keywords, see [`Object::new`].
+    pub(crate) fn finish<E, const MIN: usize>(self) -> Result<Object<T, MIN>, E>
+    where
+        T: ValidateUrl,
+        E: de::Error,
+    {
+        Object::new(
+            required(self.properties, "properties")?,
+            self.required.unwrap_or_default(),
+        )
+        .map_err(|error| {
+            E::custom(ParsePropertyTypeObjectError::ValidationError {
+                path: error.path(),
+                error,
+            })
+        })
+    }
+}
+
+impl<T: Serialize, const MIN: usize> Object<T, MIN> {
+    /// Serializes the keywords of this object into `state`, e.g. to flatten them into an
+    /// [`EntityType`].
+    ///
+    /// [`EntityType`]: crate::EntityType
+    pub(crate) fn serialize_fields<S>(&self, state: &mut S) -> Result<(), S::Error>
+    where
+        S: SerializeStruct,
+    {
+        state.serialize_field("type", "object")?;
+        state.serialize_field("properties", self.properties())?;
+        serialize_optional_field(
+            state,
+            "required",
+            (!self.required().is_empty()).then(|| self.required()),
+        )
+    }
+}
+
+impl<T: Serialize, const MIN: usize> Serialize for Object<T, MIN> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("Object", 3)?;
+        self.serialize_fields(&mut state)?;
+        state.end()
+    }
+}
+
+impl<'de, T: Deserialize<'de> + ValidateUrl, const MIN: usize> FromMap<'de> for Object<T, MIN> {
+    const EXPECTING: &'static str = "an object schema";
+
+    fn from_map<A>(mut map: A) -> Result<Self, A::Error>
+    where
+        A: MapAccess<'de>,
+    {
+        let mut r#type = false;
+        let mut fields = ObjectFields::default();
+        while let Some(key) = map.next_key::<Key>()? {
+            match &*key {
+                "type" => next_tag(&mut map, &mut r#type, "type", "object")?,
+                key => {
+                    if !fields.next_value(key, &mut map)? {
+                        return Err(de::Error::unknown_field(key, FIELDS));
+                    }
+                }
+            }
+        }
+
+        required_tag(r#type, "type")?;
+        fields.finish()
+    }
+}
+
+impl<'de, T: Deserialize<'de> + ValidateUrl, const MIN: usize> Deserialize<'de> for Object<T, MIN> {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserialize_map(deserializer)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use crate::{PropertyTypeReference, ValueOrArray};
+
+    type Object = super::Object<ValueOrArray<PropertyTypeReference>, 1>;
+
+    #[test]
+    fn round_trip() {
+        let input = json!({
+            "type": "object",
+            "properties": {
+                "https://example.com/property_type_a/": { "$ref": "https://example.com/property_type_a/v/1" },
+                "https://example.com/property_type_b/": {
+                    "type": "array",
+                    "items": { "$ref": "https://example.com/property_type_b/v/1" },
+                },
+            },
+            "required": ["https://example.com/property_type_b/"],
+        });
+        let object =
+            serde_json::from_value::<Object>(input.clone()).expect("failed to deserialize object");
+        assert_eq!(object.properties().len(), 2);
+        assert_eq!(
+            serde_json::to_value(&object).expect("failed to serialize object"),
+            input
+        );
+    }
+
+    #[test]
+    fn validated() {
+        let error = serde_json::from_value::<Object>(json!({
+            "type": "object",
+            "properties": {
+                "https://example.com/property_type_a/": { "$ref": "https://example.com/property_type_b/v/1" }
+            }
+        }))
+        .expect_err("deserialized a property with a mismatching base URL");
+        assert!(
+            error.to_string().starts_with(
+                "failed validation at `/properties/https:~1~1example.com~1property_type_a~1`"
+            ),
+            "{error}"
+        );
+
+        serde_json::from_value::<Object>(json!({
+            "type": "object",
+            "properties": {}
+        }))
+        .expect_err("deserialized an object with too few properties");
+    }
+}
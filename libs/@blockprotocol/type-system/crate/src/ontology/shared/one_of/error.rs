@@ -3,16 +3,50 @@ use thiserror::Error;
 #[cfg(target_arch = "wasm32")]
 use tsify::Tsify;
 
-use crate::{url::ParseVersionedUrlError, ParsePropertyTypeError, ValidationError};
+use crate::{
+    ontology::shared::json_pointer::Located, url::ParseVersionedUrlError, JsonPointer,
+    ParsePropertyTypeError, ValidationError,
+};
 
 #[cfg_attr(target_arch = "wasm32", derive(Tsify))]
 #[derive(Debug, Deserialize, Serialize, PartialEq, Eq, Error)]
 #[serde(tag = "reason", content = "inner")]
 pub enum ParseOneOfError {
-    #[error("invalid entity type reference: `{0}`")]
-    EntityTypeReferenceError(ParseVersionedUrlError),
+    #[error("invalid entity type reference at `{path}`: `{error}`")]
+    EntityTypeReferenceError {
+        path: JsonPointer,
+        error: ParseVersionedUrlError,
+    },
     #[error("invalid value definition: `{0}`")]
     PropertyValuesError(ParsePropertyTypeError),
-    #[error("failed validation: `{0}`")]
-    ValidationError(ValidationError),
+    #[error("failed validation at `{path}`: `{error}`")]
+    ValidationError {
+        path: JsonPointer,
+        error: ValidationError,
+    },
+}
+
+impl ParseOneOfError {
+    /// Returns the location of the node which failed to parse, relative to the schema containing
+    /// `oneOf`.
+    #[must_use]
+    pub fn path(&self) -> &JsonPointer {
+        match self {
+            Self::EntityTypeReferenceError { path, .. } | Self::ValidationError { path, .. } => {
+                path
+            }
+            Self::PropertyValuesError(error) => error.path(),
+        }
+    }
+}
+
+impl Located for ParseOneOfError {
+    fn prepend_path(&mut self, prefix: &JsonPointer) {
+        match self {
+            Self::EntityTypeReferenceError { path, .. } | Self::ValidationError { path, .. } => {
+                path.prepend(prefix);
+            }
+            Self::PropertyValuesError(error) => error.prepend_path(prefix),
+        }
+    }
 }
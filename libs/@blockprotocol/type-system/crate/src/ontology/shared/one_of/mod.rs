@@ -1,5 +1,6 @@
 pub(crate) mod error;
 pub(in crate::ontology) mod raw;
+mod serialization;
 
 use crate::ValidationError;
 
@@ -45,7 +46,7 @@ mod tests {
     use serde_json::json;
 
     use crate::{
-        raw, utils::tests::ensure_failed_validation, ParseOneOfError, PropertyValues,
+        raw, utils::tests::ensure_failed_validation, JsonPointer, ParseOneOfError, PropertyValues,
         ValidationError,
     };
 
@@ -58,7 +59,10 @@ mod tests {
             &json!({
                 "oneOf": []
             }),
-            ParseOneOfError::ValidationError(ValidationError::EmptyOneOf),
+            ParseOneOfError::ValidationError {
+                path: JsonPointer::root().join("oneOf"),
+                error: ValidationError::EmptyOneOf,
+            },
         );
     }
 }
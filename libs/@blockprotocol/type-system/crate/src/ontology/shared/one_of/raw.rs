@@ -2,7 +2,13 @@ use serde::{Deserialize, Serialize};
 #[cfg(target_arch = "wasm32")]
 use tsify::Tsify;
 
-use crate::{raw, EntityTypeReference, ParseOneOfError, PropertyValues};
+use crate::{
+    ontology::shared::{
+        json_pointer::Located,
+        parse::{collect, first_error, TryFromRaw},
+    },
+    raw, EntityTypeReference, JsonPointer, ParseOneOfError, PropertyValues,
+};
 
 #[cfg_attr(target_arch = "wasm32", derive(Tsify))]
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
@@ -13,34 +19,84 @@ pub struct OneOf<T> {
     pub possibilities: Vec<T>,
 }
 
-impl TryFrom<OneOf<raw::PropertyValues>> for super::OneOf<PropertyValues> {
+impl TryFromRaw<OneOf<raw::PropertyValues>> for super::OneOf<PropertyValues> {
     type Error = ParseOneOfError;
 
-    fn try_from(one_of_repr: OneOf<raw::PropertyValues>) -> Result<Self, Self::Error> {
+    fn try_from_raw(one_of_repr: OneOf<raw::PropertyValues>) -> Result<Self, Vec<Self::Error>> {
+        let mut errors = Vec::new();
         let inner = one_of_repr
             .possibilities
             .into_iter()
-            .map(|ele| ele.try_into().map_err(ParseOneOfError::PropertyValuesError))
-            .collect::<Result<Vec<_>, Self::Error>>()?;
+            .enumerate()
+            .filter_map(|(index, ele)| {
+                collect(PropertyValues::try_from_raw(ele), &mut errors, |error| {
+                    ParseOneOfError::PropertyValuesError(
+                        error.prefixed(&JsonPointer::root().join("oneOf").join(index)),
+                    )
+                })
+            })
+            .collect::<Vec<_>>();
 
-        Self::new(inner).map_err(ParseOneOfError::ValidationError)
+        if !errors.is_empty() {
+            return Err(errors);
+        }
+        Self::new(inner).map_err(|error| {
+            vec![ParseOneOfError::ValidationError {
+                path: error.path(),
+                error,
+            }]
+        })
     }
 }
 
-impl TryFrom<OneOf<raw::EntityTypeReference>> for super::OneOf<EntityTypeReference> {
+impl TryFrom<OneOf<raw::PropertyValues>> for super::OneOf<PropertyValues> {
     type Error = ParseOneOfError;
 
-    fn try_from(one_of_repr: OneOf<raw::EntityTypeReference>) -> Result<Self, Self::Error> {
+    fn try_from(one_of_repr: OneOf<raw::PropertyValues>) -> Result<Self, Self::Error> {
+        first_error(Self::try_from_raw(one_of_repr))
+    }
+}
+
+impl TryFromRaw<OneOf<raw::EntityTypeReference>> for super::OneOf<EntityTypeReference> {
+    type Error = ParseOneOfError;
+
+    fn try_from_raw(
+        one_of_repr: OneOf<raw::EntityTypeReference>,
+    ) -> Result<Self, Vec<Self::Error>> {
+        let mut errors = Vec::new();
         let inner = one_of_repr
             .possibilities
             .into_iter()
-            .map(|ele| {
+            .enumerate()
+            .filter_map(|(index, ele)| {
                 ele.try_into()
-                    .map_err(ParseOneOfError::EntityTypeReferenceError)
+                    .map_err(|error| {
+                        errors.push(ParseOneOfError::EntityTypeReferenceError {
+                            path: JsonPointer::root().join("oneOf").join(index).join("$ref"),
+                            error,
+                        });
+                    })
+                    .ok()
             })
-            .collect::<Result<Vec<_>, Self::Error>>()?;
+            .collect::<Vec<_>>();
+
+        if !errors.is_empty() {
+            return Err(errors);
+        }
+        Self::new(inner).map_err(|error| {
+            vec![ParseOneOfError::ValidationError {
+                path: error.path(),
+                error,
+            }]
+        })
+    }
+}
 
-        Self::new(inner).map_err(ParseOneOfError::ValidationError)
+impl TryFrom<OneOf<raw::EntityTypeReference>> for super::OneOf<EntityTypeReference> {
+    type Error = ParseOneOfError;
+
+    fn try_from(one_of_repr: OneOf<raw::EntityTypeReference>) -> Result<Self, Self::Error> {
+        first_error(Self::try_from_raw(one_of_repr))
     }
 }
 
@@ -0,0 +1,105 @@
+use serde::{
+    de::{self, MapAccess},
+    ser::SerializeStruct,
+    Deserialize, Deserializer, Serialize, Serializer,
+};
+
+use crate::{
+    ontology::shared::serialization::{deserialize_map, next_value, required, FromMap, Key},
+    OneOf, ParseOneOfError,
+};
+
+const FIELDS: &[&str] = &["oneOf"];
+
+impl<T> OneOf<T> {
+    /// Creates a `OneOf` from the deserialized value of a `oneOf` keyword, see [`OneOf::new`].
+    pub(crate) fn from_deserialized<E>(possibilities: Vec<T>) -> Result<Self, E>
+    where
+        E: de::Error,
+    {
+        Self::new(possibilities).map_err(|error| {
+            E::custom(ParseOneOfError::ValidationError {
+                path: error.path(),
+                error,
+            })
+        })
+    }
+}
+
+impl<T: Serialize> Serialize for OneOf<T> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("OneOf", 1)?;
+        state.serialize_field("oneOf", self.one_of())?;
+        state.end()
+    }
+}
+
+impl<'de, T: Deserialize<'de>> FromMap<'de> for OneOf<T> {
+    const EXPECTING: &'static str = "a `oneOf` schema";
+
+    fn from_map<A>(mut map: A) -> Result<Self, A::Error>
+    where
+        A: MapAccess<'de>,
+    {
+        let mut possibilities = None;
+        while let Some(key) = map.next_key::<Key>()? {
+            match &*key {
+                "oneOf" => next_value(&mut map, &mut possibilities, "oneOf")?,
+                key => return Err(de::Error::unknown_field(key, FIELDS)),
+            }
+        }
+
+        Self::from_deserialized(required(possibilities, "oneOf")?)
+    }
+}
+
+impl<'de, T: Deserialize<'de>> Deserialize<'de> for OneOf<T> {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserialize_map(deserializer)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    #[test]
+    fn round_trip() {
+        let one_of = serde_json::from_value::<OneOf<String>>(json!({ "oneOf": ["A", "B"] }))
+            .expect("failed to deserialize oneOf");
+        assert_eq!(one_of.one_of(), ["A", "B"]);
+        assert_eq!(
+            serde_json::to_value(&one_of).expect("failed to serialize oneOf"),
+            json!({ "oneOf": ["A", "B"] })
+        );
+    }
+
+    #[test]
+    fn empty() {
+        let error = serde_json::from_value::<OneOf<String>>(json!({ "oneOf": [] }))
+            .expect_err("deserialized an empty oneOf");
+        assert!(
+            error
+                .to_string()
+                .starts_with("failed validation at `/oneOf`"),
+            "{error}"
+        );
+    }
+
+    #[test]
+    fn additional_properties() {
+        serde_json::from_value::<OneOf<String>>(json!({
+            "oneOf": ["A", "B"],
+            "additional": 10,
+        }))
+        .expect_err("deserialized a oneOf with an unknown keyword");
+    }
+}
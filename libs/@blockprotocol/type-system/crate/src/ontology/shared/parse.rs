@@ -0,0 +1,63 @@
+use serde::de::DeserializeOwned;
+
+/// Conversion from a [`raw`] representation which keeps going after the first error.
+///
+/// The [`TryFrom`] implementations of the native types are fail-fast and return the first error
+/// reported by this conversion.
+///
+/// [`raw`]: crate::raw
+pub(crate) trait TryFromRaw<R>: Sized {
+    type Error;
+
+    /// Converts `repr`, reporting every error found in it.
+    ///
+    /// Errors are reported in the order the fail-fast conversion encounters them.
+    fn try_from_raw(repr: R) -> Result<Self, Vec<Self::Error>>;
+}
+
+/// Returns the first error reported by [`TryFromRaw::try_from_raw`].
+pub(crate) fn first_error<T, E>(result: Result<T, Vec<E>>) -> Result<T, E> {
+    result.map_err(|errors| {
+        errors
+            .into_iter()
+            .next()
+            .expect("a failed conversion should report at least one error")
+    })
+}
+
+/// Converts every error reported in `result` with `into_error`.
+pub(crate) fn map_errors<T, E, F>(
+    result: Result<T, Vec<E>>,
+    into_error: impl FnMut(E) -> F,
+) -> Result<T, Vec<F>> {
+    result.map_err(|errors| errors.into_iter().map(into_error).collect())
+}
+
+/// Moves the errors of `result` into `errors`, converting each one with `into_error`.
+///
+/// Returns the converted value if there were no errors.
+pub(crate) fn collect<T, E, F>(
+    result: Result<T, Vec<E>>,
+    errors: &mut Vec<F>,
+    into_error: impl FnMut(E) -> F,
+) -> Option<T> {
+    map_errors(result, into_error)
+        .map_err(|reported| errors.extend(reported))
+        .ok()
+}
+
+/// Deserializes `schema` into the raw representation `R` and converts it, reporting every error.
+///
+/// If `schema` can't be deserialized, only the error returned by `invalid_json` is reported.
+pub(crate) fn parse_all<R, T>(
+    schema: serde_json::Value,
+    invalid_json: impl FnOnce(String) -> T::Error,
+) -> Result<T, Vec<T::Error>>
+where
+    R: DeserializeOwned,
+    T: TryFromRaw<R>,
+{
+    let repr = serde_json::from_value::<R>(schema)
+        .map_err(|error| vec![invalid_json(error.to_string())])?;
+    T::try_from_raw(repr)
+}
@@ -0,0 +1,95 @@
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+#[cfg(target_arch = "wasm32")]
+use tsify::Tsify;
+
+use crate::{
+    url::{BaseUrl, VersionedUrl},
+    JsonPointer,
+};
+
+/// A set of constraints which no value can satisfy, addressed by its location inside the schema
+/// of the type which was checked.
+///
+/// See [RFC 0428] for which schemas are considered unsatisfiable.
+///
+/// [RFC 0428]: https://github.com/blockprotocol/blockprotocol/blob/main/rfcs/text/0428-extending-and-duplicating-types.md#disallowing-unsatisfiable-schemas
+#[cfg_attr(target_arch = "wasm32", derive(Tsify))]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, Error)]
+#[error("unsatisfiable schema at `{path}`: {reason}")]
+pub struct Unsatisfiable {
+    pub path: JsonPointer,
+    pub reason: UnsatisfiableReason,
+}
+
+impl Unsatisfiable {
+    #[must_use]
+    pub const fn new(path: JsonPointer, reason: UnsatisfiableReason) -> Self {
+        Self { path, reason }
+    }
+}
+
+#[cfg_attr(target_arch = "wasm32", derive(Tsify))]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, Error)]
+#[serde(tag = "reason", content = "inner")]
+pub enum UnsatisfiableReason {
+    #[error("at least {min_items} items are required, but at most {max_items} are allowed")]
+    MinItemsExceedsMaxItems { min_items: usize, max_items: usize },
+    #[error("the property `{0}` is required, but its array does not allow any items")]
+    RequiredPropertyWithoutItems(BaseUrl),
+    #[error("the link `{0}` is required, but it does not allow any links")]
+    RequiredLinkWithoutItems(VersionedUrl),
+    #[error("the property `{0}` is defined both as a single value and as an array")]
+    PropertyValueAndArray(BaseUrl),
+    #[error(
+        "the property `{property}` references `{property_type}` and `{other_property_type}`, \
+         which don't share any value"
+    )]
+    ConflictingPropertyTypes {
+        property: BaseUrl,
+        property_type: VersionedUrl,
+        other_property_type: VersionedUrl,
+    },
+    #[error("the entity type `{0}` could not be resolved")]
+    UnresolvedEntityType(VersionedUrl),
+}
+
+/// Checks that at least one number of items lies within `min_items` and `max_items`.
+///
+/// If the array has to be present with at least one item, `required` is reported when no items
+/// are allowed at all.
+pub(crate) fn check_bounds_at(
+    min_items: Option<usize>,
+    max_items: Option<usize>,
+    required: Option<UnsatisfiableReason>,
+    path: &JsonPointer,
+    unsatisfiable: &mut Vec<Unsatisfiable>,
+) {
+    if let (Some(min_items), Some(max_items)) = (min_items, max_items) {
+        if min_items > max_items {
+            unsatisfiable.push(Unsatisfiable::new(
+                path.clone(),
+                UnsatisfiableReason::MinItemsExceedsMaxItems {
+                    min_items,
+                    max_items,
+                },
+            ));
+            return;
+        }
+    }
+
+    if max_items == Some(0) {
+        if let Some(reason) = required {
+            unsatisfiable.push(Unsatisfiable::new(path.clone(), reason));
+        }
+    }
+}
+
+/// Turns the collected unsatisfiable constraints into the result of a satisfiability check.
+pub(crate) fn into_result(unsatisfiable: Vec<Unsatisfiable>) -> Result<(), Vec<Unsatisfiable>> {
+    if unsatisfiable.is_empty() {
+        Ok(())
+    } else {
+        Err(unsatisfiable)
+    }
+}
@@ -0,0 +1,295 @@
+//! Helpers for the `Serialize` and `Deserialize` implementations of the native types.
+//!
+//! The native types are serialized by reference and deserialized with map visitors which check
+//! every keyword as it is encountered, so neither direction goes through the [`raw`]
+//! representation. Keywords which are shared between several schemas, such as the ones of an
+//! [`Array`] or an [`Object`], are collected by `*Fields` types, which lets a schema flattening
+//! them, or a schema which is one of several variants, be deserialized in a single pass.
+//!
+//! [`raw`]: crate::raw
+//! [`Array`]: crate::Array
+//! [`Object`]: crate::Object
+
+use std::{borrow::Cow, fmt, marker::PhantomData, ops::Deref, str::FromStr};
+
+use serde::{
+    de::{self, MapAccess, Visitor},
+    ser::{SerializeMap, SerializeStruct},
+    Deserialize, Deserializer, Serialize, Serializer,
+};
+
+use crate::url::{ParseVersionedUrlError, VersionedUrl};
+
+/// A schema which is deserialized by visiting the keys of a JSON object.
+pub(crate) trait FromMap<'de>: Sized {
+    /// Describes the schema in error messages, e.g. `"an array schema"`.
+    const EXPECTING: &'static str;
+
+    /// Deserializes the schema from the entries of `map`.
+    fn from_map<A>(map: A) -> Result<Self, A::Error>
+    where
+        A: MapAccess<'de>;
+}
+
+/// Deserializes `T` from a JSON object, see [`FromMap`].
+pub(crate) fn deserialize_map<'de, D, T>(deserializer: D) -> Result<T, D::Error>
+where
+    D: Deserializer<'de>,
+    T: FromMap<'de>,
+{
+    struct MapVisitor<T>(PhantomData<T>);
+
+    impl<'de, T: FromMap<'de>> Visitor<'de> for MapVisitor<T> {
+        type Value = T;
+
+        fn expecting(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+            fmt.write_str(T::EXPECTING)
+        }
+
+        fn visit_map<A>(self, map: A) -> Result<Self::Value, A::Error>
+        where
+            A: MapAccess<'de>,
+        {
+            T::from_map(map)
+        }
+    }
+
+    deserializer.deserialize_map(MapVisitor(PhantomData))
+}
+
+/// A key of a JSON object, or a string value, borrowed from the input where possible.
+pub(crate) struct Key<'de>(Cow<'de, str>);
+
+impl Deref for Key<'_> {
+    type Target = str;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl Key<'_> {
+    pub(crate) fn into_owned(self) -> String {
+        self.0.into_owned()
+    }
+}
+
+impl<'de> Deserialize<'de> for Key<'de> {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        struct KeyVisitor;
+
+        impl<'de> Visitor<'de> for KeyVisitor {
+            type Value = Key<'de>;
+
+            fn expecting(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+                fmt.write_str("a string")
+            }
+
+            fn visit_borrowed_str<E>(self, value: &'de str) -> Result<Self::Value, E>
+            where
+                E: de::Error,
+            {
+                Ok(Key(Cow::Borrowed(value)))
+            }
+
+            fn visit_str<E>(self, value: &str) -> Result<Self::Value, E>
+            where
+                E: de::Error,
+            {
+                Ok(Key(Cow::Owned(value.to_owned())))
+            }
+
+            fn visit_string<E>(self, value: String) -> Result<Self::Value, E>
+            where
+                E: de::Error,
+            {
+                Ok(Key(Cow::Owned(value)))
+            }
+        }
+
+        deserializer.deserialize_str(KeyVisitor)
+    }
+}
+
+/// Deserializes the next value of `map` into `field`.
+///
+/// `key` is only used to report a duplicated key, if `field` has already been deserialized.
+pub(crate) fn next_value<'de, A, T>(
+    map: &mut A,
+    field: &mut Option<T>,
+    key: &'static str,
+) -> Result<(), A::Error>
+where
+    A: MapAccess<'de>,
+    T: Deserialize<'de>,
+{
+    if field.is_some() {
+        return Err(de::Error::duplicate_field(key));
+    }
+    *field = Some(map.next_value()?);
+    Ok(())
+}
+
+/// Deserializes the next value of `map`, which has to be the string `tag`, e.g. the `"array"` of
+/// `"type": "array"`.
+pub(crate) fn next_tag<'de, A>(
+    map: &mut A,
+    seen: &mut bool,
+    key: &'static str,
+    tag: &'static str,
+) -> Result<(), A::Error>
+where
+    A: MapAccess<'de>,
+{
+    if *seen {
+        return Err(de::Error::duplicate_field(key));
+    }
+    let value = map.next_value::<Key>()?;
+    if &*value != tag {
+        return Err(de::Error::invalid_value(de::Unexpected::Str(&value), &tag));
+    }
+    *seen = true;
+    Ok(())
+}
+
+/// Deserializes the next value of `map` as the `$schema` of a type, which has to be
+/// `meta_schema`.
+///
+/// Any other meta schema is reported with the error returned by `invalid_meta_schema`.
+pub(crate) fn next_meta_schema<'de, A, E>(
+    map: &mut A,
+    seen: &mut bool,
+    meta_schema: &str,
+    invalid_meta_schema: impl FnOnce(String) -> E,
+) -> Result<(), A::Error>
+where
+    A: MapAccess<'de>,
+    E: fmt::Display,
+{
+    if *seen {
+        return Err(de::Error::duplicate_field("$schema"));
+    }
+    let value = map.next_value::<Key>()?;
+    if &*value != meta_schema {
+        return Err(de::Error::custom(invalid_meta_schema(value.into_owned())));
+    }
+    *seen = true;
+    Ok(())
+}
+
+/// Deserializes the next value of `map` as the `$id` of a type.
+///
+/// An invalid URL is reported with the error returned by `invalid_url`.
+pub(crate) fn next_id<'de, A, E>(
+    map: &mut A,
+    id: &mut Option<VersionedUrl>,
+    invalid_url: impl FnOnce(ParseVersionedUrlError) -> E,
+) -> Result<(), A::Error>
+where
+    A: MapAccess<'de>,
+    E: fmt::Display,
+{
+    if id.is_some() {
+        return Err(de::Error::duplicate_field("$id"));
+    }
+    let url = map.next_value::<Key>()?;
+    *id =
+        Some(VersionedUrl::from_str(&url).map_err(|error| de::Error::custom(invalid_url(error)))?);
+    Ok(())
+}
+
+/// Returns the value of a required keyword, or reports it as missing.
+pub(crate) fn required<T, E>(field: Option<T>, key: &'static str) -> Result<T, E>
+where
+    E: de::Error,
+{
+    field.ok_or_else(|| E::missing_field(key))
+}
+
+/// Reports a missing `tag`, see [`next_tag`].
+pub(crate) fn required_tag<E>(seen: bool, key: &'static str) -> Result<(), E>
+where
+    E: de::Error,
+{
+    if seen {
+        Ok(())
+    } else {
+        Err(E::missing_field(key))
+    }
+}
+
+/// Serializes `value` as the field `key`, or skips the field if there is no `value`.
+pub(crate) fn serialize_optional_field<S, T>(
+    state: &mut S,
+    key: &'static str,
+    value: Option<&T>,
+) -> Result<(), S::Error>
+where
+    S: SerializeStruct,
+    T: Serialize + ?Sized,
+{
+    match value {
+        Some(value) => state.serialize_field(key, value),
+        None => state.skip_field(key),
+    }
+}
+
+/// Serializes `value` as the entry `key`, or skips the entry if there is no `value`.
+pub(crate) fn serialize_optional_entry<M, T>(
+    map: &mut M,
+    key: &'static str,
+    value: Option<&T>,
+) -> Result<(), M::Error>
+where
+    M: SerializeMap,
+    T: Serialize + ?Sized,
+{
+    value.map_or(Ok(()), |value| map.serialize_entry(key, value))
+}
+
+/// A `{"$ref": url}` reference to a type.
+struct Reference(VersionedUrl);
+
+impl<'de> FromMap<'de> for Reference {
+    const EXPECTING: &'static str = "a reference with `$ref`";
+
+    fn from_map<A>(mut map: A) -> Result<Self, A::Error>
+    where
+        A: MapAccess<'de>,
+    {
+        let mut url = None;
+        while let Some(key) = map.next_key::<Key>()? {
+            match &*key {
+                "$ref" => next_value(&mut map, &mut url, "$ref")?,
+                key => return Err(de::Error::unknown_field(key, &["$ref"])),
+            }
+        }
+
+        required(url, "$ref").map(Self)
+    }
+}
+
+/// Serializes a reference to the type identified by `url`, named `name` in the serializer.
+pub(crate) fn serialize_reference<S>(
+    name: &'static str,
+    url: &VersionedUrl,
+    serializer: S,
+) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+{
+    let mut state = serializer.serialize_struct(name, 1)?;
+    state.serialize_field("$ref", url)?;
+    state.end()
+}
+
+/// Deserializes a reference to a type, returning the URL it references.
+pub(crate) fn deserialize_reference<'de, D>(deserializer: D) -> Result<VersionedUrl, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    deserialize_map(deserializer).map(|Reference(url)| url)
+}
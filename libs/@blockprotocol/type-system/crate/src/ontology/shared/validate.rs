@@ -8,7 +8,10 @@ use serde::{Deserialize, Serialize};
 #[cfg(target_arch = "wasm32")]
 use tsify::Tsify;
 
-use crate::url::{BaseUrl, VersionedUrl};
+use crate::{
+    url::{BaseUrl, VersionedUrl},
+    JsonPointer,
+};
 
 #[cfg_attr(target_arch = "wasm32", derive(Tsify))]
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
@@ -31,6 +34,21 @@ pub enum ValidationError {
     EmptyOneOf,
 }
 
+impl ValidationError {
+    /// Returns the location of the keyword which failed validation, relative to the validated
+    /// schema.
+    pub(crate) fn path(&self) -> JsonPointer {
+        let path = JsonPointer::root();
+        match self {
+            Self::MissingRequiredProperty(_) => path.join("required"),
+            Self::BaseUrlMismatch { base_url, .. } => path.join("properties").join(base_url),
+            Self::MissingRequiredLink(_) => path.join("requiredLinks"),
+            Self::MismatchedPropertyCount { .. } => path.join("properties"),
+            Self::EmptyOneOf => path.join("oneOf"),
+        }
+    }
+}
+
 impl Display for ValidationError {
     fn fmt(&self, fmt: &mut Formatter) -> fmt::Result {
         match self {
@@ -0,0 +1,9 @@
+use thiserror::Error;
+
+use crate::url::VersionedUrl;
+
+#[derive(Debug, PartialEq, Eq, Error)]
+pub enum InsertTypeError {
+    #[error("a different type with the ID `{0}` already exists, types are immutable")]
+    AlreadyExists(VersionedUrl),
+}
@@ -0,0 +1,378 @@
+mod error;
+
+use std::collections::{btree_map, BTreeMap, HashMap};
+
+pub use error::InsertTypeError;
+
+use crate::{
+    url::{BaseUrl, VersionedUrl},
+    DataType, DataTypeReference, EntityType, EntityTypeReference, OntologyTypeProvider,
+    PropertyType, PropertyTypeReference,
+};
+
+/// A reference to a type which is not contained in an [`OntologyStore`].
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum DanglingReference {
+    DataType(VersionedUrl),
+    PropertyType(VersionedUrl),
+    EntityType(VersionedUrl),
+}
+
+/// The versions of a single type, keyed by their version number.
+type Versions<T> = HashMap<BaseUrl, BTreeMap<u32, T>>;
+
+/// An in-memory collection of [`DataType`]s, [`PropertyType`]s and [`EntityType`]s.
+///
+/// Types are stored by their [`VersionedUrl`], which allows resolving references between types
+/// through the [`OntologyTypeProvider`] implementation, and to look up the latest version of a
+/// type by its [`BaseUrl`].
+///
+/// Types may be inserted in any order. References to types which are not (yet) contained in the
+/// store are reported on insert, and can be listed at any time with [`dangling_references`].
+///
+/// [`dangling_references`]: Self::dangling_references
+#[derive(Debug, Default, Clone)]
+#[expect(
+    clippy::struct_field_names,
+    reason = "The fields are named after the kind of types they store"
+)]
+pub struct OntologyStore {
+    data_types: Versions<DataType>,
+    property_types: Versions<PropertyType>,
+    entity_types: Versions<EntityType>,
+}
+
+/// Inserts `value` as the version `url` into `versions`.
+///
+/// Inserting a type which is already stored is a no-op, but a different type with the same `url`
+/// can't be inserted.
+fn insert<T: PartialEq>(
+    versions: &mut Versions<T>,
+    url: &VersionedUrl,
+    value: T,
+) -> Result<(), InsertTypeError> {
+    match versions
+        .entry(url.base_url.clone())
+        .or_default()
+        .entry(url.version)
+    {
+        btree_map::Entry::Vacant(entry) => {
+            entry.insert(value);
+            Ok(())
+        }
+        btree_map::Entry::Occupied(entry) if *entry.get() == value => Ok(()),
+        btree_map::Entry::Occupied(_) => Err(InsertTypeError::AlreadyExists(url.clone())),
+    }
+}
+
+fn get<'a, T>(versions: &'a Versions<T>, url: &VersionedUrl) -> Option<&'a T> {
+    versions.get(&url.base_url)?.get(&url.version)
+}
+
+fn latest<'a, T>(versions: &'a Versions<T>, base_url: &BaseUrl) -> Option<&'a T> {
+    versions
+        .get(base_url)?
+        .last_key_value()
+        .map(|(_, value)| value)
+}
+
+impl OntologyStore {
+    /// Creates an empty `OntologyStore`.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Inserts a [`DataType`] into the store.
+    ///
+    /// # Errors
+    ///
+    /// - [`AlreadyExists`] if a different data type with the same ID is already stored
+    ///
+    /// [`AlreadyExists`]: InsertTypeError::AlreadyExists
+    pub fn insert_data_type(&mut self, data_type: DataType) -> Result<(), InsertTypeError> {
+        let id = data_type.id().clone();
+        insert(&mut self.data_types, &id, data_type)
+    }
+
+    /// Inserts a [`PropertyType`] into the store.
+    ///
+    /// Returns the references of the property type to types which are not contained in the store.
+    ///
+    /// # Errors
+    ///
+    /// - [`AlreadyExists`] if a different property type with the same ID is already stored
+    ///
+    /// [`AlreadyExists`]: InsertTypeError::AlreadyExists
+    pub fn insert_property_type(
+        &mut self,
+        property_type: PropertyType,
+    ) -> Result<Vec<DanglingReference>, InsertTypeError> {
+        let dangling_references = self.dangling_references_of_property_type(&property_type);
+        let id = property_type.id().clone();
+        insert(&mut self.property_types, &id, property_type)?;
+        Ok(dangling_references)
+    }
+
+    /// Inserts an [`EntityType`] into the store.
+    ///
+    /// Returns the references of the entity type to types which are not contained in the store.
+    /// References of the entity type to itself are not considered dangling.
+    ///
+    /// # Errors
+    ///
+    /// - [`AlreadyExists`] if a different entity type with the same ID is already stored
+    ///
+    /// [`AlreadyExists`]: InsertTypeError::AlreadyExists
+    pub fn insert_entity_type(
+        &mut self,
+        entity_type: EntityType,
+    ) -> Result<Vec<DanglingReference>, InsertTypeError> {
+        let dangling_references = self.dangling_references_of_entity_type(&entity_type);
+        let id = entity_type.id().clone();
+        insert(&mut self.entity_types, &id, entity_type)?;
+        Ok(dangling_references)
+    }
+
+    /// Returns the latest version of the data type with the given [`BaseUrl`].
+    #[must_use]
+    pub fn latest_data_type(&self, base_url: &BaseUrl) -> Option<&DataType> {
+        latest(&self.data_types, base_url)
+    }
+
+    /// Returns the latest version of the property type with the given [`BaseUrl`].
+    #[must_use]
+    pub fn latest_property_type(&self, base_url: &BaseUrl) -> Option<&PropertyType> {
+        latest(&self.property_types, base_url)
+    }
+
+    /// Returns the latest version of the entity type with the given [`BaseUrl`].
+    #[must_use]
+    pub fn latest_entity_type(&self, base_url: &BaseUrl) -> Option<&EntityType> {
+        latest(&self.entity_types, base_url)
+    }
+
+    pub fn data_types(&self) -> impl Iterator<Item = &DataType> {
+        self.data_types.values().flat_map(BTreeMap::values)
+    }
+
+    pub fn property_types(&self) -> impl Iterator<Item = &PropertyType> {
+        self.property_types.values().flat_map(BTreeMap::values)
+    }
+
+    pub fn entity_types(&self) -> impl Iterator<Item = &EntityType> {
+        self.entity_types.values().flat_map(BTreeMap::values)
+    }
+
+    /// Returns all references between the stored types to types which are not contained in the
+    /// store.
+    #[must_use]
+    pub fn dangling_references(&self) -> Vec<DanglingReference> {
+        let mut dangling_references = self
+            .property_types()
+            .flat_map(|property_type| self.dangling_references_of_property_type(property_type))
+            .chain(
+                self.entity_types()
+                    .flat_map(|entity_type| self.dangling_references_of_entity_type(entity_type)),
+            )
+            .collect::<Vec<_>>();
+        dangling_references.sort_unstable();
+        dangling_references.dedup();
+        dangling_references
+    }
+
+    fn dangling_references_of_property_type(
+        &self,
+        property_type: &PropertyType,
+    ) -> Vec<DanglingReference> {
+        let mut dangling_references = property_type
+            .data_type_references()
+            .into_iter()
+            .filter(|reference| self.data_type(reference).is_none())
+            .map(|reference| DanglingReference::DataType(reference.url().clone()))
+            .chain(
+                property_type
+                    .property_type_references()
+                    .into_iter()
+                    .filter(|reference| {
+                        reference.url() != property_type.id()
+                            && self.property_type(reference).is_none()
+                    })
+                    .map(|reference| DanglingReference::PropertyType(reference.url().clone())),
+            )
+            .collect::<Vec<_>>();
+        dangling_references.sort_unstable();
+        dangling_references
+    }
+
+    fn dangling_references_of_entity_type(
+        &self,
+        entity_type: &EntityType,
+    ) -> Vec<DanglingReference> {
+        let property_types = entity_type
+            .property_type_references()
+            .into_iter()
+            .filter(|reference| self.property_type(reference).is_none())
+            .map(|reference| DanglingReference::PropertyType(reference.url().clone()));
+
+        let entity_types = entity_type
+            .inherits_from()
+            .all_of()
+            .iter()
+            .chain(
+                entity_type
+                    .link_mappings()
+                    .into_iter()
+                    .flat_map(|(link, destinations)| {
+                        core::iter::once(link).chain(destinations.unwrap_or_default())
+                    }),
+            )
+            .filter(|reference| {
+                reference.url() != entity_type.id() && self.entity_type(reference).is_none()
+            })
+            .map(|reference| DanglingReference::EntityType(reference.url().clone()));
+
+        let mut dangling_references = property_types.chain(entity_types).collect::<Vec<_>>();
+        dangling_references.sort_unstable();
+        dangling_references.dedup();
+        dangling_references
+    }
+}
+
+impl OntologyTypeProvider for OntologyStore {
+    fn data_type(&self, reference: &DataTypeReference) -> Option<&DataType> {
+        get(&self.data_types, reference.url())
+    }
+
+    fn property_type(&self, reference: &PropertyTypeReference) -> Option<&PropertyType> {
+        get(&self.property_types, reference.url())
+    }
+
+    fn entity_type(&self, reference: &EntityTypeReference) -> Option<&EntityType> {
+        get(&self.entity_types, reference.url())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        raw, test_data,
+        utils::tests::{check_serialization_from_str, entity_type, url},
+    };
+
+    fn data_type(input: &str) -> DataType {
+        check_serialization_from_str::<DataType, raw::DataType>(input, None)
+    }
+
+    fn property_type(input: &str) -> PropertyType {
+        check_serialization_from_str::<PropertyType, raw::PropertyType>(input, None)
+    }
+
+    #[test]
+    fn resolve_references() {
+        let mut store = OntologyStore::new();
+        let text = data_type(test_data::data_type::TEXT_V1);
+        let name = property_type(test_data::property_type::NAME_V1);
+        let block = entity_type(test_data::entity_type::BLOCK_V1);
+
+        store
+            .insert_data_type(text.clone())
+            .expect("failed to insert data type");
+        assert_eq!(store.insert_property_type(name.clone()), Ok(Vec::new()));
+        assert_eq!(store.insert_entity_type(block.clone()), Ok(Vec::new()));
+
+        assert_eq!(
+            store.data_type(&DataTypeReference::new(text.id().clone())),
+            Some(&text)
+        );
+        assert_eq!(
+            store.property_type(&PropertyTypeReference::new(name.id().clone())),
+            Some(&name)
+        );
+        assert_eq!(
+            store.entity_type(&EntityTypeReference::new(block.id().clone())),
+            Some(&block)
+        );
+        assert_eq!(
+            store.entity_type(&EntityTypeReference::new(url(
+                "https://blockprotocol.org/@alice/types/entity-type/block/v/2"
+            ))),
+            None
+        );
+    }
+
+    #[test]
+    fn latest_version() {
+        let mut store = OntologyStore::new();
+        let v1 = property_type(test_data::property_type::USER_ID_V1);
+        let v2 = property_type(test_data::property_type::USER_ID_V2);
+        let base_url = v1.id().base_url.clone();
+
+        assert_eq!(store.latest_property_type(&base_url), None);
+        store
+            .insert_property_type(v2.clone())
+            .expect("failed to insert property type");
+        store
+            .insert_property_type(v1)
+            .expect("failed to insert property type");
+
+        assert_eq!(store.latest_property_type(&base_url), Some(&v2));
+        assert_eq!(store.property_types().count(), 2);
+    }
+
+    #[test]
+    fn already_exists() {
+        let mut store = OntologyStore::new();
+        let text = data_type(test_data::data_type::TEXT_V1);
+        let mut changed_text = text.clone();
+        changed_text
+            .additional_properties_mut()
+            .insert("$comment".to_owned(), serde_json::json!("changed"));
+
+        store
+            .insert_data_type(text.clone())
+            .expect("failed to insert data type");
+        store
+            .insert_data_type(text.clone())
+            .expect("inserting the same type twice should succeed");
+        assert_eq!(
+            store.insert_data_type(changed_text),
+            Err(InsertTypeError::AlreadyExists(text.id().clone()))
+        );
+    }
+
+    #[test]
+    fn dangling_references() {
+        let mut store = OntologyStore::new();
+
+        // The link to `person` itself is not dangling
+        let dangling_references = store
+            .insert_entity_type(entity_type(test_data::entity_type::PERSON_V1))
+            .expect("failed to insert entity type");
+        assert_eq!(dangling_references, [
+            DanglingReference::PropertyType(url(
+                "https://blockprotocol.org/@alice/types/property-type/name/v/1"
+            )),
+            DanglingReference::EntityType(url(
+                "https://blockprotocol.org/@alice/types/entity-type/friend-of/v/1"
+            )),
+            DanglingReference::EntityType(url(
+                "https://blockprotocol.org/@alice/types/entity-type/owns/v/1"
+            )),
+        ]);
+        assert_eq!(store.dangling_references(), dangling_references);
+
+        assert_eq!(
+            store.insert_property_type(property_type(test_data::property_type::NAME_V1)),
+            Ok(vec![DanglingReference::DataType(url(
+                "https://blockprotocol.org/@blockprotocol/types/data-type/text/v/1"
+            ))])
+        );
+        store
+            .insert_data_type(data_type(test_data::data_type::TEXT_V1))
+            .expect("failed to insert data type");
+
+        assert_eq!(store.dangling_references(), dangling_references[1..]);
+    }
+}
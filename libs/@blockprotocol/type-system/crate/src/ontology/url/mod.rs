@@ -1,4 +1,13 @@
-use std::{fmt, num::IntErrorKind, result::Result, str::FromStr};
+use std::{
+    cmp::Ordering,
+    collections::HashSet,
+    fmt,
+    hash::{DefaultHasher, Hash, Hasher},
+    num::IntErrorKind,
+    result::Result,
+    str::FromStr,
+    sync::{Arc, Mutex, OnceLock, PoisonError},
+};
 
 pub use error::{ParseBaseUrlError, ParseVersionedUrlError};
 use serde::{de, Deserialize, Deserializer, Serialize, Serializer};
@@ -10,9 +19,100 @@ mod error;
 #[cfg(target_arch = "wasm32")]
 mod wasm;
 
+/// A validated URL ending with a trailing slash, e.g. `https://example.com/data-type/`.
+///
+/// Base URLs are interned, equal base URLs share the same allocation. Cloning, comparing for
+/// equality and hashing are therefore independent of the length of the URL.
 #[cfg_attr(target_arch = "wasm32", derive(Tsify))]
-#[derive(Clone, PartialEq, Eq, Ord, PartialOrd, Hash)]
-pub struct BaseUrl(String);
+#[derive(Clone)]
+pub struct BaseUrl(#[cfg_attr(target_arch = "wasm32", tsify(type = "string"))] Arc<str>);
+
+/// The number of independently locked shards of the interner, so threads creating base URLs
+/// concurrently only contend for a lock if their URLs end up in the same shard. Has to be a power
+/// of two.
+const INTERNER_SHARDS: usize = 16;
+
+/// The minimum number of URLs a shard of the interner holds before unused URLs are released.
+const MIN_INTERNED_URLS: usize = 64;
+
+struct InternerShard {
+    urls: HashSet<Arc<str>>,
+    release_at: usize,
+}
+
+/// Returns the shared allocation of `url`, allocating it if it isn't interned yet.
+///
+/// The interner is split into [`INTERNER_SHARDS`] shards by the hash of the URL, each behind its
+/// own lock. URLs which are not referenced by any [`BaseUrl`] anymore are released whenever their
+/// shard has doubled in size since they were last released.
+fn intern(url: &str) -> Arc<str> {
+    static INTERNER: OnceLock<[Mutex<InternerShard>; INTERNER_SHARDS]> = OnceLock::new();
+
+    let mut hasher = DefaultHasher::new();
+    url.hash(&mut hasher);
+    #[expect(
+        clippy::cast_possible_truncation,
+        reason = "Only the lowest bits of the hash are used to select the shard"
+    )]
+    let index = hasher.finish() as usize & (INTERNER_SHARDS - 1);
+
+    let shard = &INTERNER.get_or_init(|| {
+        core::array::from_fn(|_| {
+            Mutex::new(InternerShard {
+                urls: HashSet::new(),
+                release_at: MIN_INTERNED_URLS,
+            })
+        })
+    })[index];
+
+    // A shard is consistent at any point, so it's safe to keep using it after a panic
+    let mut shard = shard.lock().unwrap_or_else(PoisonError::into_inner);
+
+    if let Some(interned) = shard.urls.get(url) {
+        return Arc::clone(interned);
+    }
+
+    if shard.urls.len() >= shard.release_at {
+        shard.urls.retain(|url| Arc::strong_count(url) > 1);
+        shard.release_at = (shard.urls.len() * 2).max(MIN_INTERNED_URLS);
+    }
+
+    let url = Arc::<str>::from(url);
+    shard.urls.insert(Arc::clone(&url));
+    url
+}
+
+// As every `BaseUrl` is interned, two base URLs are equal if and only if they share their
+// allocation, so only the address is compared and hashed.
+impl PartialEq for BaseUrl {
+    fn eq(&self, other: &Self) -> bool {
+        Arc::ptr_eq(&self.0, &other.0)
+    }
+}
+
+impl Eq for BaseUrl {}
+
+impl Hash for BaseUrl {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        Arc::as_ptr(&self.0).cast::<u8>().hash(state);
+    }
+}
+
+impl PartialOrd for BaseUrl {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for BaseUrl {
+    fn cmp(&self, other: &Self) -> Ordering {
+        if self == other {
+            Ordering::Equal
+        } else {
+            self.as_str().cmp(other.as_str())
+        }
+    }
+}
 
 impl fmt::Debug for BaseUrl {
     fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
@@ -31,10 +131,14 @@ impl BaseUrl {
     ///
     /// # Errors
     /// - `ParseBaseUrlError` if the given URL string is invalid
+    #[expect(
+        clippy::needless_pass_by_value,
+        reason = "The URL is interned, taking a `String` keeps the signature stable"
+    )]
     pub fn new(url: String) -> Result<Self, ParseBaseUrlError> {
         Self::validate_str(&url)?;
 
-        Ok(Self(url))
+        Ok(Self(intern(&url)))
     }
 
     fn validate_str(url: &str) -> Result<(), ParseBaseUrlError> {
@@ -61,12 +165,12 @@ impl BaseUrl {
         reason = "The URL is validated on creation"
     )]
     pub fn to_url(&self) -> Url {
-        Url::parse(&self.0).expect("invalid Base URL")
+        Url::parse(self.as_str()).expect("invalid Base URL")
     }
 
     #[must_use]
     pub fn as_str(&self) -> &str {
-        self.0.as_str()
+        &self.0
     }
 }
 
@@ -75,7 +179,7 @@ impl Serialize for BaseUrl {
     where
         S: Serializer,
     {
-        self.to_string().serialize(serializer)
+        self.as_str().serialize(serializer)
     }
 }
 
@@ -170,7 +274,7 @@ impl Serialize for VersionedUrl {
     where
         S: Serializer,
     {
-        self.to_string().serialize(serializer)
+        serializer.collect_str(self)
     }
 }
 
@@ -191,6 +295,53 @@ mod tests {
 
     // TODO: add some unit tests for base URL
 
+    #[test]
+    fn base_url_interned() {
+        let url_a = BaseUrl::new("https://example.com/a/".to_owned()).expect("invalid base URL");
+        let url_b = BaseUrl::new("https://example.com/b/".to_owned()).expect("invalid base URL");
+        let versioned_url_a = VersionedUrl::from_str("https://example.com/a/v/1")
+            .expect("parsing versioned URL failed");
+
+        assert_eq!(versioned_url_a.base_url, url_a);
+        assert_eq!(
+            versioned_url_a.base_url.as_str().as_ptr(),
+            url_a.as_str().as_ptr()
+        );
+        assert_ne!(url_a, url_b);
+        assert!(url_a < url_b);
+        assert_eq!(
+            serde_json::to_string(&versioned_url_a).expect("failed to serialize versioned URL"),
+            "\"https://example.com/a/v/1\""
+        );
+    }
+
+    #[test]
+    fn base_url_interned_concurrently() {
+        let urls = std::thread::scope(|scope| {
+            let mut threads = Vec::new();
+            for _ in 0..8 {
+                threads.push(scope.spawn(|| {
+                    (0..256)
+                        .map(|index| {
+                            BaseUrl::new(format!("https://example.com/concurrent/{index}/"))
+                                .expect("invalid base URL")
+                        })
+                        .collect::<Vec<_>>()
+                }));
+            }
+            threads
+                .into_iter()
+                .map(|thread| thread.join().expect("thread panicked"))
+                .collect::<Vec<_>>()
+        });
+
+        for thread_urls in &urls[1..] {
+            for (url, other_url) in urls[0].iter().zip(thread_urls) {
+                assert_eq!(url.as_str().as_ptr(), other_url.as_str().as_ptr());
+            }
+        }
+    }
+
     #[test]
     fn versioned_url() {
         let input_str = "https://blockprotocol.org/@blockprotocol/types/data-type/empty-list/v/1";
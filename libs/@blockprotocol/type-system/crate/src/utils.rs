@@ -39,6 +39,14 @@ pub(crate) mod tests {
     use std::fmt::Debug;
 
     use serde::{de::DeserializeOwned, Deserialize, Serialize};
+    use serde_json::json;
+
+    use crate::{
+        raw, test_data,
+        url::{BaseUrl, VersionedUrl},
+        DataType, DataTypeReference, EntityType, EntityTypeReference, OntologyStore,
+        OntologyTypeProvider, PropertyType, PropertyTypeReference,
+    };
 
     /// Will serialize as a constant value `"string"`
     #[derive(Default, Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
@@ -58,13 +66,16 @@ pub(crate) mod tests {
     /// Ensures a type can be deserialized from a given string, as well as being able to be
     /// serialized back.
     ///
-    /// Optionally checks the deserialized object against an expected value.
+    /// The type is deserialized and serialized both directly and through its [`repr`], which have
+    /// to agree. Optionally checks the deserialized object against an expected value.
+    ///
+    /// [`repr`]: crate::raw
     pub(crate) fn check_serialization_from_str<T, R>(
         input: &str,
         expected_native_repr: Option<R>,
     ) -> T
     where
-        T: Debug + Clone + TryFrom<R>,
+        T: Debug + PartialEq + Clone + TryFrom<R> + Serialize + DeserializeOwned,
         T::Error: Debug,
         R: Debug + PartialEq + Clone + From<T> + Serialize + DeserializeOwned,
     {
@@ -80,8 +91,19 @@ pub(crate) mod tests {
             assert_eq!(re_serialized_repr, repr);
         }
 
-        let _re_serialized_value =
-            serde_json::to_value(re_serialized_repr).expect("failed to serialize");
+        // Maps keep their declaration order, so the output has to be byte-stable
+        let serialized = serde_json::to_string(&deserialized_repr).expect("failed to serialize");
+        assert_eq!(
+            serde_json::to_string(&re_serialized_repr).expect("failed to serialize"),
+            serialized,
+        );
+
+        let deserialized: T = serde_json::from_str(input).expect("failed to deserialize");
+        assert_eq!(deserialized, value);
+        assert_eq!(
+            serde_json::to_string(&value).expect("failed to serialize"),
+            serialized,
+        );
 
         value
     }
@@ -144,4 +166,162 @@ pub(crate) mod tests {
         serde_json::from_value::<T>(json)
             .expect_err("JSON was expected to be invalid but it was accepted");
     }
+
+    pub(crate) fn url(url: &str) -> VersionedUrl {
+        url.parse().expect("invalid versioned URL")
+    }
+
+    pub(crate) fn base_url(url: &str) -> BaseUrl {
+        BaseUrl::new(url.to_owned()).expect("invalid base URL")
+    }
+
+    /// Deserializes a data type from `input`, see [`check_serialization_from_str`].
+    pub(crate) fn data_type(input: &str) -> DataType {
+        check_serialization_from_str::<DataType, raw::DataType>(input, None)
+    }
+
+    /// Creates the data type `https://example.com/data-type/{name}` of the given `json_type` with
+    /// the additional `keywords`.
+    pub(crate) fn data_type_json(
+        name: &str,
+        json_type: &str,
+        keywords: &serde_json::Value,
+    ) -> serde_json::Value {
+        let mut data_type = json!({
+            "$schema": "https://blockprotocol.org/types/modules/graph/0.3/schema/data-type",
+            "kind": "dataType",
+            "$id": format!("https://example.com/data-type/{name}"),
+            "title": name,
+            "type": json_type,
+        });
+        data_type
+            .as_object_mut()
+            .expect("data type is not an object")
+            .extend(
+                keywords
+                    .as_object()
+                    .expect("keywords are not an object")
+                    .clone(),
+            );
+        data_type
+    }
+
+    /// Deserializes an entity type from `input`, see [`check_serialization_from_str`].
+    pub(crate) fn entity_type(input: &str) -> EntityType {
+        check_serialization_from_str::<EntityType, raw::EntityType>(input, None)
+    }
+
+    /// Creates the entity type `https://example.com/entity-type/{name}` with the given
+    /// `properties` and `links`, inheriting from `https://example.com/entity-type/{parent}` for
+    /// every entry in `parents`.
+    pub(crate) fn entity_type_json(
+        name: &str,
+        parents: &[&str],
+        properties: &serde_json::Value,
+        required: &[&str],
+        links: &serde_json::Value,
+    ) -> String {
+        json!({
+            "$schema": "https://blockprotocol.org/types/modules/graph/0.3/schema/entity-type",
+            "kind": "entityType",
+            "$id": format!("https://example.com/entity-type/{name}"),
+            "type": "object",
+            "title": name,
+            "allOf": parents
+                .iter()
+                .map(|parent| json!({ "$ref": format!("https://example.com/entity-type/{parent}") }))
+                .collect::<Vec<_>>(),
+            "properties": properties,
+            "required": required,
+            "links": links,
+        })
+        .to_string()
+    }
+
+    /// An [`OntologyTypeProvider`] which knows about all types from the test data.
+    pub(crate) struct TestOntology(OntologyStore);
+
+    impl TestOntology {
+        pub(crate) fn new() -> Self {
+            let mut ontology = Self(OntologyStore::new());
+
+            for data_type in [
+                test_data::data_type::BOOLEAN_V1,
+                test_data::data_type::EMPTY_LIST_V1,
+                test_data::data_type::NULL_V1,
+                test_data::data_type::NUMBER_V1,
+                test_data::data_type::OBJECT_V1,
+                test_data::data_type::TEXT_V1,
+            ] {
+                ontology.add_data_type(data_type);
+            }
+
+            for property_type in [
+                test_data::property_type::AGE_V1,
+                test_data::property_type::CONTACT_INFORMATION_V1,
+                test_data::property_type::CONTRIVED_PROPERTY_V1,
+                test_data::property_type::FAVORITE_QUOTE_V1,
+                test_data::property_type::INTERESTS_V1,
+                test_data::property_type::NAME_V1,
+                test_data::property_type::NUMBERS_V1,
+                test_data::property_type::TEXT_V1,
+                test_data::property_type::USER_ID_V1,
+                test_data::property_type::USER_ID_V2,
+            ] {
+                ontology.add_property_type(property_type);
+            }
+
+            for entity_type in [
+                test_data::entity_type::ADDRESS_V1,
+                test_data::entity_type::BLOCK_V1,
+                test_data::entity_type::BOOK_V1,
+                test_data::entity_type::BUILDING_V1,
+                test_data::entity_type::CHURCH_V1,
+                test_data::entity_type::ORGANIZATION_V1,
+                test_data::entity_type::PAGE,
+                test_data::entity_type::PERSON_V1,
+                test_data::entity_type::PLAYLIST_V1,
+                test_data::entity_type::SONG_V1,
+            ] {
+                ontology.add_entity_type(entity_type);
+            }
+
+            ontology
+        }
+
+        pub(crate) fn add_data_type(&mut self, input: &str) {
+            self.0
+                .insert_data_type(data_type(input))
+                .expect("failed to insert data type");
+        }
+
+        pub(crate) fn add_property_type(&mut self, input: &str) {
+            self.0
+                .insert_property_type(check_serialization_from_str::<
+                    PropertyType,
+                    raw::PropertyType,
+                >(input, None))
+                .expect("failed to insert property type");
+        }
+
+        pub(crate) fn add_entity_type(&mut self, input: &str) {
+            self.0
+                .insert_entity_type(entity_type(input))
+                .expect("failed to insert entity type");
+        }
+    }
+
+    impl OntologyTypeProvider for TestOntology {
+        fn data_type(&self, reference: &DataTypeReference) -> Option<&DataType> {
+            self.0.data_type(reference)
+        }
+
+        fn property_type(&self, reference: &PropertyTypeReference) -> Option<&PropertyType> {
+            self.0.property_type(reference)
+        }
+
+        fn entity_type(&self, reference: &EntityTypeReference) -> Option<&EntityType> {
+            self.0.entity_type(reference)
+        }
+    }
 }
@@ -1,5 +1,4 @@
 //! Test data for testing the Type System package.
-#![expect(dead_code)]
 
 pub(crate) mod data_type;
 pub(crate) mod entity_type;
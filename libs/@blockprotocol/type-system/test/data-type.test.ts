@@ -79,8 +79,11 @@ const invalidDataTypes: [DataType, ParseDataTypeError][] = [
     {
       reason: "InvalidVersionedUrl",
       inner: {
-        reason: "AdditionalEndContent",
-        inner: ".3",
+        path: "/$id",
+        error: {
+          reason: "AdditionalEndContent",
+          inner: ".3",
+        },
       },
     },
   ],
@@ -127,7 +130,10 @@ const brokenTypes: [any, ParseDataTypeError][] = [
     },
     {
       reason: "InvalidMetaSchema",
-      inner: "https://blockprotocol.org/types/modules/graph/0.3/schema/foo",
+      inner: {
+        path: "/$schema",
+        meta_schema: "https://blockprotocol.org/types/modules/graph/0.3/schema/foo",
+      },
     },
   ],
 ];
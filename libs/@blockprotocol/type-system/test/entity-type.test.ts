@@ -297,8 +297,11 @@ const invalidEntityTypes: [string, EntityType, ParseEntityTypeError][] = [
     {
       reason: "InvalidVersionedUrl",
       inner: {
-        reason: "AdditionalEndContent",
-        inner: ".2",
+        path: "/$id",
+        error: {
+          reason: "AdditionalEndContent",
+          inner: ".2",
+        },
       },
     },
   ],
@@ -321,10 +324,13 @@ const invalidEntityTypes: [string, EntityType, ParseEntityTypeError][] = [
     {
       reason: "InvalidVersionedUrl",
       inner: {
-        reason: "InvalidBaseUrl",
-        inner: {
-          reason: "UrlParseError",
-          inner: "invalid international domain name",
+        path: "/$id",
+        error: {
+          reason: "InvalidBaseUrl",
+          inner: {
+            reason: "UrlParseError",
+            inner: "invalid international domain name",
+          },
         },
       },
     },
@@ -350,10 +356,13 @@ const invalidEntityTypes: [string, EntityType, ParseEntityTypeError][] = [
       inner: {
         reason: "InvalidPropertyTypeReference",
         inner: {
-          reason: "InvalidBaseUrl",
-          inner: {
-            reason: "UrlParseError",
-            inner: "relative URL without a base",
+          path: "/properties/https:~1~1blockprotocol.org~1@alice~1types~1property-type~1address-line-1~1/$ref",
+          error: {
+            reason: "InvalidBaseUrl",
+            inner: {
+              reason: "UrlParseError",
+              inner: "relative URL without a base",
+            },
           },
         },
       },
@@ -380,7 +389,10 @@ const invalidEntityTypes: [string, EntityType, ParseEntityTypeError][] = [
       inner: {
         reason: "InvalidPropertyKey",
         inner: {
-          reason: "MissingTrailingSlash",
+          path: "/properties/https:~1~1blockprotocol.org~1@alice~1types~1property-type~1address-line-1~1v~11",
+          error: {
+            reason: "MissingTrailingSlash",
+          },
         },
       },
     },
@@ -419,7 +431,10 @@ const invalidEntityTypes: [string, EntityType, ParseEntityTypeError][] = [
       inner: {
         reason: "InvalidPropertyKey",
         inner: {
-          reason: "MissingTrailingSlash",
+          path: "/properties/https:~1~1blockprotocol.org~1@alice~1types~1property-type~1address-line-1~1v~11",
+          error: {
+            reason: "MissingTrailingSlash",
+          },
         },
       },
     },
@@ -458,7 +473,10 @@ const invalidEntityTypes: [string, EntityType, ParseEntityTypeError][] = [
       inner: {
         reason: "InvalidPropertyKey",
         inner: {
-          reason: "MissingTrailingSlash",
+          path: "/properties/https:~1~1blockprotocol.org~1@alice~1types~1property-type~1address-line-1~1v~11",
+          error: {
+            reason: "MissingTrailingSlash",
+          },
         },
       },
     },
@@ -484,8 +502,11 @@ const invalidEntityTypes: [string, EntityType, ParseEntityTypeError][] = [
       inner: {
         reason: "EntityTypeReferenceError",
         inner: {
-          reason: "AdditionalEndContent",
-          inner: ".2",
+          path: "/allOf/0/$ref",
+          error: {
+            reason: "AdditionalEndContent",
+            inner: ".2",
+          },
         },
       },
     },
@@ -520,7 +541,10 @@ const brokenTypes: [any, ParseEntityTypeError][] = [
     },
     {
       reason: "InvalidMetaSchema",
-      inner: "https://blockprotocol.org/types/modules/graph/0.3/schema/foo",
+      inner: {
+        path: "/$schema",
+        meta_schema: "https://blockprotocol.org/types/modules/graph/0.3/schema/foo",
+      },
     },
   ],
 ];
@@ -199,8 +199,11 @@ const invalidPropertyTypes: [string, PropertyType, ParsePropertyTypeError][] = [
     {
       reason: "InvalidVersionedUrl",
       inner: {
-        reason: "AdditionalEndContent",
-        inner: ".4",
+        path: "/$id",
+        error: {
+          reason: "AdditionalEndContent",
+          inner: ".4",
+        },
       },
     },
   ],
@@ -221,10 +224,13 @@ const invalidPropertyTypes: [string, PropertyType, ParsePropertyTypeError][] = [
     {
       reason: "InvalidVersionedUrl",
       inner: {
-        reason: "InvalidBaseUrl",
-        inner: {
-          reason: "UrlParseError",
-          inner: "invalid international domain name",
+        path: "/$id",
+        error: {
+          reason: "InvalidBaseUrl",
+          inner: {
+            reason: "UrlParseError",
+            inner: "invalid international domain name",
+          },
         },
       },
     },
@@ -250,10 +256,13 @@ const invalidPropertyTypes: [string, PropertyType, ParsePropertyTypeError][] = [
         inner: {
           reason: "InvalidDataTypeReference",
           inner: {
-            reason: "InvalidBaseUrl",
-            inner: {
-              reason: "UrlParseError",
-              inner: "relative URL without a base",
+            path: "/oneOf/0/$ref",
+            error: {
+              reason: "InvalidBaseUrl",
+              inner: {
+                reason: "UrlParseError",
+                inner: "relative URL without a base",
+              },
             },
           },
         },
@@ -289,7 +298,10 @@ const invalidPropertyTypes: [string, PropertyType, ParsePropertyTypeError][] = [
           inner: {
             reason: "InvalidPropertyKey",
             inner: {
-              reason: "MissingTrailingSlash",
+              path: "/oneOf/0/properties/https:~1~1blockprotocol.org~1@blockprotocol~1types~1property-type~1broken~1v~11",
+              error: {
+                reason: "MissingTrailingSlash",
+              },
             },
           },
         },
@@ -329,7 +341,10 @@ const brokenTypes: [any, ParsePropertyTypeError][] = [
       inner: {
         reason: "ValidationError",
         inner: {
-          type: "EmptyOneOf",
+          path: "/oneOf",
+          error: {
+            type: "EmptyOneOf",
+          },
         },
       },
     },
@@ -348,7 +363,10 @@ const brokenTypes: [any, ParsePropertyTypeError][] = [
     },
     {
       reason: "InvalidMetaSchema",
-      inner: "https://blockprotocol.org/types/modules/graph/0.3/schema/foo",
+      inner: {
+        path: "/$schema",
+        meta_schema: "https://blockprotocol.org/types/modules/graph/0.3/schema/foo",
+      },
     },
   ],
 ];